@@ -91,11 +91,12 @@ mod tests {
     fn configuring_the_decoder() -> Result<()> {
         // ANCHOR: configuring_the_decoder
 
-        use fuels::core::codec::ABIDecoder;
+        use fuels::core::codec::{ABIDecoder, Encoding};
 
         ABIDecoder::new(DecoderConfig {
             max_depth: 5,
             max_tokens: 100,
+            encoding: Encoding::Legacy,
         });
         // ANCHOR_END: configuring_the_decoder
 
@@ -105,15 +106,37 @@ mod tests {
     #[test]
     fn configuring_the_encoder() -> Result<()> {
         // ANCHOR: configuring_the_encoder
-        use fuels::core::codec::ABIEncoder;
+        use fuels::core::codec::{ABIEncoder, Encoding};
 
         ABIEncoder::new(EncoderConfig {
             max_depth: 5,
             max_tokens: 100,
             max_total_enum_width: 10_000,
+            encoding: Encoding::Legacy,
         });
         // ANCHOR_END: configuring_the_encoder
 
         Ok(())
     }
+
+    #[test]
+    fn selecting_the_experimental_encoding() -> Result<()> {
+        // ANCHOR: experimental_encoding
+        use fuels::core::{
+            codec::{ABIEncoder, Encoding},
+            traits::Tokenizable,
+        };
+
+        let encoder = ABIEncoder::new(EncoderConfig {
+            encoding: Encoding::Experimental,
+            ..Default::default()
+        });
+
+        let encoded = encoder.encode(&[vec![1u8, 2].into_token()])?.resolve(0);
+
+        assert_eq!(encoded, [0, 0, 0, 0, 0, 0, 0, 2, 1, 2]);
+        // ANCHOR_END: experimental_encoding
+
+        Ok(())
+    }
 }
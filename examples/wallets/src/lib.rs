@@ -115,6 +115,43 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn manage_keystore_directory() -> Result<()> {
+        // ANCHOR: keystore_directory
+        use fuels::{
+            accounts::keystore::{KdfParams, Keystore},
+            prelude::*,
+        };
+
+        let dir = tempfile::tempdir()?;
+
+        // Opens the keystore, creating the directory if needed
+        let keystore = Keystore::new(dir.path())?.with_kdf_params(KdfParams::Scrypt {
+            log_n: 15,
+            r: 8,
+            p: 1,
+        });
+
+        let wallet = WalletUnlocked::new_random(None);
+        keystore.import(&wallet, "my_password", Some("deployer"))?;
+
+        let entries = keystore.list()?;
+        assert_eq!(entries[0].label.as_deref(), Some("deployer"));
+
+        // Looks up the key's file in the address index and decrypts it
+        let wallet = keystore.unlock_by_address(wallet.address(), "my_password", None)?;
+        // ANCHOR_END: keystore_directory
+
+        // ANCHOR: keystore_maintenance
+        keystore.set_label(wallet.address(), Some("old deployer"))?;
+        keystore.change_password(wallet.address(), "my_password", "my_new_password")?;
+        keystore.export(wallet.address(), dir.path().join("deployer.json"))?;
+        keystore.delete(wallet.address())?;
+        // ANCHOR_END: keystore_maintenance
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn wallet_transfer() -> Result<()> {
         // ANCHOR: wallet_transfer
@@ -371,6 +408,9 @@ mod tests {
             .await?
             .expect("failed to retrieve message proof");
 
+        // Verify the proof against the block headers it contains
+        proof.verify()?;
+
         // Verify the amount and recipient
         assert_eq!(proof.amount, amount);
         assert_eq!(proof.recipient, base_layer_address);
@@ -0,0 +1,135 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::{anyhow, Context, Result};
+use fuel_abi_types::abi::program::{ProgramABI, TypeDeclaration};
+use fuels_core::types::param_types::{ParamType, TypeNames};
+
+/// A parsed JSON ABI together with the lookup needed to resolve its types.
+#[derive(Debug, Clone)]
+pub struct ProgramAbi {
+    json: String,
+    abi: ProgramABI,
+    type_lookup: HashMap<usize, TypeDeclaration>,
+}
+
+/// A function from the JSON ABI with all of its types resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiFunction {
+    pub name: String,
+    pub inputs: Vec<ParamType>,
+    pub output: ParamType,
+    /// The argument names and the names of the components of each input.
+    pub input_names: Vec<TypeNames>,
+    pub output_names: TypeNames,
+}
+
+impl ProgramAbi {
+    pub fn from_json(json: &str) -> Result<Self> {
+        let abi: ProgramABI = serde_json::from_str(json).context("failed to parse JSON ABI")?;
+        let type_lookup = abi
+            .types
+            .iter()
+            .map(|decl| (decl.type_id, decl.clone()))
+            .collect();
+
+        Ok(Self {
+            json: json.to_string(),
+            abi,
+            type_lookup,
+        })
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("failed to read JSON ABI from `{}`", path.display()))?;
+
+        Self::from_json(&json)
+    }
+
+    /// The JSON this ABI was parsed from.
+    pub fn json(&self) -> &str {
+        &self.json
+    }
+
+    pub fn function(&self, name: &str) -> Result<AbiFunction> {
+        let function = self
+            .abi
+            .functions
+            .iter()
+            .find(|function| function.name == name)
+            .ok_or_else(|| anyhow!("function `{name}` not found in the JSON ABI"))?;
+
+        let inputs = function
+            .inputs
+            .iter()
+            .map(|input| ParamType::try_from_type_application(input, &self.type_lookup))
+            .collect::<fuels_core::types::errors::Result<Vec<_>>>()?;
+        let output = ParamType::try_from_type_application(&function.output, &self.type_lookup)?;
+
+        let input_names = function
+            .inputs
+            .iter()
+            .map(|input| TypeNames::try_from_type_application(input, &self.type_lookup))
+            .collect::<fuels_core::types::errors::Result<Vec<_>>>()?;
+        let output_names =
+            TypeNames::try_from_type_application(&function.output, &self.type_lookup)?;
+
+        Ok(AbiFunction {
+            name: function.name.clone(),
+            inputs,
+            output,
+            input_names,
+            output_names,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"
+    {
+        "types": [
+            { "typeId": 0, "type": "bool", "components": null, "typeParameters": null },
+            { "typeId": 1, "type": "u32", "components": null, "typeParameters": null }
+        ],
+        "functions": [
+            {
+                "inputs": [ { "name": "arg", "type": 1, "typeArguments": null } ],
+                "name": "takes_u32_returns_bool",
+                "output": { "name": "", "type": 0, "typeArguments": null }
+            }
+        ],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    #[test]
+    fn resolves_function_types() -> Result<()> {
+        let abi = ProgramAbi::from_json(ABI)?;
+
+        let function = abi.function("takes_u32_returns_bool")?;
+
+        assert_eq!(function.inputs, vec![ParamType::U32]);
+        assert_eq!(function.output, ParamType::Bool);
+        assert_eq!(function.input_names[0].name, "arg");
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_function_is_an_error() -> Result<()> {
+        let abi = ProgramAbi::from_json(ABI)?;
+
+        let err = abi.function("missing").expect_err("should have failed");
+
+        assert_eq!(
+            err.to_string(),
+            "function `missing` not found in the JSON ABI"
+        );
+
+        Ok(())
+    }
+}
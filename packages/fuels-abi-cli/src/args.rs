@@ -0,0 +1,116 @@
+use anyhow::{anyhow, bail, Context, Result};
+use fuels_core::{codec::json_to_token, types::Token};
+use serde_json::Value;
+
+use crate::abi::AbiFunction;
+
+/// Parses `json` into one `Token` per input of `function`.
+///
+/// The arguments are given either as a JSON array, in the order of the inputs, or as a JSON object
+/// keyed by the names of the inputs. See [`json_to_token`] for the format of each argument.
+pub fn parse_args(function: &AbiFunction, json: &str) -> Result<Vec<Token>> {
+    let value: Value = serde_json::from_str(json).context("arguments are not valid JSON")?;
+
+    let values = match &value {
+        Value::Array(values) => {
+            if values.len() != function.inputs.len() {
+                bail!(
+                    "expected {} arguments, got {}: `{value}`",
+                    function.inputs.len(),
+                    values.len()
+                );
+            }
+            values.iter().collect::<Vec<_>>()
+        }
+        Value::Object(object) => {
+            if let Some(unknown) = object
+                .keys()
+                .find(|key| !function.input_names.iter().any(|names| &names.name == *key))
+            {
+                bail!("`{}` has no argument named `{unknown}`", function.name);
+            }
+
+            function
+                .input_names
+                .iter()
+                .map(|names| {
+                    object
+                        .get(&names.name)
+                        .ok_or_else(|| anyhow!("missing argument `{}`", names.name))
+                })
+                .collect::<Result<_>>()?
+        }
+        _ => bail!("expected the arguments as an array or an object, got `{value}`"),
+    };
+
+    function
+        .inputs
+        .iter()
+        .zip(&function.input_names)
+        .zip(values)
+        .map(|((param_type, names), value)| {
+            json_to_token(value, param_type, names)
+                .with_context(|| format!("invalid argument `{}`", names.name))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::param_types::{ParamType, TypeNames};
+
+    use super::*;
+
+    fn function() -> AbiFunction {
+        let names = |name: &str| TypeNames {
+            name: name.to_string(),
+            ..Default::default()
+        };
+
+        AbiFunction {
+            name: "my_fn".to_string(),
+            inputs: vec![ParamType::Bool, ParamType::U32, ParamType::U128],
+            output: ParamType::Unit,
+            input_names: vec![names("flag"), names("count"), names("amount")],
+            output_names: TypeNames::default(),
+        }
+    }
+
+    #[test]
+    fn parses_positional_and_named_args() -> Result<()> {
+        let expected = vec![Token::Bool(true), Token::U32(42), Token::U128(u128::MAX)];
+
+        let positional = parse_args(
+            &function(),
+            r#"[true, 42, "340282366920938463463374607431768211455"]"#,
+        )?;
+        let named = parse_args(
+            &function(),
+            r#"{"amount": "340282366920938463463374607431768211455", "flag": true, "count": 42}"#,
+        )?;
+
+        assert_eq!(positional, expected);
+        assert_eq!(named, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_args_are_an_error() {
+        let error = |json| {
+            parse_args(&function(), json)
+                .expect_err("should have failed")
+                .to_string()
+        };
+
+        assert_eq!(error("[1, 2]"), "expected 3 arguments, got 2: `[1,2]`");
+        assert_eq!(
+            error(r#"{"flag": true, "count": 1}"#),
+            "missing argument `amount`"
+        );
+        assert_eq!(
+            error(r#"{"other": 1}"#),
+            "`my_fn` has no argument named `other`"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::{Context, Result};
+use fuels_code_gen::{Abigen, AbigenTarget, ProgramType};
+use fuels_core::codec::{resolve_fn_selector, token_to_json, ABIDecoder, ABIEncoder};
+
+use crate::{abi::ProgramAbi, args::parse_args};
+
+/// Encodes a call to `fn_name` with the JSON `args` and returns the calldata as a hex string.
+///
+/// The calldata is prefixed with the function selector unless `with_selector` is `false`, which is
+/// what scripts and predicates expect. Heap types are resolved as if the calldata was placed at
+/// `offset` in the VM memory.
+pub fn encode(
+    abi: &ProgramAbi,
+    fn_name: &str,
+    args: &str,
+    with_selector: bool,
+    offset: u64,
+) -> Result<String> {
+    let function = abi.function(fn_name)?;
+    let tokens = parse_args(&function, args)
+        .with_context(|| format!("failed to parse the arguments of `{fn_name}`"))?;
+
+    let encoded_args = ABIEncoder::default().encode(&tokens)?.resolve(offset);
+
+    let calldata = if with_selector {
+        let mut calldata = resolve_fn_selector(fn_name, &function.inputs).to_vec();
+        calldata.extend(encoded_args);
+        calldata
+    } else {
+        encoded_args
+    };
+
+    Ok(hex::encode(calldata))
+}
+
+/// Decodes the hex encoded return value of `fn_name` and renders it as JSON.
+pub fn decode(abi: &ProgramAbi, fn_name: &str, data: &str) -> Result<String> {
+    let function = abi.function(fn_name)?;
+    let data = data.trim();
+    let bytes = hex::decode(data.strip_prefix("0x").unwrap_or(data))
+        .context("data is not a valid hex string")?;
+
+    let token = ABIDecoder::default().decode(&function.output, &bytes)?;
+    let json = token_to_json(&token, &function.output_names)?;
+
+    Ok(json.to_string())
+}
+
+/// Generates the Rust bindings `abigen!` would generate for the given ABI.
+pub fn codegen(
+    abi: &ProgramAbi,
+    name: &str,
+    program_type: ProgramType,
+    no_std: bool,
+) -> Result<String> {
+    let target = AbigenTarget {
+        name: name.to_string(),
+        abi: abi.json().to_string(),
+        program_type,
+    };
+    let code = Abigen::generate(vec![target], no_std)?;
+
+    let file = syn::parse2(code).context("generated bindings are not valid Rust")?;
+
+    Ok(prettyplease::unparse(&file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"
+    {
+        "types": [
+            { "typeId": 0, "type": "bool", "components": null, "typeParameters": null },
+            { "typeId": 1, "type": "u32", "components": null, "typeParameters": null },
+            { "typeId": 2, "type": "[_; 2]", "components": [ { "name": "__array_element", "type": 3, "typeArguments": null } ], "typeParameters": null },
+            { "typeId": 3, "type": "u16", "components": null, "typeParameters": null }
+        ],
+        "functions": [
+            {
+                "inputs": [ { "name": "arg", "type": 1, "typeArguments": null } ],
+                "name": "takes_u32_returns_bool",
+                "output": { "name": "", "type": 0, "typeArguments": null }
+            },
+            {
+                "inputs": [ { "name": "arg", "type": 2, "typeArguments": null } ],
+                "name": "takes_array",
+                "output": { "name": "", "type": 2, "typeArguments": null }
+            }
+        ],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    #[test]
+    fn encodes_calls() -> Result<()> {
+        let abi = ProgramAbi::from_json(ABI)?;
+
+        let with_selector = encode(&abi, "takes_u32_returns_bool", "[4]", true, 0)?;
+        let args_only = encode(&abi, "takes_array", "[[1, 2]]", false, 0)?;
+
+        assert_eq!(with_selector, "000000006355e6ee0000000000000004");
+        assert_eq!(args_only, "00000000000000010000000000000002");
+
+        Ok(())
+    }
+
+    #[test]
+    fn decodes_return_values() -> Result<()> {
+        let abi = ProgramAbi::from_json(ABI)?;
+
+        let decoded = decode(&abi, "takes_array", "0x00000000000000010000000000000002")?;
+
+        assert_eq!(decoded, "[1,2]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn generates_bindings() -> Result<()> {
+        let abi = ProgramAbi::from_json(ABI)?;
+
+        let code = codegen(&abi, "MyContract", ProgramType::Contract, false)?;
+
+        assert!(code.contains("pub struct MyContract<"));
+        assert!(code.contains("pub fn takes_u32_returns_bool("));
+
+        Ok(())
+    }
+}
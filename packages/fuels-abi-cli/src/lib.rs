@@ -0,0 +1,3 @@
+pub mod abi;
+pub mod args;
+pub mod commands;
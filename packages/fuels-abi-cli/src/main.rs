@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use fuels_abi_cli::{
+    abi::ProgramAbi,
+    commands::{codegen, decode, encode},
+};
+use fuels_code_gen::ProgramType;
+
+/// FuelVM ABI coder
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Encode ABI call
+    Encode {
+        /// Path to the JSON ABI
+        abi: PathBuf,
+        /// Name of the function being called
+        function: String,
+        /// JSON array or object holding the function arguments
+        #[arg(default_value = "[]")]
+        args: String,
+        /// Don't prefix the calldata with the function selector
+        #[arg(long)]
+        no_selector: bool,
+        /// Memory offset used to resolve the pointers of heap types
+        #[arg(long, default_value_t = 0)]
+        offset: u64,
+    },
+    /// Decode ABI call result
+    Decode {
+        /// Path to the JSON ABI
+        abi: PathBuf,
+        /// Name of the function that returned the data
+        function: String,
+        /// Hex encoded return data
+        data: String,
+    },
+    /// Output Rust types file
+    Codegen {
+        /// Path to the JSON ABI
+        abi: PathBuf,
+        /// Name of the generated program type
+        #[arg(long)]
+        name: String,
+        /// One of `Contract`, `Script` or `Predicate`
+        #[arg(long, default_value = "Contract")]
+        program_type: ProgramType,
+        /// Don't use the Rust std library
+        #[arg(long)]
+        no_std: bool,
+    },
+}
+
+fn main() -> Result<()> {
+    let output = match Cli::parse().command {
+        Command::Encode {
+            abi,
+            function,
+            args,
+            no_selector,
+            offset,
+        } => encode(
+            &ProgramAbi::load_from(&abi)?,
+            &function,
+            &args,
+            !no_selector,
+            offset,
+        )?,
+        Command::Decode {
+            abi,
+            function,
+            data,
+        } => decode(&ProgramAbi::load_from(&abi)?, &function, &data)?,
+        Command::Codegen {
+            abi,
+            name,
+            program_type,
+            no_std,
+        } => codegen(&ProgramAbi::load_from(&abi)?, &name, program_type, no_std)?,
+    };
+
+    println!("{output}");
+
+    Ok(())
+}
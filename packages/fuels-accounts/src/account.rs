@@ -10,7 +10,7 @@ use fuels_core::{
         bech32::{Bech32Address, Bech32ContractId},
         coin::Coin,
         coin_type::CoinType,
-        errors::Result,
+        errors::{error, Result},
         input::Input,
         message::Message,
         transaction::{Transaction, TxPolicies},
@@ -22,7 +22,10 @@ use fuels_core::{
 };
 
 use crate::{
-    accounts_utils::{adjust_inputs_outputs, calculate_missing_base_amount, extract_message_nonce},
+    accounts_utils::{
+        adjust_inputs_outputs, calculate_missing_base_amount, extract_message_nonce,
+        inputs_excluding, return_base_change_of_others, send_and_await_commit, send_transfer,
+    },
     provider::{Provider, ResourceFilter},
 };
 
@@ -144,6 +147,12 @@ pub trait Account: ViewOnlyAccount {
         Ok(())
     }
 
+    /// Returns the input spending `resource`, which is owned by this account. Accounts whose
+    /// resources are not unlocked by a signature, like predicates, must override this.
+    fn resource_input(&self, resource: CoinType) -> Input {
+        Input::resource_signed(resource)
+    }
+
     /// Transfer funds from this account to another `Address`.
     /// Fails if amount for asset ID is larger than address's spendable coins.
     /// Returns the transaction ID that was sent and the list of receipts.
@@ -178,6 +187,158 @@ pub trait Account: ViewOnlyAccount {
         Ok((tx_id, receipts))
     }
 
+    /// Transfer funds to multiple recipients, possibly of different assets, in a single
+    /// transaction. Each `(to, asset_id, amount)` gets its own coin output, while the inputs and
+    /// the change are aggregated per asset, so the fee is paid only once.
+    /// Fails if the amount of any asset is larger than the account's spendable coins.
+    /// Returns the transaction ID that was sent and the list of receipts.
+    async fn transfer_batch(
+        &self,
+        transfers: Vec<(Bech32Address, AssetId, u64)>,
+        tx_policies: TxPolicies,
+    ) -> Result<(TxId, Vec<Receipt>)> {
+        if transfers.is_empty() {
+            return Err(error!(Other, "no transfers given"));
+        }
+
+        let mut amounts_per_asset: Vec<(AssetId, u64)> = vec![];
+        for (_, asset_id, amount) in &transfers {
+            match amounts_per_asset.iter_mut().find(|(id, _)| id == asset_id) {
+                Some((_, total)) => {
+                    *total = total.checked_add(*amount).ok_or_else(|| {
+                        error!(Other, "total amount of asset `{asset_id}` overflows")
+                    })?;
+                }
+                None => amounts_per_asset.push((*asset_id, *amount)),
+            }
+        }
+
+        let mut inputs = vec![];
+        for (asset_id, amount) in &amounts_per_asset {
+            inputs.extend(self.get_asset_inputs_for_amount(*asset_id, *amount).await?);
+        }
+
+        let coin_outputs = transfers
+            .iter()
+            .map(|(to, asset_id, amount)| Output::coin(to.into(), *amount, *asset_id));
+        // Note that the change will be computed by the node.
+        let change_outputs = amounts_per_asset
+            .iter()
+            .map(|(asset_id, _)| Output::change(self.address().into(), 0, *asset_id));
+        let outputs = coin_outputs.chain(change_outputs).collect();
+
+        let used_base_amount = amounts_per_asset
+            .iter()
+            .find_map(|(asset_id, amount)| (*asset_id == BASE_ASSET_ID).then_some(*amount))
+            .unwrap_or_default();
+
+        send_transfer(self, inputs, outputs, used_base_amount, tx_policies).await
+    }
+
+    /// Merges the coins of `asset_id` owned by this account. Every transaction spends up to
+    /// `max_inputs_per_tx` coins, starting with the smallest ones, into a single coin. The input
+    /// limit of the consensus parameters is respected as well, keeping an input free for the fee
+    /// when `asset_id` is not the base asset.
+    /// Returns the IDs of the sent transactions.
+    async fn consolidate_coins(
+        &self,
+        asset_id: AssetId,
+        max_inputs_per_tx: usize,
+    ) -> Result<Vec<TxId>> {
+        let provider = self.try_provider()?;
+
+        let max_inputs = provider.consensus_parameters().tx_params().max_inputs as usize;
+        let inputs_for_fee = usize::from(asset_id != BASE_ASSET_ID);
+        let inputs_per_tx = max_inputs_per_tx.min(max_inputs.saturating_sub(inputs_for_fee));
+        if inputs_per_tx < 2 {
+            return Err(error!(
+                Other,
+                "at least 2 inputs per transaction are needed to consolidate coins, got `{inputs_per_tx}`"
+            ));
+        }
+
+        let mut coins = self.get_coins(asset_id).await?;
+        coins.sort_by_key(|coin| coin.amount);
+
+        let mut tx_ids = vec![];
+        for chunk in coins.chunks(inputs_per_tx).filter(|chunk| chunk.len() > 1) {
+            let inputs = chunk
+                .iter()
+                .map(|coin| self.resource_input(CoinType::Coin(coin.clone())))
+                .collect();
+            // Note that the change will be computed by the node.
+            let outputs = vec![Output::change(self.address().into(), 0, asset_id)];
+
+            let (tx_id, _) = send_transfer(self, inputs, outputs, 0, TxPolicies::default()).await?;
+            tx_ids.push(tx_id);
+        }
+
+        Ok(tx_ids)
+    }
+
+    /// Creates `n` coins of `amount` of `asset_id` owned by this account, so that they can be
+    /// spent by concurrent transactions. The coins are spread over as many transactions as the
+    /// output limit of the consensus parameters requires.
+    /// Returns the IDs of the sent transactions.
+    async fn split_coins(&self, asset_id: AssetId, n: usize, amount: u64) -> Result<Vec<TxId>> {
+        let provider = self.try_provider()?;
+
+        if n == 0 || amount == 0 {
+            return Err(error!(
+                Other,
+                "cannot split coins into `{n}` coins of amount `{amount}`"
+            ));
+        }
+
+        // The change of `asset_id`, and of the base asset paying the fee, need an output each
+        let max_outputs = provider.consensus_parameters().tx_params().max_outputs as usize;
+        let change_outputs = if asset_id == BASE_ASSET_ID { 1 } else { 2 };
+        let coins_per_tx = max_outputs.saturating_sub(change_outputs).max(1);
+
+        let mut tx_ids = vec![];
+        // Coins split off by earlier transactions must not be spent by the later ones
+        let mut split_coins = vec![];
+        let mut coins_left = n;
+        while coins_left > 0 {
+            let num_coins = coins_left.min(coins_per_tx);
+            let total = (num_coins as u64)
+                .checked_mul(amount)
+                .ok_or_else(|| error!(Other, "total amount of the split coins overflows"))?;
+
+            let inputs = inputs_excluding(self, asset_id, total, split_coins.clone()).await?;
+            let outputs = std::iter::repeat(Output::coin(self.address().into(), amount, asset_id))
+                .take(num_coins)
+                .chain([Output::change(self.address().into(), 0, asset_id)])
+                .collect();
+
+            let mut tx_builder =
+                ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+            self.add_witnesses(&mut tx_builder)?;
+
+            let used_base_amount = if asset_id == BASE_ASSET_ID { total } else { 0 };
+            let missing_base_amount =
+                calculate_missing_base_amount(&tx_builder, used_base_amount, provider).await?;
+            if missing_base_amount > 0 {
+                let fee_inputs = inputs_excluding(
+                    self,
+                    BASE_ASSET_ID,
+                    missing_base_amount,
+                    split_coins.clone(),
+                )
+                .await?;
+                adjust_inputs_outputs(&mut tx_builder, fee_inputs, self.address());
+            }
+
+            let (tx_id, _) = send_and_await_commit(tx_builder, provider).await?;
+
+            split_coins.extend((0..num_coins).map(|index| UtxoId::new(tx_id, index as u8)));
+            tx_ids.push(tx_id);
+            coins_left -= num_coins;
+        }
+
+        Ok(tx_ids)
+    }
+
     /// Unconditionally transfers `balance` of type `asset_id` to
     /// the contract at `to`.
     /// Fails if balance for `asset_id` is larger than this account's spendable balance.
@@ -276,6 +437,45 @@ pub trait Account: ViewOnlyAccount {
     }
 }
 
+/// An account paying the fee of script transactions on behalf of another account, e.g. of a user
+/// holding no base asset.
+///
+/// Implemented for every [`Account`]. Unlike [`Account`], it can be used as a trait object.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait FeePayer: std::fmt::Debug + Send + Sync {
+    /// Adds the base asset inputs needed to cover the fee and `used_base_amount`, a base asset
+    /// change output owned by the fee payer and the witnesses of the fee payer.
+    ///
+    /// A transaction can have only one change output per asset. Base asset the other account
+    /// puts in beyond `used_base_amount` is therefore returned to it with a coin output, instead
+    /// of its change output, so that the fee payer keeps the refunded fee.
+    ///
+    /// The inputs and witnesses of the other account have to be added beforehand, so that the
+    /// fee accounts for them. As the signatures are only made once the transaction is built,
+    /// the signatures of both accounts stay valid.
+    async fn pay_fee(&self, tb: &mut ScriptTransactionBuilder, used_base_amount: u64)
+        -> Result<()>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<A: Account> FeePayer for A {
+    async fn pay_fee(
+        &self,
+        tb: &mut ScriptTransactionBuilder,
+        used_base_amount: u64,
+    ) -> Result<()> {
+        let returned_base_amount =
+            return_base_change_of_others(tb, used_base_amount, self.address());
+
+        self.add_witnesses(tb)?;
+        self.adjust_for_fee(tb, used_base_amount + returned_base_amount)
+            .await?;
+        adjust_inputs_outputs(tb, [], self.address());
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -1,16 +1,22 @@
-use fuel_tx::{Output, Receipt};
-use fuel_types::Nonce;
+use fuel_tx::{Output, Receipt, TxId, UtxoId};
+use fuel_types::{AssetId, Nonce};
 use fuels_core::{
     constants::BASE_ASSET_ID,
     types::{
         bech32::Bech32Address,
         errors::{error, error_transaction, Error, Result},
         input::Input,
-        transaction_builders::TransactionBuilder,
+        transaction::{Transaction, TxPolicies},
+        transaction_builders::{
+            BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
+        },
     },
 };
 
-use crate::provider::Provider;
+use crate::{
+    provider::{Provider, ResourceFilter},
+    Account,
+};
 
 pub fn extract_message_nonce(receipts: &[Receipt]) -> Option<Nonce> {
     receipts.iter().find_map(|m| m.nonce()).copied()
@@ -61,6 +67,65 @@ fn is_consuming_utxos(tb: &impl TransactionBuilder) -> bool {
         .any(|input| !matches!(input, Input::Contract { .. }))
 }
 
+/// Sends a transfer of `inputs` to `outputs` signed, and with the fee paid, by `account`.
+pub async fn send_transfer(
+    account: &impl Account,
+    inputs: Vec<Input>,
+    outputs: Vec<Output>,
+    used_base_amount: u64,
+    tx_policies: TxPolicies,
+) -> Result<(TxId, Vec<Receipt>)> {
+    let provider = account.try_provider()?;
+
+    let mut tx_builder = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+
+    account.add_witnesses(&mut tx_builder)?;
+    account
+        .adjust_for_fee(&mut tx_builder, used_base_amount)
+        .await?;
+
+    send_and_await_commit(tx_builder, provider).await
+}
+
+pub async fn send_and_await_commit(
+    tx_builder: ScriptTransactionBuilder,
+    provider: &Provider,
+) -> Result<(TxId, Vec<Receipt>)> {
+    let tx = tx_builder.build(provider).await?;
+    let tx_id = tx.id(provider.chain_id());
+
+    let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+
+    let receipts = tx_status.take_receipts_checked(None)?;
+
+    Ok((tx_id, receipts))
+}
+
+/// Returns inputs spending resources of `account` that cover `amount` of `asset_id`, without
+/// using any of the `excluded_utxos`.
+pub async fn inputs_excluding(
+    account: &impl Account,
+    asset_id: AssetId,
+    amount: u64,
+    excluded_utxos: Vec<UtxoId>,
+) -> Result<Vec<Input>> {
+    let filter = ResourceFilter {
+        from: account.address().clone(),
+        asset_id,
+        amount,
+        excluded_utxos,
+        ..Default::default()
+    };
+
+    Ok(account
+        .try_provider()?
+        .get_spendable_resources(filter)
+        .await?
+        .into_iter()
+        .map(|resource| account.resource_input(resource))
+        .collect())
+}
+
 pub fn adjust_inputs_outputs(
     tb: &mut impl TransactionBuilder,
     new_base_inputs: impl IntoIterator<Item = Input>,
@@ -79,6 +144,55 @@ pub fn adjust_inputs_outputs(
     }
 }
 
+/// Makes room for the base asset change output of `fee_payer`, as a transaction can have only one
+/// change output per asset. The base asset change outputs of other accounts are replaced by a coin
+/// output returning what their inputs hold beyond `used_base_amount`. Returns the returned amount.
+pub fn return_base_change_of_others(
+    tb: &mut impl TransactionBuilder,
+    used_base_amount: u64,
+    fee_payer: &Bech32Address,
+) -> u64 {
+    let others_base_inputs: Vec<_> = tb
+        .inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::ResourceSigned { resource } | Input::ResourcePredicate { resource, .. }
+                if resource.asset_id() == BASE_ASSET_ID && resource.owner() != fee_payer =>
+            {
+                Some(resource)
+            }
+            _ => None,
+        })
+        .collect();
+    let returned_amount = others_base_inputs
+        .iter()
+        .map(|resource| resource.amount())
+        .sum::<u64>()
+        .saturating_sub(used_base_amount);
+    let first_owner = others_base_inputs
+        .first()
+        .map(|resource| resource.owner().into());
+
+    let fee_payer = fee_payer.into();
+    let mut change_owner = None;
+    tb.outputs_mut().retain(|output| match output {
+        Output::Change { to, asset_id, .. } if *asset_id == BASE_ASSET_ID && *to != fee_payer => {
+            change_owner.get_or_insert(*to);
+            false
+        }
+        _ => true,
+    });
+
+    match change_owner.or(first_owner) {
+        Some(owner) if returned_amount > 0 => {
+            tb.outputs_mut()
+                .push(Output::coin(owner, returned_amount, BASE_ASSET_ID));
+            returned_amount
+        }
+        _ => 0,
+    }
+}
+
 pub(crate) fn try_provider_error() -> Error {
     error!(
         Other,
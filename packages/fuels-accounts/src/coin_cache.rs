@@ -1,18 +1,78 @@
 use std::{
     collections::{HashMap, HashSet},
-    hash::{Hash, Hasher},
+    fmt::Debug,
+    fs::{self, Metadata, OpenOptions},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{SystemTime, UNIX_EPOCH},
 };
 
-use fuel_types::AssetId;
-use fuels_core::types::{bech32::Bech32Address, coin_type_id::CoinTypeId};
+use async_trait::async_trait;
+use fuel_tx::{TxId, UtxoId};
+use fuel_types::{AssetId, Bytes32, Nonce};
+use fuels_core::types::{
+    bech32::Bech32Address,
+    coin_type_id::CoinTypeId,
+    errors::{error, Result},
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use tokio::time::{Duration, Instant};
 
-type CoinCacheKey = (Bech32Address, AssetId);
+pub type CoinCacheKey = (Bech32Address, AssetId);
+
+/// Keeps track of the coins selected for, and spent by, submitted transactions, so that they
+/// aren't selected again before the transactions are resolved.
+///
+/// Coins are reserved as soon as they are selected, under the reservation id of the selecting
+/// provider, and the reservation is taken over by the transaction spending them once it is
+/// submitted. A lock is released once the status of its transaction is resolved or, at the latest,
+/// when its time to live runs out. Stores are shared by cloning a `Provider` or by giving the same
+/// store, or a store backed by the same file, to several providers through
+/// `Provider::with_coin_lock_store`.
+#[async_trait]
+pub trait CoinLockStore: Debug + Send + Sync {
+    /// Locks the coins spent by the transaction `tx_id` for `ttl`, taking them over from their
+    /// reservations.
+    async fn lock(
+        &self,
+        tx_id: TxId,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+        ttl: Duration,
+    ) -> Result<()>;
+
+    /// Reserves `coins` for `ttl` under `reservation_id`, unless some of them are locked by a
+    /// transaction or reserved under another id. The check and the reservation must be atomic, so
+    /// that providers sharing the store never select the same coins. Returns the coins that are
+    /// taken, in which case nothing is reserved.
+    async fn reserve(
+        &self,
+        reservation_id: Bytes32,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+        ttl: Duration,
+    ) -> Result<HashSet<CoinTypeId>>;
+
+    /// Returns the coins of `key` that are currently locked by a transaction or reserved under an
+    /// id other than `reservation_id`.
+    async fn locked(
+        &self,
+        key: &CoinCacheKey,
+        reservation_id: &Bytes32,
+    ) -> Result<HashSet<CoinTypeId>>;
+
+    /// Releases the coins locked for the transaction `tx_id`.
+    async fn release(&self, tx_id: &TxId) -> Result<()>;
+}
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct CoinsCache {
     ttl: Duration,
-    items: HashMap<CoinCacheKey, HashSet<CoinCacheItem>>,
+    store: Arc<dyn CoinLockStore>,
+    // Coins are reserved per cache, so that the selections of the provider it belongs to, e.g.
+    // for estimating the cost of a transaction and then submitting it, don't exclude each other.
+    reservation_id: Bytes32,
 }
 
 impl Default for CoinsCache {
@@ -25,95 +85,461 @@ impl CoinsCache {
     pub fn new(ttl: Duration) -> Self {
         Self {
             ttl,
-            items: HashMap::default(),
+            store: Arc::new(InMemoryCoinLockStore::default()),
+            reservation_id: rand::thread_rng().gen(),
         }
     }
 
-    pub fn insert_multiple(
-        &mut self,
-        coin_ids: impl IntoIterator<Item = (CoinCacheKey, Vec<CoinTypeId>)>,
-    ) {
-        for (key, ids) in coin_ids {
-            let new_items = ids.into_iter().map(CoinCacheItem::new);
+    pub fn with_store(mut self, store: impl CoinLockStore + 'static) -> Self {
+        self.store = Arc::new(store);
 
-            let items = self.items.entry(key.clone()).or_default();
-            items.extend(new_items);
-        }
+        self
+    }
+
+    pub async fn lock(
+        &self,
+        tx_id: TxId,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+    ) -> Result<()> {
+        self.store.lock(tx_id, coins, self.ttl).await
+    }
+
+    /// Reserves `coins` for a transaction that isn't submitted yet. Returns the coins that are
+    /// taken already, in which case nothing is reserved.
+    pub async fn reserve(
+        &self,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+    ) -> Result<HashSet<CoinTypeId>> {
+        self.store
+            .reserve(self.reservation_id, coins, self.ttl)
+            .await
+    }
+
+    pub async fn get_active(&self, key: &CoinCacheKey) -> Result<HashSet<CoinTypeId>> {
+        self.store.locked(key, &self.reservation_id).await
+    }
+
+    pub async fn release(&self, tx_id: &TxId) -> Result<()> {
+        self.store.release(tx_id).await
+    }
+}
+
+/// Keeps the locks in memory. This is the store a `Provider` starts with.
+#[derive(Debug, Default)]
+pub struct InMemoryCoinLockStore {
+    locks: Mutex<InMemoryLocks>,
+}
+
+type InMemoryLocks = HashMap<CoinCacheKey, HashMap<CoinTypeId, InMemoryLock>>;
+
+#[derive(Debug, Clone, Copy)]
+struct InMemoryLock {
+    // the id of the transaction or of the reservation holding the coin
+    lock_id: Bytes32,
+    expires_at: Instant,
+}
+
+impl InMemoryCoinLockStore {
+    fn locks(&self) -> MutexGuard<'_, InMemoryLocks> {
+        // the locks stay consistent even if a thread panicked while holding the mutex
+        self.locks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
     }
+}
 
-    pub fn get_active(&mut self, key: &CoinCacheKey) -> HashSet<CoinTypeId> {
-        self.remove_expired_entries(key);
+#[async_trait]
+impl CoinLockStore for InMemoryCoinLockStore {
+    async fn lock(
+        &self,
+        tx_id: TxId,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+        ttl: Duration,
+    ) -> Result<()> {
+        let expires_at = Instant::now() + ttl;
+        let mut locks = self.locks();
+
+        for (key, ids) in coins {
+            let locked = locks.entry(key).or_default();
+            locked.extend(ids.into_iter().map(|id| {
+                let lock = InMemoryLock {
+                    lock_id: tx_id,
+                    expires_at,
+                };
+                (id, lock)
+            }));
+        }
 
-        self.items
-            .get(key)
+        Ok(())
+    }
+
+    async fn reserve(
+        &self,
+        reservation_id: Bytes32,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+        ttl: Duration,
+    ) -> Result<HashSet<CoinTypeId>> {
+        let now = Instant::now();
+        let mut locks = self.locks();
+
+        let taken: HashSet<CoinTypeId> = coins
+            .iter()
+            .flat_map(|(key, ids)| {
+                let locked = locks.get(key);
+                ids.iter().filter(move |id| {
+                    locked
+                        .and_then(|locked| locked.get(id))
+                        .is_some_and(|lock| lock.expires_at > now && lock.lock_id != reservation_id)
+                })
+            })
             .cloned()
-            .unwrap_or_default()
-            .into_iter()
-            .map(|item| item.id)
-            .collect()
-    }
-
-    pub fn remove_items(
-        &mut self,
-        inputs: impl IntoIterator<Item = (CoinCacheKey, Vec<CoinTypeId>)>,
-    ) {
-        for (key, ids) in inputs {
-            for id in ids {
-                self.remove(&key, id);
+            .collect();
+
+        if taken.is_empty() {
+            let expires_at = now + ttl;
+            for (key, ids) in coins {
+                let locked = locks.entry(key).or_default();
+                locked.extend(ids.into_iter().map(|id| {
+                    let lock = InMemoryLock {
+                        lock_id: reservation_id,
+                        expires_at,
+                    };
+                    (id, lock)
+                }));
             }
         }
+
+        Ok(taken)
     }
 
-    fn remove(&mut self, key: &CoinCacheKey, id: CoinTypeId) {
-        if let Some(ids) = self.items.get_mut(key) {
-            let item = CoinCacheItem::new(id);
-            ids.remove(&item);
-        }
+    async fn locked(
+        &self,
+        key: &CoinCacheKey,
+        reservation_id: &Bytes32,
+    ) -> Result<HashSet<CoinTypeId>> {
+        let mut locks = self.locks();
+        let Some(locked) = locks.get_mut(key) else {
+            return Ok(HashSet::new());
+        };
+
+        let now = Instant::now();
+        locked.retain(|_, lock| lock.expires_at > now);
+
+        Ok(locked
+            .iter()
+            .filter(|(_, lock)| lock.lock_id != *reservation_id)
+            .map(|(id, _)| id.clone())
+            .collect())
     }
 
-    fn remove_expired_entries(&mut self, key: &CoinCacheKey) {
-        if let Some(entry) = self.items.get_mut(key) {
-            entry.retain(|item| item.is_valid(self.ttl));
+    async fn release(&self, tx_id: &TxId) -> Result<()> {
+        for locked in self.locks().values_mut() {
+            locked.retain(|_, lock| lock.lock_id != *tx_id);
         }
+
+        Ok(())
     }
 }
 
-#[derive(Eq, Debug, Clone)]
-struct CoinCacheItem {
-    created_at: Instant,
-    pub id: CoinTypeId,
+/// Keeps the locks in a JSON file, so that they are shared by all processes using the same path.
+///
+/// Access to the file is serialized through a lock file placed next to it. A lock file older than
+/// `STALE_LOCK_FILE_AGE`, e.g. left behind by a crashed process, is removed. Expiry is based on the
+/// system clock, which should therefore agree between the processes sharing the file.
+#[derive(Debug, Clone)]
+pub struct FileCoinLockStore {
+    path: PathBuf,
+    stale_lock_file_age: Duration,
 }
 
-impl PartialEq for CoinCacheItem {
-    fn eq(&self, other: &Self) -> bool {
-        self.id.eq(&other.id)
+const STALE_LOCK_FILE_AGE: Duration = Duration::from_secs(10);
+const LOCK_FILE_TIMEOUT: Duration = Duration::from_secs(30);
+const LOCK_FILE_RETRY_INTERVAL: Duration = Duration::from_millis(5);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct FileLock {
+    owner: String,
+    asset_id: String,
+    coin_id: String,
+    /// The id of the transaction or of the reservation holding the coin.
+    lock_id: String,
+    expires_at_ms: u64,
+}
+
+impl FileLock {
+    fn new(
+        key: &CoinCacheKey,
+        coin_id: &CoinTypeId,
+        lock_id: &Bytes32,
+        expires_at_ms: u64,
+    ) -> Self {
+        Self {
+            owner: key.0.to_string(),
+            asset_id: format!("{:#x}", key.1),
+            coin_id: Self::format_coin_id(coin_id),
+            lock_id: format!("{lock_id:#x}"),
+            expires_at_ms,
+        }
+    }
+
+    fn format_coin_id(coin_id: &CoinTypeId) -> String {
+        match coin_id {
+            CoinTypeId::UtxoId(utxo_id) => format!("utxo:{utxo_id:#x}"),
+            CoinTypeId::Nonce(nonce) => format!("nonce:{nonce:#x}"),
+        }
+    }
+
+    fn is_for(&self, key: &CoinCacheKey) -> bool {
+        self.owner == key.0.to_string() && self.asset_id == format!("{:#x}", key.1)
+    }
+
+    fn coin_id(&self) -> Result<CoinTypeId> {
+        let invalid = || {
+            error!(
+                Other,
+                "invalid coin id `{}` in coin lock file", self.coin_id
+            )
+        };
+
+        match self.coin_id.split_once(':') {
+            Some(("utxo", id)) => UtxoId::from_str(id)
+                .map(CoinTypeId::UtxoId)
+                .map_err(|_| invalid()),
+            Some(("nonce", id)) => Nonce::from_str(id)
+                .map(CoinTypeId::Nonce)
+                .map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
     }
 }
 
-impl Hash for CoinCacheItem {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.id.hash(state);
+/// Identifies a lock file, so that a stale lock file is told apart from one created again by
+/// another process in the meantime.
+#[derive(Debug, PartialEq, Eq)]
+struct LockFileId {
+    modified: SystemTime,
+    #[cfg(unix)]
+    inode: u64,
+}
+
+impl LockFileId {
+    fn of(metadata: &Metadata) -> std::io::Result<Self> {
+        Ok(Self {
+            modified: metadata.modified()?,
+            #[cfg(unix)]
+            inode: std::os::unix::fs::MetadataExt::ino(metadata),
+        })
     }
 }
 
-impl CoinCacheItem {
-    pub fn new(id: CoinTypeId) -> Self {
+impl FileCoinLockStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
         Self {
-            created_at: Instant::now(),
-            id,
+            path: path.into(),
+            stale_lock_file_age: STALE_LOCK_FILE_AGE,
+        }
+    }
+
+    /// Runs `f` on the unexpired locks in the file, holding the lock file so that no other
+    /// process changes the file in the meantime. Whatever `f` leaves behind is written back.
+    async fn update<T>(&self, f: impl FnOnce(&mut Vec<FileLock>) -> Result<T> + Send) -> Result<T> {
+        let _guard = self.acquire_lock_file().await?;
+
+        let mut locks: Vec<FileLock> = match fs::read(&self.path) {
+            Ok(contents) if !contents.is_empty() => serde_json::from_slice(&contents)
+                .map_err(|e| error!(Other, "could not parse the coin lock file: {e}"))?,
+            Ok(_) => vec![],
+            Err(e) if e.kind() == ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e.into()),
+        };
+
+        let now = now_ms();
+        locks.retain(|lock| lock.expires_at_ms > now);
+
+        let result = f(&mut locks)?;
+
+        let contents = serde_json::to_vec(&locks)
+            .map_err(|e| error!(Other, "could not serialize the coin locks: {e}"))?;
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, contents)?;
+        fs::rename(tmp_path, &self.path)?;
+
+        Ok(result)
+    }
+
+    async fn acquire_lock_file(&self) -> Result<LockFileGuard> {
+        let path = self.path.with_extension("lock");
+        let started = Instant::now();
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(LockFileGuard { path }),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if let Some(stale_id) = self.stale_lock_file_id(&path) {
+                        remove_stale_lock_file(&path, &stale_id)?;
+                    } else if started.elapsed() > LOCK_FILE_TIMEOUT {
+                        return Err(std::io::Error::new(
+                            ErrorKind::TimedOut,
+                            format!(
+                                "timed out waiting for the coin lock file `{}`",
+                                path.display()
+                            ),
+                        )
+                        .into());
+                    } else {
+                        tokio::time::sleep(LOCK_FILE_RETRY_INTERVAL).await;
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            }
         }
     }
 
-    pub fn is_valid(&self, ttl: Duration) -> bool {
-        self.created_at + ttl > Instant::now()
+    fn stale_lock_file_id(&self, path: &Path) -> Option<LockFileId> {
+        let metadata = fs::metadata(path).ok()?;
+        let age = metadata.modified().ok()?.elapsed().ok()?;
+
+        if age > self.stale_lock_file_age {
+            LockFileId::of(&metadata).ok()
+        } else {
+            None
+        }
+    }
+}
+
+/// Removes the lock file at `path` if it still is the stale one identified by `stale_id`. The file
+/// is moved away first, which is atomic, so that a lock file another process created after removing
+/// the stale one itself can't be removed by mistake.
+fn remove_stale_lock_file(path: &Path, stale_id: &LockFileId) -> Result<()> {
+    let suffix: u64 = rand::thread_rng().gen();
+    let moved_path = path.with_extension(format!("lock.{suffix:x}"));
+
+    match fs::rename(path, &moved_path) {
+        Ok(()) => {}
+        // another process removed it already
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    }
+
+    if LockFileId::of(&fs::metadata(&moved_path)?)? != *stale_id {
+        // a lock file held by another process, which is put back unless a new one was created
+        let _ = fs::hard_link(&moved_path, path);
+    }
+    fs::remove_file(&moved_path)?;
+
+    Ok(())
+}
+
+struct LockFileGuard {
+    path: PathBuf,
+}
+
+impl Drop for LockFileGuard {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[async_trait]
+impl CoinLockStore for FileCoinLockStore {
+    async fn lock(
+        &self,
+        tx_id: TxId,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+        ttl: Duration,
+    ) -> Result<()> {
+        let expires_at_ms = now_ms().saturating_add(ttl.as_millis() as u64);
+
+        self.update(|locks| {
+            for (key, ids) in &coins {
+                for id in ids {
+                    let new_lock = FileLock::new(key, id, &tx_id, expires_at_ms);
+                    locks.retain(|lock| !(lock.coin_id == new_lock.coin_id && lock.is_for(key)));
+                    locks.push(new_lock);
+                }
+            }
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn reserve(
+        &self,
+        reservation_id: Bytes32,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+        ttl: Duration,
+    ) -> Result<HashSet<CoinTypeId>> {
+        let expires_at_ms = now_ms().saturating_add(ttl.as_millis() as u64);
+        let own_lock_id = format!("{reservation_id:#x}");
+
+        self.update(|locks| {
+            let mut taken = HashSet::new();
+            for (key, ids) in &coins {
+                for id in ids {
+                    let coin_id = FileLock::format_coin_id(id);
+                    if locks.iter().any(|lock| {
+                        lock.coin_id == coin_id && lock.is_for(key) && lock.lock_id != own_lock_id
+                    }) {
+                        taken.insert(id.clone());
+                    }
+                }
+            }
+
+            if taken.is_empty() {
+                for (key, ids) in &coins {
+                    for id in ids {
+                        let new_lock = FileLock::new(key, id, &reservation_id, expires_at_ms);
+                        locks
+                            .retain(|lock| !(lock.coin_id == new_lock.coin_id && lock.is_for(key)));
+                        locks.push(new_lock);
+                    }
+                }
+            }
+
+            Ok(taken)
+        })
+        .await
+    }
+
+    async fn locked(
+        &self,
+        key: &CoinCacheKey,
+        reservation_id: &Bytes32,
+    ) -> Result<HashSet<CoinTypeId>> {
+        let own_lock_id = format!("{reservation_id:#x}");
+
+        self.update(|locks| {
+            locks
+                .iter()
+                .filter(|lock| lock.is_for(key) && lock.lock_id != own_lock_id)
+                .map(FileLock::coin_id)
+                .collect()
+        })
+        .await
+    }
+
+    async fn release(&self, tx_id: &TxId) -> Result<()> {
+        let tx_id = format!("{tx_id:#x}");
+
+        self.update(|locks| {
+            locks.retain(|lock| lock.lock_id != tx_id);
+
+            Ok(())
+        })
+        .await
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use fuel_tx::UtxoId;
-    use fuel_types::{Bytes32, Nonce};
-
     use super::*;
 
     fn get_items() -> (CoinTypeId, CoinTypeId) {
@@ -123,32 +549,34 @@ mod tests {
         (CoinTypeId::UtxoId(utxo_id), CoinTypeId::Nonce(nonce))
     }
 
-    #[test]
-    fn test_insert_and_get_active() {
-        let mut cache = CoinsCache::new(Duration::from_secs(60));
+    #[tokio::test]
+    async fn test_insert_and_get_active() -> Result<()> {
+        let cache = CoinsCache::new(Duration::from_secs(60));
 
         let key: CoinCacheKey = Default::default();
         let (item1, item2) = get_items();
         let items = HashMap::from([(key.clone(), vec![item1.clone(), item2.clone()])]);
 
-        cache.insert_multiple(items);
+        cache.lock(TxId::zeroed(), items).await?;
 
-        let active_coins = cache.get_active(&key);
+        let active_coins = cache.get_active(&key).await?;
 
         assert_eq!(active_coins.len(), 2);
         assert!(active_coins.contains(&item1));
         assert!(active_coins.contains(&item2));
+
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_insert_and_expire_items() {
-        let mut cache = CoinsCache::new(Duration::from_secs(10));
+    async fn test_insert_and_expire_items() -> Result<()> {
+        let cache = CoinsCache::new(Duration::from_secs(10));
 
         let key = CoinCacheKey::default();
         let (item1, _) = get_items();
         let items = HashMap::from([(key.clone(), vec![item1.clone()])]);
 
-        cache.insert_multiple(items);
+        cache.lock(TxId::zeroed(), items).await?;
 
         // Advance time by more than the cache's TTL
         tokio::time::pause();
@@ -156,42 +584,225 @@ mod tests {
 
         let (_, item2) = get_items();
         let items = HashMap::from([(key.clone(), vec![item2.clone()])]);
-        cache.insert_multiple(items);
+        cache.lock(TxId::new([1; 32]), items).await?;
 
-        let active_coins = cache.get_active(&key);
+        let active_coins = cache.get_active(&key).await?;
 
         assert_eq!(active_coins.len(), 1);
         assert!(!active_coins.contains(&item1));
         assert!(active_coins.contains(&item2));
+
+        Ok(())
     }
 
-    #[test]
-    fn test_get_active_no_items() {
-        let mut cache = CoinsCache::new(Duration::from_secs(60));
+    #[tokio::test]
+    async fn test_get_active_no_items() -> Result<()> {
+        let cache = CoinsCache::new(Duration::from_secs(60));
 
         let key = Default::default();
-        let active_coins = cache.get_active(&key);
+        let active_coins = cache.get_active(&key).await?;
 
         assert!(active_coins.is_empty());
+
+        Ok(())
     }
 
-    #[test]
-    fn test_remove_items() {
-        let mut cache = CoinsCache::new(Duration::from_secs(60));
+    #[tokio::test]
+    async fn test_release() -> Result<()> {
+        let cache = CoinsCache::new(Duration::from_secs(60));
 
         let key: CoinCacheKey = Default::default();
         let (item1, item2) = get_items();
+        let released_tx_id = TxId::new([1; 32]);
 
-        let items_to_insert = [(key.clone(), vec![item1.clone(), item2.clone()])];
-        cache.insert_multiple(items_to_insert.iter().cloned());
+        cache
+            .lock(
+                released_tx_id,
+                HashMap::from([(key.clone(), vec![item1.clone()])]),
+            )
+            .await?;
+        cache
+            .lock(
+                TxId::new([2; 32]),
+                HashMap::from([(key.clone(), vec![item2.clone()])]),
+            )
+            .await?;
 
-        let items_to_remove = [(key.clone(), vec![item1.clone()])];
-        cache.remove_items(items_to_remove.iter().cloned());
+        cache.release(&released_tx_id).await?;
 
-        let active_coins = cache.get_active(&key);
+        let active_coins = cache.get_active(&key).await?;
 
         assert_eq!(active_coins.len(), 1);
         assert!(!active_coins.contains(&item1));
         assert!(active_coins.contains(&item2));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_is_shared_through_the_file() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("coin_locks.json");
+        let cache = CoinsCache::default().with_store(FileCoinLockStore::new(&path));
+        let other_cache = CoinsCache::default().with_store(FileCoinLockStore::new(&path));
+
+        let key: CoinCacheKey = Default::default();
+        let (item1, item2) = get_items();
+        let tx_id = TxId::new([1; 32]);
+        cache
+            .lock(
+                tx_id,
+                HashMap::from([(key.clone(), vec![item1.clone(), item2.clone()])]),
+            )
+            .await?;
+
+        assert_eq!(
+            other_cache.get_active(&key).await?,
+            HashSet::from([item1, item2])
+        );
+        assert!(other_cache
+            .get_active(&(key.0.clone(), AssetId::new([1; 32])))
+            .await?
+            .is_empty());
+
+        other_cache.release(&tx_id).await?;
+
+        assert!(cache.get_active(&key).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_drops_expired_locks() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = CoinsCache::new(Duration::ZERO)
+            .with_store(FileCoinLockStore::new(dir.path().join("coin_locks.json")));
+
+        let key: CoinCacheKey = Default::default();
+        let (item1, _) = get_items();
+        cache
+            .lock(TxId::zeroed(), HashMap::from([(key.clone(), vec![item1])]))
+            .await?;
+
+        assert!(cache.get_active(&key).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_removes_stale_lock_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("coin_locks.json");
+        fs::File::create(path.with_extension("lock"))?;
+        std::thread::sleep(Duration::from_millis(10));
+
+        let store = FileCoinLockStore {
+            stale_lock_file_age: Duration::from_millis(1),
+            ..FileCoinLockStore::new(&path)
+        };
+        let active_coins = store
+            .locked(&Default::default(), &Bytes32::zeroed())
+            .await?;
+
+        assert!(active_coins.is_empty());
+        assert!(!path.with_extension("lock").exists());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reserved_coins_are_not_selected_by_other_providers() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("coin_locks.json");
+        let cache = CoinsCache::default().with_store(FileCoinLockStore::new(&path));
+        let other_cache = CoinsCache::default().with_store(FileCoinLockStore::new(&path));
+
+        let key: CoinCacheKey = Default::default();
+        let (item1, item2) = get_items();
+        let coins = HashMap::from([(key.clone(), vec![item1.clone()])]);
+
+        assert!(cache.reserve(coins.clone()).await?.is_empty());
+        // reserving again, e.g. when selecting for the same transaction again, renews the
+        // reservation, while its own reservations don't keep a provider from selecting the coins
+        assert!(cache.reserve(coins.clone()).await?.is_empty());
+        assert!(cache.get_active(&key).await?.is_empty());
+
+        let other_coins = HashMap::from([(key.clone(), vec![item1.clone(), item2.clone()])]);
+        assert_eq!(
+            other_cache.reserve(other_coins.clone()).await?,
+            HashSet::from([item1.clone()])
+        );
+        assert_eq!(
+            other_cache.get_active(&key).await?,
+            HashSet::from([item1.clone()])
+        );
+        // nothing was reserved, as some of the coins were taken
+        assert!(cache.get_active(&key).await?.is_empty());
+
+        // submitting a transaction takes the reservation over
+        let tx_id = TxId::new([1; 32]);
+        cache.lock(tx_id, coins).await?;
+        assert_eq!(cache.get_active(&key).await?, HashSet::from([item1]));
+
+        cache.release(&tx_id).await?;
+        assert!(other_cache.reserve(other_coins).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_reserves_coins() -> Result<()> {
+        let store = InMemoryCoinLockStore::default();
+        let ttl = Duration::from_secs(60);
+
+        let key: CoinCacheKey = Default::default();
+        let (item1, _) = get_items();
+        let coins = HashMap::from([(key.clone(), vec![item1.clone()])]);
+        let reservation_id = Bytes32::new([1; 32]);
+        let other_reservation_id = Bytes32::new([2; 32]);
+
+        assert!(store
+            .reserve(reservation_id, coins.clone(), ttl)
+            .await?
+            .is_empty());
+        assert!(store.locked(&key, &reservation_id).await?.is_empty());
+
+        assert_eq!(
+            store
+                .reserve(other_reservation_id, coins.clone(), ttl)
+                .await?,
+            HashSet::from([item1.clone()])
+        );
+        assert_eq!(
+            store.locked(&key, &other_reservation_id).await?,
+            HashSet::from([item1])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn lock_files_created_again_are_not_removed_as_stale() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("coin_locks.lock");
+        fs::File::create(&path)?;
+        let stale_id = LockFileId::of(&fs::metadata(&path)?)?;
+
+        // another process removes the stale lock file and creates its own
+        fs::remove_file(&path)?;
+        std::thread::sleep(Duration::from_millis(10));
+        fs::File::create(&path)?;
+        let fresh_id = LockFileId::of(&fs::metadata(&path)?)?;
+
+        remove_stale_lock_file(&path, &stale_id)?;
+
+        assert_eq!(LockFileId::of(&fs::metadata(&path)?)?, fresh_id);
+        assert_eq!(fs::read_dir(dir.path())?.count(), 1);
+
+        remove_stale_lock_file(&path, &fresh_id)?;
+
+        assert!(!path.exists());
+
+        Ok(())
     }
 }
@@ -0,0 +1,299 @@
+use std::fmt::Debug;
+
+use fuels_core::types::{
+    coin_type::CoinType,
+    errors::{error, Result},
+};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// Selects the resources spent to cover an amount of an asset.
+///
+/// A `Provider` with a coin selector set through `Provider::with_coin_selector` fetches all
+/// spendable coins and messages of an account with `get_coins` and `get_messages` and lets the
+/// selector pick from them, instead of leaving the selection to the node's `coins_to_spend`
+/// query. This applies to everything funded through `Account::get_asset_inputs_for_amount`,
+/// including contract and script calls.
+// ANCHOR: coin_selector
+pub trait CoinSelector: Debug + Send + Sync {
+    /// Returns resources from `candidates` whose amounts add up to at least `amount`. All
+    /// `candidates` are of the same asset.
+    fn select(&self, candidates: Vec<CoinType>, amount: u64) -> Result<Vec<CoinType>>;
+}
+// ANCHOR_END: coin_selector
+
+/// Spends the largest resources first, using as few inputs as possible.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LargestFirst;
+
+impl CoinSelector for LargestFirst {
+    fn select(&self, mut candidates: Vec<CoinType>, amount: u64) -> Result<Vec<CoinType>> {
+        sort_by_amount(&mut candidates);
+        candidates.reverse();
+
+        take_until_covered(candidates, amount)
+    }
+}
+
+/// Spends the smallest resources first, cleaning up dust as a side effect.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SmallestFirst;
+
+impl CoinSelector for SmallestFirst {
+    fn select(&self, mut candidates: Vec<CoinType>, amount: u64) -> Result<Vec<CoinType>> {
+        sort_by_amount(&mut candidates);
+
+        take_until_covered(candidates, amount)
+    }
+}
+
+/// Searches, with branch and bound, for resources adding up to exactly the requested amount, so
+/// that no change is left behind. Falls back to [`LargestFirst`] if no exact match is found
+/// within `max_tries` steps.
+#[derive(Debug, Clone, Copy)]
+pub struct ExactMatch {
+    max_tries: usize,
+}
+
+impl ExactMatch {
+    pub fn new(max_tries: usize) -> Self {
+        Self { max_tries }
+    }
+}
+
+impl Default for ExactMatch {
+    fn default() -> Self {
+        Self::new(100_000)
+    }
+}
+
+impl CoinSelector for ExactMatch {
+    fn select(&self, mut candidates: Vec<CoinType>, amount: u64) -> Result<Vec<CoinType>> {
+        sort_by_amount(&mut candidates);
+        candidates.reverse();
+
+        let amounts: Vec<u64> = candidates.iter().map(CoinType::amount).collect();
+        let mut search = ExactMatchSearch {
+            amounts: &amounts,
+            remaining_sums: remaining_sums(&amounts),
+            tries_left: self.max_tries,
+            selected: vec![],
+        };
+
+        if search.find(0, amount) {
+            let selected = search.selected;
+            return Ok(candidates
+                .into_iter()
+                .enumerate()
+                .filter(|(index, _)| selected.contains(index))
+                .map(|(_, resource)| resource)
+                .collect());
+        }
+
+        LargestFirst.select(candidates, amount)
+    }
+}
+
+struct ExactMatchSearch<'a> {
+    /// Amounts of the candidates, largest first.
+    amounts: &'a [u64],
+    /// `remaining_sums[i]` is the sum of `amounts[i..]`.
+    remaining_sums: Vec<u128>,
+    tries_left: usize,
+    selected: Vec<usize>,
+}
+
+impl ExactMatchSearch<'_> {
+    fn find(&mut self, index: usize, target: u64) -> bool {
+        if target == 0 {
+            return true;
+        }
+        // bound: give up on branches that cannot reach the target anymore
+        if index == self.amounts.len()
+            || self.remaining_sums[index] < u128::from(target)
+            || self.tries_left == 0
+        {
+            return false;
+        }
+        self.tries_left -= 1;
+
+        let amount = self.amounts[index];
+        if amount <= target {
+            self.selected.push(index);
+            if self.find(index + 1, target - amount) {
+                return true;
+            }
+            self.selected.pop();
+        }
+
+        self.find(index + 1, target)
+    }
+}
+
+fn remaining_sums(amounts: &[u64]) -> Vec<u128> {
+    let mut sums = vec![0; amounts.len() + 1];
+    for (index, amount) in amounts.iter().enumerate().rev() {
+        sums[index] = sums[index + 1] + u128::from(*amount);
+    }
+
+    sums
+}
+
+/// Spends resources in random order, so that the inputs of a transaction reveal less about the
+/// resources of the account. Seeding the selector makes the selection reproducible.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomSelection {
+    seed: Option<u64>,
+}
+
+impl RandomSelection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self { seed: Some(seed) }
+    }
+}
+
+impl CoinSelector for RandomSelection {
+    fn select(&self, mut candidates: Vec<CoinType>, amount: u64) -> Result<Vec<CoinType>> {
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        // the order of the candidates returned by the node must not affect a seeded selection
+        sort_by_amount(&mut candidates);
+        candidates.shuffle(&mut rng);
+
+        take_until_covered(candidates, amount)
+    }
+}
+
+/// Sorts by amount, ordering resources of the same amount by their id, so that the selection
+/// does not depend on the order the resources were fetched in.
+fn sort_by_amount(resources: &mut [CoinType]) {
+    resources.sort_by_cached_key(|resource| (resource.amount(), resource.id()));
+}
+
+fn take_until_covered(candidates: Vec<CoinType>, amount: u64) -> Result<Vec<CoinType>> {
+    let mut selected = vec![];
+    let mut total = 0u64;
+
+    for resource in candidates {
+        if total >= amount {
+            break;
+        }
+        total = total.saturating_add(resource.amount());
+        selected.push(resource);
+    }
+
+    if total < amount {
+        return Err(error!(
+            Other,
+            "not enough resources to cover `{amount}`, only `{total}` available"
+        ));
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::UtxoId;
+    use fuel_types::{AssetId, Bytes32};
+    use fuels_core::types::coin::Coin;
+
+    use super::*;
+
+    fn coins(amounts: &[u64]) -> Vec<CoinType> {
+        amounts
+            .iter()
+            .enumerate()
+            .map(|(index, amount)| {
+                CoinType::Coin(Coin {
+                    amount: *amount,
+                    asset_id: AssetId::BASE,
+                    utxo_id: UtxoId::new(Bytes32::new([index as u8; 32]), 0),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    fn selected_amounts(
+        selector: impl CoinSelector,
+        amounts: &[u64],
+        amount: u64,
+    ) -> Result<Vec<u64>> {
+        Ok(selector
+            .select(coins(amounts), amount)?
+            .iter()
+            .map(CoinType::amount)
+            .collect())
+    }
+
+    #[test]
+    fn largest_first_uses_the_fewest_resources() -> Result<()> {
+        let selected = selected_amounts(LargestFirst, &[5, 50, 1, 20], 60)?;
+
+        assert_eq!(selected, [50, 20]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn smallest_first_spends_dust() -> Result<()> {
+        let selected = selected_amounts(SmallestFirst, &[5, 50, 1, 20], 10)?;
+
+        assert_eq!(selected, [1, 5, 20]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn exact_match_leaves_no_change() -> Result<()> {
+        let mut selected = selected_amounts(ExactMatch::default(), &[40, 30, 25, 8, 7], 62)?;
+        selected.sort();
+
+        assert_eq!(selected, [7, 25, 30]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn exact_match_falls_back_to_largest_first() -> Result<()> {
+        let selected = selected_amounts(ExactMatch::default(), &[40, 30, 25], 42)?;
+
+        assert_eq!(selected, [40, 30]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn seeded_random_selection_is_reproducible() -> Result<()> {
+        let amounts: Vec<u64> = (1..=20).collect();
+        let mut reversed = coins(&amounts);
+        reversed.reverse();
+
+        let selection = RandomSelection::with_seed(42).select(coins(&amounts), 30)?;
+        let same_selection = RandomSelection::with_seed(42).select(reversed, 30)?;
+
+        assert_eq!(selection, same_selection);
+        assert!(selection.iter().map(CoinType::amount).sum::<u64>() >= 30);
+
+        Ok(())
+    }
+
+    #[test]
+    fn selection_fails_without_enough_resources() {
+        let error = LargestFirst
+            .select(coins(&[5, 10]), 20)
+            .expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            "not enough resources to cover `20`, only `15` available"
+        );
+    }
+}
@@ -0,0 +1,462 @@
+use std::fmt;
+
+use coins_bip32::{
+    ecdsa::{SigningKey, VerifyingKey},
+    enc::{MainnetEncoder, XKeyEncoder},
+    prelude::{Hint, Parent, XKeyInfo, XPriv, XPub},
+};
+use coins_bip39::{
+    ChineseSimplified, ChineseTraditional, Czech, English, French, Italian, Japanese, Korean,
+    Mnemonic, Portuguese, Spanish, Wordlist,
+};
+use fuel_core_client::client::pagination::{PageDirection, PaginationRequest};
+use fuel_crypto::{PublicKey, SecretKey};
+use fuels_core::types::{
+    bech32::{Bech32Address, FUEL_BECH32_HRP},
+    errors::{error, Result},
+};
+use rand::Rng;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    accounts_utils::try_provider_error,
+    provider::Provider,
+    wallet::{Wallet, WalletUnlocked, DEFAULT_DERIVATION_PATH_PREFIX},
+};
+
+const SEED_LEN: usize = 64;
+const HARDENED: u32 = 1 << 31;
+
+/// The languages of the BIP39 wordlists a mnemonic phrase can be written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MnemonicLanguage {
+    #[default]
+    English,
+    ChineseSimplified,
+    ChineseTraditional,
+    Czech,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Portuguese,
+    Spanish,
+}
+
+impl MnemonicLanguage {
+    pub(crate) fn generate_phrase<R: Rng>(self, rng: &mut R, count: usize) -> Result<String> {
+        match self {
+            Self::English => generate_phrase::<English, _>(rng, count),
+            Self::ChineseSimplified => generate_phrase::<ChineseSimplified, _>(rng, count),
+            Self::ChineseTraditional => generate_phrase::<ChineseTraditional, _>(rng, count),
+            Self::Czech => generate_phrase::<Czech, _>(rng, count),
+            Self::French => generate_phrase::<French, _>(rng, count),
+            Self::Italian => generate_phrase::<Italian, _>(rng, count),
+            Self::Japanese => generate_phrase::<Japanese, _>(rng, count),
+            Self::Korean => generate_phrase::<Korean, _>(rng, count),
+            Self::Portuguese => generate_phrase::<Portuguese, _>(rng, count),
+            Self::Spanish => generate_phrase::<Spanish, _>(rng, count),
+        }
+    }
+
+    fn seed(self, phrase: &str, passphrase: Option<&str>) -> Result<[u8; SEED_LEN]> {
+        match self {
+            Self::English => seed_from_phrase::<English>(phrase, passphrase),
+            Self::ChineseSimplified => seed_from_phrase::<ChineseSimplified>(phrase, passphrase),
+            Self::ChineseTraditional => seed_from_phrase::<ChineseTraditional>(phrase, passphrase),
+            Self::Czech => seed_from_phrase::<Czech>(phrase, passphrase),
+            Self::French => seed_from_phrase::<French>(phrase, passphrase),
+            Self::Italian => seed_from_phrase::<Italian>(phrase, passphrase),
+            Self::Japanese => seed_from_phrase::<Japanese>(phrase, passphrase),
+            Self::Korean => seed_from_phrase::<Korean>(phrase, passphrase),
+            Self::Portuguese => seed_from_phrase::<Portuguese>(phrase, passphrase),
+            Self::Spanish => seed_from_phrase::<Spanish>(phrase, passphrase),
+        }
+    }
+}
+
+fn generate_phrase<W: Wordlist, R: Rng>(rng: &mut R, count: usize) -> Result<String> {
+    Mnemonic::<W>::new_with_count(rng, count)
+        .map(|mnemonic| mnemonic.to_phrase())
+        .map_err(|err| error!(Other, "could not generate mnemonic phrase: {err}"))
+}
+
+fn seed_from_phrase<W: Wordlist>(phrase: &str, passphrase: Option<&str>) -> Result<[u8; SEED_LEN]> {
+    // BIP39 seeds are derived from the NFKD forms, which the wordlists are written in too. Some
+    // wordlists are written with other whitespace than the ASCII space, e.g. Japanese
+    let phrase = phrase.nfkd().collect::<String>();
+    let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+    let passphrase = passphrase.map(|passphrase| passphrase.nfkd().collect::<String>());
+
+    Mnemonic::<W>::new_from_phrase(&phrase)
+        .and_then(|mnemonic| mnemonic.to_seed(passphrase.as_deref()))
+        .map_err(|err| error!(Other, "invalid mnemonic phrase: {err}"))
+}
+
+/// A wallet discovered by [`HdWallet::discover`] or [`WatchOnlyHdWallet::discover`], alongside
+/// the account and index it was derived with.
+#[derive(Debug, Clone)]
+pub struct DiscoveredWallet<W> {
+    pub account: u32,
+    pub index: u32,
+    pub wallet: W,
+}
+
+/// A hierarchical deterministic wallet, deriving any number of wallets from the seed of a BIP39
+/// mnemonic phrase.
+///
+/// Wallets are derived by account and index, along the path
+/// `m/44'/1179993420'/<account>'/0/<index>`. The wallet of account 0 and index 0 is the one
+/// created by [`WalletUnlocked::new_from_mnemonic_phrase`].
+///
+/// The seed is zeroed out on `drop`.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct HdWallet {
+    seed: [u8; SEED_LEN],
+    #[zeroize(skip)]
+    provider: Option<Provider>,
+}
+
+impl HdWallet {
+    /// Creates an HD wallet from an English mnemonic phrase without a passphrase.
+    pub fn new_from_mnemonic_phrase(phrase: &str, provider: Option<Provider>) -> Result<Self> {
+        Self::new_from_mnemonic_phrase_with_options(
+            phrase,
+            None,
+            MnemonicLanguage::English,
+            provider,
+        )
+    }
+
+    /// Creates an HD wallet from a mnemonic phrase written in `language`, protected by the BIP39
+    /// `passphrase`, if any.
+    pub fn new_from_mnemonic_phrase_with_options(
+        phrase: &str,
+        passphrase: Option<&str>,
+        language: MnemonicLanguage,
+        provider: Option<Provider>,
+    ) -> Result<Self> {
+        Ok(Self {
+            seed: language.seed(phrase, passphrase)?,
+            provider,
+        })
+    }
+
+    pub fn provider(&self) -> Option<&Provider> {
+        self.provider.as_ref()
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.provider = Some(provider);
+    }
+
+    /// Derives the wallet of `account` and `index`.
+    pub fn wallet(&self, account: u32, index: u32) -> Result<WalletUnlocked> {
+        self.wallet_with_path(&format!(
+            "{DEFAULT_DERIVATION_PATH_PREFIX}/{account}'/0/{index}"
+        ))
+    }
+
+    /// Derives the wallet of the given BIP32 derivation path.
+    pub fn wallet_with_path(&self, path: &str) -> Result<WalletUnlocked> {
+        let xpriv = self.derive(path)?;
+        let signing_key: &SigningKey = xpriv.as_ref();
+        let bytes: [u8; SecretKey::LEN] = signing_key.to_bytes().into();
+        let private_key = SecretKey::try_from(bytes.as_slice())?;
+
+        Ok(WalletUnlocked::new_from_private_key(
+            private_key,
+            self.provider.clone(),
+        ))
+    }
+
+    /// Returns a watch-only HD wallet deriving the wallets of `account` from its extended public
+    /// key, without knowing their private keys.
+    pub fn watch_only(&self, account: u32) -> Result<WatchOnlyHdWallet> {
+        let xpriv = self.derive(&format!("{DEFAULT_DERIVATION_PATH_PREFIX}/{account}'"))?;
+
+        Ok(WatchOnlyHdWallet {
+            xpub: xpriv.verify_key(),
+            provider: self.provider.clone(),
+        })
+    }
+
+    /// Finds the wallets that were ever used, i.e. that own resources or are involved in a
+    /// transaction, following the BIP44 account discovery. Accounts are scanned in order, until
+    /// one without any used wallet is found. Within an account, indices are scanned until
+    /// `gap_limit` unused wallets in a row are found. BIP44 recommends a gap limit of 20.
+    pub async fn discover(&self, gap_limit: u32) -> Result<Vec<DiscoveredWallet<WalletUnlocked>>> {
+        let provider = self.try_provider()?;
+        let mut discovered = vec![];
+
+        for account in 0.. {
+            let used = discover_in_account(provider, gap_limit, |index| {
+                self.wallet(account, index)
+                    .map(|wallet| (wallet.address().clone(), wallet))
+            })
+            .await?;
+
+            if used.is_empty() {
+                break;
+            }
+
+            discovered.extend(used.into_iter().map(|(index, wallet)| DiscoveredWallet {
+                account,
+                index,
+                wallet,
+            }));
+        }
+
+        Ok(discovered)
+    }
+
+    fn derive(&self, path: &str) -> Result<XPriv> {
+        XPriv::root_from_seed(&self.seed, Some(Hint::Legacy))
+            .and_then(|root| root.derive_path(path))
+            .map_err(|err| error!(Other, "could not derive key `{path}`: {err}"))
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.provider.as_ref().ok_or_else(try_provider_error)
+    }
+}
+
+impl fmt::Debug for HdWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HdWallet")
+            .field("provider", &self.provider)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The watch-only counterpart of an account of an [`HdWallet`]. It derives the account's
+/// wallets from its extended public key, so they can be looked up but not unlocked.
+#[derive(Debug, Clone)]
+pub struct WatchOnlyHdWallet {
+    xpub: XPub,
+    provider: Option<Provider>,
+}
+
+impl WatchOnlyHdWallet {
+    /// Creates a watch-only HD wallet from an extended public key exported with
+    /// [`WatchOnlyHdWallet::xpub`].
+    pub fn from_xpub(xpub: &str, provider: Option<Provider>) -> Result<Self> {
+        let xpub = MainnetEncoder::xpub_from_base58(xpub)
+            .map_err(|err| error!(Other, "invalid extended public key: {err}"))?;
+
+        Ok(Self { xpub, provider })
+    }
+
+    /// The Base58-encoded extended public key of the account.
+    pub fn xpub(&self) -> Result<String> {
+        MainnetEncoder::xpub_to_base58(&self.xpub)
+            .map_err(|err| error!(Other, "could not encode extended public key: {err}"))
+    }
+
+    /// The account the extended public key belongs to.
+    pub fn account(&self) -> u32 {
+        let info: &XKeyInfo = self.xpub.as_ref();
+
+        info.index & !HARDENED
+    }
+
+    pub fn provider(&self) -> Option<&Provider> {
+        self.provider.as_ref()
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.provider = Some(provider);
+    }
+
+    /// Derives the watch-only wallet of `index`.
+    pub fn wallet(&self, index: u32) -> Result<Wallet> {
+        let xpub = self
+            .xpub
+            .derive_child(0)
+            .and_then(|external| external.derive_child(index))
+            .map_err(|err| error!(Other, "could not derive public key `0/{index}`: {err}"))?;
+
+        let verifying_key: &VerifyingKey = xpub.as_ref();
+        let public_key = PublicKey::from(verifying_key);
+        let address = Bech32Address::new(FUEL_BECH32_HRP, public_key.hash());
+
+        Ok(Wallet::from_address(address, self.provider.clone()))
+    }
+
+    /// Finds the wallets of the account that were ever used, see [`HdWallet::discover`].
+    pub async fn discover(&self, gap_limit: u32) -> Result<Vec<DiscoveredWallet<Wallet>>> {
+        let provider = self.provider.as_ref().ok_or_else(try_provider_error)?;
+        let account = self.account();
+
+        let used = discover_in_account(provider, gap_limit, |index| {
+            self.wallet(index)
+                .map(|wallet| (wallet.address().clone(), wallet))
+        })
+        .await?;
+
+        Ok(used
+            .into_iter()
+            .map(|(index, wallet)| DiscoveredWallet {
+                account,
+                index,
+                wallet,
+            })
+            .collect())
+    }
+}
+
+/// Scans the wallets returned by `derive` until `gap_limit` unused ones in a row are found.
+async fn discover_in_account<W>(
+    provider: &Provider,
+    gap_limit: u32,
+    derive: impl Fn(u32) -> Result<(Bech32Address, W)>,
+) -> Result<Vec<(u32, W)>> {
+    let mut used = vec![];
+    let mut unused_in_a_row = 0;
+
+    for index in 0.. {
+        if unused_in_a_row >= gap_limit {
+            break;
+        }
+
+        let (address, wallet) = derive(index)?;
+        if is_used(provider, &address).await? {
+            used.push((index, wallet));
+            unused_in_a_row = 0;
+        } else {
+            unused_in_a_row += 1;
+        }
+    }
+
+    Ok(used)
+}
+
+async fn is_used(provider: &Provider, address: &Bech32Address) -> Result<bool> {
+    if !provider.get_balances(address).await?.is_empty() {
+        return Ok(true);
+    }
+
+    let transactions = provider
+        .get_transactions_by_owner(
+            address,
+            PaginationRequest {
+                cursor: None,
+                results: 1,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await?;
+
+    Ok(!transactions.results.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::generate_mnemonic_phrase_with_language;
+
+    const PHRASE: &str =
+        "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+
+    #[test]
+    fn derives_the_same_wallets_as_walletunlocked() -> Result<()> {
+        let hd_wallet = HdWallet::new_from_mnemonic_phrase(PHRASE, None)?;
+
+        let default_wallet = WalletUnlocked::new_from_mnemonic_phrase(PHRASE, None)?;
+        let path = "m/44'/60'/0'/0/0";
+        let wallet_with_path =
+            WalletUnlocked::new_from_mnemonic_phrase_with_path(PHRASE, None, path)?;
+
+        assert_eq!(hd_wallet.wallet(0, 0)?.address(), default_wallet.address());
+        assert_eq!(
+            hd_wallet.wallet_with_path(path)?.address(),
+            wallet_with_path.address()
+        );
+        assert_ne!(
+            hd_wallet.wallet(0, 1)?.address(),
+            hd_wallet.wallet(1, 0)?.address()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn passphrase_changes_the_wallets() -> Result<()> {
+        let hd_wallet = HdWallet::new_from_mnemonic_phrase(PHRASE, None)?;
+        let protected_hd_wallet = HdWallet::new_from_mnemonic_phrase_with_options(
+            PHRASE,
+            Some("passphrase"),
+            MnemonicLanguage::English,
+            None,
+        )?;
+
+        assert_ne!(
+            hd_wallet.wallet(0, 0)?.address(),
+            protected_hd_wallet.wallet(0, 0)?.address()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn phrases_of_other_languages_are_supported() -> Result<()> {
+        let mut rng = rand::thread_rng();
+        let phrase =
+            generate_mnemonic_phrase_with_language(&mut rng, 24, MnemonicLanguage::Japanese)?;
+
+        let hd_wallet = HdWallet::new_from_mnemonic_phrase_with_options(
+            &phrase,
+            None,
+            MnemonicLanguage::Japanese,
+            None,
+        )?;
+        hd_wallet.wallet(0, 0)?;
+
+        let error = HdWallet::new_from_mnemonic_phrase(&phrase, None)
+            .expect_err("should fail for the wrong language");
+        assert!(error.to_string().starts_with("invalid mnemonic phrase"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn phrase_and_passphrase_are_normalized() -> Result<()> {
+        // written in NFC, with precomposed accents
+        let phrase = "\u{e1}baco \u{e1}lbum l\u{ed}quido \u{e1}lbum espuma acudir bolero bosque \
+                      amante gaita dictar boca";
+        let passphrase = "contrase\u{f1}a";
+
+        let hd_wallet = HdWallet::new_from_mnemonic_phrase_with_options(
+            phrase,
+            Some(passphrase),
+            MnemonicLanguage::Spanish,
+            None,
+        )?;
+
+        let expected_seed = "e990842e47d15e8a1f99ea03fc2b42f34224484d214f775272ff39ff41e0cdf6\
+                             fdeecef67db61bf85afa851f35fadc94e135f979ef27e47c410fa2dbf59312c0";
+        assert_eq!(hex::encode(hd_wallet.seed), expected_seed);
+
+        Ok(())
+    }
+
+    #[test]
+    fn watch_only_wallets_match_the_derived_wallets() -> Result<()> {
+        let hd_wallet = HdWallet::new_from_mnemonic_phrase(PHRASE, None)?;
+
+        let xpub = hd_wallet.watch_only(2)?.xpub()?;
+        let watch_only = WatchOnlyHdWallet::from_xpub(&xpub, None)?;
+
+        assert!(xpub.starts_with("xpub"));
+        assert_eq!(watch_only.account(), 2);
+        for index in 0..3 {
+            assert_eq!(
+                watch_only.wallet(index)?.address(),
+                hd_wallet.wallet(2, index)?.address()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,508 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use aes::{
+    cipher::{KeyIvInit, StreamCipher},
+    Aes128,
+};
+use eth_keystore::{CipherparamsJson, CryptoJson, EthKeystore, KdfType, KdfparamsType};
+use fuel_crypto::SecretKey;
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+};
+use hmac::Hmac;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use uuid::Uuid;
+use zeroize::Zeroizing;
+
+use crate::{provider::Provider, wallet::WalletUnlocked};
+
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
+
+const INDEX_FILE_NAME: &str = "index.json";
+const CIPHER: &str = "aes-128-ctr";
+const PBKDF2_PRF: &str = "hmac-sha256";
+const DERIVED_KEY_SIZE: usize = 32;
+const SALT_SIZE: usize = 32;
+const IV_SIZE: usize = 16;
+/// The minimum number of PBKDF2 iterations, as recommended by RFC 2898.
+const MIN_PBKDF2_ITERATIONS: u32 = 1000;
+
+/// The key derivation function used to derive the encryption key of a keystore file from its
+/// password. Higher costs make brute-forcing the password slower, but also unlocking the wallet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KdfParams {
+    /// [scrypt](https://tools.ietf.org/html/rfc7914.html) with a CPU/memory cost of `2^log_n`,
+    /// a block size of `r` and a parallelization of `p`.
+    Scrypt { log_n: u8, r: u32, p: u32 },
+    /// [PBKDF2](https://ietf.org/rfc/rfc2898.txt) with HMAC-SHA256 and `c` iterations, at least
+    /// 1000.
+    Pbkdf2 { c: u32 },
+}
+
+impl Default for KdfParams {
+    /// The parameters [`WalletUnlocked::encrypt`] uses.
+    fn default() -> Self {
+        Self::Scrypt {
+            log_n: 13,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+/// A key stored in a [`Keystore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeystoreEntry {
+    pub address: Bech32Address,
+    /// The name of the encrypted JSON file in the keystore directory.
+    pub file_name: String,
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Index {
+    keys: Vec<IndexEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexEntry {
+    address: String,
+    file_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+impl TryFrom<&IndexEntry> for KeystoreEntry {
+    type Error = fuels_core::types::errors::Error;
+
+    fn try_from(entry: &IndexEntry) -> Result<Self> {
+        Ok(Self {
+            address: Bech32Address::from_str(&entry.address)?,
+            file_name: entry.file_name.clone(),
+            label: entry.label.clone(),
+        })
+    }
+}
+
+/// Manages a directory of encrypted JSON keystore files, the same ones
+/// [`WalletUnlocked::encrypt`] creates.
+///
+/// Next to the keys, the directory holds a plaintext `index.json` file with the address and the
+/// label of every key, so keys can be listed and looked up without their passwords.
+#[derive(Debug, Clone)]
+pub struct Keystore {
+    dir: PathBuf,
+    kdf_params: KdfParams,
+}
+
+impl Keystore {
+    /// Opens the keystore in `dir`, creating the directory if it doesn't exist.
+    pub fn new<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            kdf_params: KdfParams::default(),
+        })
+    }
+
+    /// Sets the key derivation function used for the keys encrypted from now on. Keys that are
+    /// already stored keep theirs until their password is changed.
+    pub fn with_kdf_params(mut self, kdf_params: KdfParams) -> Self {
+        self.kdf_params = kdf_params;
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn kdf_params(&self) -> KdfParams {
+        self.kdf_params
+    }
+
+    /// Lists the keys in the keystore, in the order they were added.
+    pub fn list(&self) -> Result<Vec<KeystoreEntry>> {
+        self.read_index()?
+            .keys
+            .iter()
+            .map(KeystoreEntry::try_from)
+            .collect()
+    }
+
+    /// Returns the key of `address`, if the keystore holds it.
+    pub fn find(&self, address: &Bech32Address) -> Result<Option<KeystoreEntry>> {
+        Ok(self
+            .list()?
+            .into_iter()
+            .find(|entry| &entry.address == address))
+    }
+
+    /// Encrypts the private key of `wallet` with `password` and adds it to the keystore.
+    pub fn import<S: AsRef<[u8]>>(
+        &self,
+        wallet: &WalletUnlocked,
+        password: S,
+        label: Option<&str>,
+    ) -> Result<KeystoreEntry> {
+        let mut index = self.read_index()?;
+        let address = wallet.address().to_string();
+        if index.keys.iter().any(|entry| entry.address == address) {
+            return Err(error!(
+                Other,
+                "keystore already contains a key for `{address}`"
+            ));
+        }
+
+        let keystore = encrypt_key(&wallet.private_key, password.as_ref(), self.kdf_params)?;
+        let file_name = keystore.id.to_string();
+        write_json(&self.dir.join(&file_name), &keystore)?;
+
+        let entry = IndexEntry {
+            address,
+            file_name,
+            label: label.map(ToString::to_string),
+        };
+        let imported = KeystoreEntry::try_from(&entry)?;
+        index.keys.push(entry);
+        self.write_index(&index)?;
+
+        Ok(imported)
+    }
+
+    /// Adds the key of an encrypted JSON keystore file, e.g. one created by
+    /// [`WalletUnlocked::encrypt`], to the keystore. The key is re-encrypted with `password`
+    /// and the key derivation function of the keystore.
+    pub fn import_keystore<P, S>(
+        &self,
+        path: P,
+        password: S,
+        label: Option<&str>,
+    ) -> Result<KeystoreEntry>
+    where
+        P: AsRef<Path>,
+        S: AsRef<[u8]>,
+    {
+        let wallet = WalletUnlocked::load_keystore(path, &password, None)?;
+
+        self.import(&wallet, password, label)
+    }
+
+    /// Copies the encrypted JSON keystore file of `address` to `path`. The file can be loaded
+    /// with [`WalletUnlocked::load_keystore`] or imported into another keystore.
+    pub fn export<P: AsRef<Path>>(&self, address: &Bech32Address, path: P) -> Result<()> {
+        let entry = self.entry(address)?;
+        fs::copy(self.dir.join(entry.file_name), path)?;
+
+        Ok(())
+    }
+
+    /// Removes the key of `address` from the keystore and deletes its file.
+    pub fn delete(&self, address: &Bech32Address) -> Result<()> {
+        let mut index = self.read_index()?;
+        let position = position_of(&index, address)?;
+        let entry = index.keys.remove(position);
+
+        self.write_index(&index)?;
+        fs::remove_file(self.dir.join(entry.file_name))?;
+
+        Ok(())
+    }
+
+    /// Sets or, with `None`, removes the label of the key of `address`.
+    pub fn set_label(&self, address: &Bech32Address, label: Option<&str>) -> Result<()> {
+        let mut index = self.read_index()?;
+        let position = position_of(&index, address)?;
+        index.keys[position].label = label.map(ToString::to_string);
+
+        self.write_index(&index)
+    }
+
+    /// Re-encrypts the key of `address` with `new_password`, using the key derivation function
+    /// of the keystore.
+    pub fn change_password<S, T>(
+        &self,
+        address: &Bech32Address,
+        password: S,
+        new_password: T,
+    ) -> Result<()>
+    where
+        S: AsRef<[u8]>,
+        T: AsRef<[u8]>,
+    {
+        let entry = self.entry(address)?;
+        let wallet = self.unlock(&entry, password, None)?;
+
+        let mut keystore =
+            encrypt_key(&wallet.private_key, new_password.as_ref(), self.kdf_params)?;
+        // the file keeps its name, and so its id
+        keystore.id = Uuid::parse_str(&entry.file_name).unwrap_or(keystore.id);
+
+        write_json(&self.dir.join(entry.file_name), &keystore)
+    }
+
+    /// Decrypts the key of `address`, looked up in the index of the keystore.
+    pub fn unlock_by_address<S: AsRef<[u8]>>(
+        &self,
+        address: &Bech32Address,
+        password: S,
+        provider: Option<Provider>,
+    ) -> Result<WalletUnlocked> {
+        let entry = self.entry(address)?;
+
+        self.unlock(&entry, password, provider)
+    }
+
+    fn unlock<S: AsRef<[u8]>>(
+        &self,
+        entry: &KeystoreEntry,
+        password: S,
+        provider: Option<Provider>,
+    ) -> Result<WalletUnlocked> {
+        let wallet =
+            WalletUnlocked::load_keystore(self.dir.join(&entry.file_name), password, provider)?;
+
+        if wallet.address() != &entry.address {
+            return Err(error!(
+                Other,
+                "keystore file `{}` doesn't contain the key of `{}`",
+                entry.file_name,
+                entry.address
+            ));
+        }
+
+        Ok(wallet)
+    }
+
+    fn entry(&self, address: &Bech32Address) -> Result<KeystoreEntry> {
+        self.find(address)?
+            .ok_or_else(|| error!(Other, "keystore doesn't contain a key for `{address}`"))
+    }
+
+    fn read_index(&self) -> Result<Index> {
+        let path = self.dir.join(INDEX_FILE_NAME);
+        if !path.exists() {
+            return Ok(Index::default());
+        }
+
+        serde_json::from_slice(&fs::read(&path)?)
+            .map_err(|err| error!(Other, "invalid keystore index `{}`: {err}", path.display()))
+    }
+
+    fn write_index(&self, index: &Index) -> Result<()> {
+        write_json(&self.dir.join(INDEX_FILE_NAME), index)
+    }
+}
+
+fn position_of(index: &Index, address: &Bech32Address) -> Result<usize> {
+    let address = address.to_string();
+
+    index
+        .keys
+        .iter()
+        .position(|entry| entry.address == address)
+        .ok_or_else(|| error!(Other, "keystore doesn't contain a key for `{address}`"))
+}
+
+/// Writes to a temporary file first, so an interrupted write doesn't corrupt the existing file.
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    let contents = serde_json::to_vec(value).map_err(|err| error!(Other, "{err}"))?;
+
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents)?;
+    fs::rename(tmp_path, path)?;
+
+    Ok(())
+}
+
+/// Encrypts `private_key` following the Web3 Secret Storage Definition, like
+/// `eth_keystore::encrypt_key` but with configurable key derivation.
+fn encrypt_key(
+    private_key: &SecretKey,
+    password: &[u8],
+    kdf_params: KdfParams,
+) -> Result<EthKeystore> {
+    let mut rng = rand::thread_rng();
+
+    let salt: [u8; SALT_SIZE] = rng.gen();
+    // the derived key decrypts the private key, so it is wiped once encryption is done
+    let mut key = Zeroizing::new([0u8; DERIVED_KEY_SIZE]);
+    let kdfparams = match kdf_params {
+        KdfParams::Scrypt { log_n, r, p } => {
+            let n = 1u32
+                .checked_shl(log_n.into())
+                .ok_or_else(|| error!(Other, "invalid scrypt parameters: `log_n` is too big"))?;
+            let params = scrypt::Params::new(log_n, r, p)
+                .map_err(|err| error!(Other, "invalid scrypt parameters: {err}"))?;
+            scrypt::scrypt(password, &salt, &params, key.as_mut_slice())
+                .map_err(|err| error!(Other, "{err}"))?;
+
+            KdfparamsType::Scrypt {
+                dklen: DERIVED_KEY_SIZE as u8,
+                n,
+                p,
+                r,
+                salt: salt.to_vec(),
+            }
+        }
+        KdfParams::Pbkdf2 { c } => {
+            if c < MIN_PBKDF2_ITERATIONS {
+                return Err(error!(
+                    Other,
+                    "invalid PBKDF2 parameters: `c` must be at least {MIN_PBKDF2_ITERATIONS}"
+                ));
+            }
+            pbkdf2::pbkdf2::<Hmac<Sha256>>(password, &salt, c, key.as_mut_slice());
+
+            KdfparamsType::Pbkdf2 {
+                c,
+                dklen: DERIVED_KEY_SIZE as u8,
+                prf: PBKDF2_PRF.to_string(),
+                salt: salt.to_vec(),
+            }
+        }
+    };
+    let kdf = match kdfparams {
+        KdfparamsType::Scrypt { .. } => KdfType::Scrypt,
+        KdfparamsType::Pbkdf2 { .. } => KdfType::Pbkdf2,
+    };
+
+    let iv: [u8; IV_SIZE] = rng.gen();
+    let mut ciphertext = private_key.to_vec();
+    Aes128Ctr::new(key[..16].into(), &iv.into()).apply_keystream(&mut ciphertext);
+
+    let mac = Keccak256::new()
+        .chain_update(&key[16..])
+        .chain_update(&ciphertext)
+        .finalize();
+
+    Ok(EthKeystore {
+        id: Uuid::new_v4(),
+        version: 3,
+        crypto: CryptoJson {
+            cipher: CIPHER.to_string(),
+            cipherparams: CipherparamsJson { iv: iv.to_vec() },
+            ciphertext,
+            kdf,
+            kdfparams,
+            mac: mac.to_vec(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    // cheap parameters to keep the tests fast
+    const SCRYPT: KdfParams = KdfParams::Scrypt {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+
+    #[test]
+    fn keys_can_be_managed() -> Result<()> {
+        let dir = tempdir()?;
+        let keystore = Keystore::new(dir.path().join("keys"))?.with_kdf_params(SCRYPT);
+        let alice = WalletUnlocked::new_random(None);
+        let bob = WalletUnlocked::new_random(None);
+
+        let alices_entry = keystore.import(&alice, "alice's password", Some("alice"))?;
+        let bobs_entry = keystore.import(&bob, "bob's password", None)?;
+
+        let addresses: Vec<_> = keystore.list()?.into_iter().map(|e| e.address).collect();
+        assert_eq!(addresses, [alice.address().clone(), bob.address().clone()]);
+        assert!(keystore
+            .import(&alice, "password", None)
+            .expect_err("should fail for a duplicate key")
+            .to_string()
+            .contains("already contains"));
+
+        keystore.set_label(bob.address(), Some("bob"))?;
+        let labeled = keystore.find(bob.address())?.expect("should be stored");
+        assert_eq!(labeled.label.as_deref(), Some("bob"));
+        assert_eq!(labeled.file_name, bobs_entry.file_name);
+
+        let unlocked = keystore.unlock_by_address(alice.address(), "alice's password", None)?;
+        assert_eq!(unlocked.address(), alice.address());
+        keystore
+            .unlock_by_address(alice.address(), "bob's password", None)
+            .expect_err("should fail for a wrong password");
+
+        keystore.delete(alice.address())?;
+        assert_eq!(keystore.list()?, [labeled]);
+        assert!(!keystore.dir().join(alices_entry.file_name).exists());
+        keystore
+            .unlock_by_address(alice.address(), "alice's password", None)
+            .expect_err("should fail for a deleted key");
+
+        Ok(())
+    }
+
+    #[test]
+    fn passwords_can_be_changed() -> Result<()> {
+        let dir = tempdir()?;
+        let keystore = Keystore::new(dir.path())?.with_kdf_params(SCRYPT);
+        let wallet = WalletUnlocked::new_random(None);
+        keystore.import(&wallet, "old password", None)?;
+
+        let keystore = keystore.with_kdf_params(KdfParams::Pbkdf2 { c: 1000 });
+        keystore.change_password(wallet.address(), "old password", "new password")?;
+
+        keystore
+            .unlock_by_address(wallet.address(), "old password", None)
+            .expect_err("should fail for the old password");
+        let unlocked = keystore.unlock_by_address(wallet.address(), "new password", None)?;
+        assert_eq!(unlocked.address(), wallet.address());
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_few_pbkdf2_iterations_are_rejected() -> Result<()> {
+        let dir = tempdir()?;
+        let keystore = Keystore::new(dir.path())?.with_kdf_params(KdfParams::Pbkdf2 { c: 0 });
+        let wallet = WalletUnlocked::new_random(None);
+
+        let error = keystore
+            .import(&wallet, "password", None)
+            .expect_err("should fail for zero iterations");
+
+        assert_eq!(
+            error.to_string(),
+            "invalid PBKDF2 parameters: `c` must be at least 1000"
+        );
+        assert!(keystore.list()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn keys_can_be_exported_and_imported() -> Result<()> {
+        let dir = tempdir()?;
+        let wallet = WalletUnlocked::new_random(None);
+        let file_name = wallet.encrypt(dir.path(), "password")?;
+
+        let keystore = Keystore::new(dir.path().join("keys"))?.with_kdf_params(SCRYPT);
+        keystore.import_keystore(dir.path().join(file_name), "password", Some("imported"))?;
+
+        let exported = dir.path().join("exported");
+        keystore.export(wallet.address(), &exported)?;
+        let loaded = WalletUnlocked::load_keystore(exported, "password", None)?;
+        assert_eq!(loaded.address(), wallet.address());
+
+        Ok(())
+    }
+}
@@ -3,14 +3,26 @@ mod account;
 #[cfg(feature = "std")]
 mod accounts_utils;
 #[cfg(feature = "std")]
+pub mod coin_selection;
+#[cfg(feature = "std")]
+pub mod hd_wallet;
+#[cfg(feature = "std")]
+pub mod keystore;
+#[cfg(feature = "std")]
+pub mod multisig;
+#[cfg(feature = "std")]
+pub mod pipeline;
+#[cfg(feature = "std")]
 pub mod provider;
 #[cfg(feature = "std")]
+pub mod remote_signer;
+#[cfg(feature = "std")]
 pub mod wallet;
 
 #[cfg(feature = "std")]
 pub use account::*;
 
 #[cfg(feature = "coin-cache")]
-mod coin_cache;
+pub mod coin_cache;
 
 pub mod predicate;
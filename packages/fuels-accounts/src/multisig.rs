@@ -0,0 +1,512 @@
+use std::collections::HashSet;
+
+use fuel_crypto::{Message, Signature};
+use fuel_tx::ConsensusParameters;
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        coin_type::CoinType,
+        errors::{error, Result},
+        input::Input,
+        transaction::{ScriptTransaction, Transaction, TxPolicies},
+        transaction_builders::{
+            BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
+        },
+        AssetId, Bytes32,
+    },
+};
+
+use crate::{predicate::Predicate, provider::Provider, Account, ViewOnlyAccount};
+
+/// An account spending the coins of a predicate that requires `threshold` signatures from
+/// distinct `owners`.
+///
+/// The predicate itself must enforce the N-of-M rule, `MultisigAccount` only makes sure that
+/// transactions spending from it carry enough witnesses for the owners' signatures. Transactions
+/// are built unsigned, handed to the owners for signing and assembled with a
+/// [`MultisigTransaction`].
+#[derive(Debug, Clone)]
+pub struct MultisigAccount {
+    predicate: Predicate,
+    owners: Vec<Bech32Address>,
+    threshold: usize,
+}
+
+impl MultisigAccount {
+    /// `predicate` must already be configured with the `owners` and the `threshold`.
+    pub fn new(predicate: Predicate, owners: Vec<Bech32Address>, threshold: usize) -> Result<Self> {
+        if threshold == 0 || threshold > owners.len() {
+            return Err(error!(
+                Other,
+                "multisig threshold must be between 1 and the number of owners ({}), got {threshold}",
+                owners.len()
+            ));
+        }
+
+        let mut unique_owners = HashSet::new();
+        if let Some(duplicate) = owners.iter().find(|owner| !unique_owners.insert(*owner)) {
+            return Err(error!(
+                Other,
+                "`{duplicate}` is listed more than once as an owner of the multisig"
+            ));
+        }
+
+        Ok(Self {
+            predicate,
+            owners,
+            threshold,
+        })
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    pub fn owners(&self) -> &[Bech32Address] {
+        &self.owners
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    pub fn provider(&self) -> Option<&Provider> {
+        self.predicate.provider()
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.predicate.set_provider(provider);
+    }
+
+    /// Builds a transaction transferring `amount` of `asset_id` to `to`, without sending it.
+    /// The owners' signatures are collected on the returned [`MultisigTransaction`].
+    pub async fn transfer_tx(
+        &self,
+        to: &Bech32Address,
+        amount: u64,
+        asset_id: AssetId,
+        tx_policies: TxPolicies,
+    ) -> Result<MultisigTransaction<ScriptTransaction>> {
+        let provider = self.try_provider()?;
+
+        let inputs = self.get_asset_inputs_for_amount(asset_id, amount).await?;
+        let outputs = self.get_asset_outputs_for_amount(to, asset_id, amount);
+
+        let mut tx_builder =
+            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+
+        self.add_witnesses(&mut tx_builder)?;
+
+        let used_base_amount = if asset_id == AssetId::BASE { amount } else { 0 };
+        self.adjust_for_fee(&mut tx_builder, used_base_amount)
+            .await?;
+
+        let tx = tx_builder.build(provider).await?;
+
+        self.multisig_transaction(tx, provider.consensus_parameters())
+    }
+
+    /// Wraps a transaction built with this account's [`Account::add_witnesses`] so that the
+    /// owners' signatures can be collected on it.
+    pub fn multisig_transaction<T: Transaction>(
+        &self,
+        tx: T,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<MultisigTransaction<T>> {
+        MultisigTransaction::new(
+            tx,
+            consensus_parameters,
+            self.owners.clone(),
+            self.threshold,
+        )
+    }
+}
+
+impl ViewOnlyAccount for MultisigAccount {
+    fn address(&self) -> &Bech32Address {
+        self.predicate.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.predicate.try_provider()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Account for MultisigAccount {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        self.predicate
+            .get_asset_inputs_for_amount(asset_id, amount)
+            .await
+    }
+
+    fn resource_input(&self, resource: CoinType) -> Input {
+        self.predicate.resource_input(resource)
+    }
+
+    /// Reserves one empty witness per required signature, so that the fee and the witness limit
+    /// of the transaction account for the signatures added later.
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.witnesses_mut()
+            .extend((0..self.threshold).map(|_| Signature::default().as_ref().to_vec().into()));
+
+        Ok(())
+    }
+}
+
+/// An unsigned transaction spending from a [`MultisigAccount`], collecting the signatures of
+/// its owners.
+///
+/// The transaction ID is independent of the witnesses, so every owner signs the same
+/// [`message`](Self::message), e.g. on a different machine, and the detached signatures are
+/// gathered with [`add_signature`](Self::add_signature).
+#[derive(Debug, Clone)]
+pub struct MultisigTransaction<T> {
+    tx: T,
+    id: Bytes32,
+    consensus_parameters: ConsensusParameters,
+    owners: Vec<Bech32Address>,
+    threshold: usize,
+    witness_indexes: Vec<usize>,
+    signatures: Vec<(Bech32Address, Signature)>,
+}
+
+impl<T: Transaction> MultisigTransaction<T> {
+    /// `tx` must contain at least `threshold` empty witnesses, as added by
+    /// [`MultisigAccount`]'s [`Account::add_witnesses`].
+    pub fn new(
+        tx: T,
+        consensus_parameters: &ConsensusParameters,
+        owners: Vec<Bech32Address>,
+        threshold: usize,
+    ) -> Result<Self> {
+        let placeholder = Signature::default();
+        let witness_indexes: Vec<usize> = tx
+            .witnesses()
+            .iter()
+            .enumerate()
+            .filter(|(_, witness)| witness.as_ref() == placeholder.as_ref())
+            .map(|(idx, _)| idx)
+            .take(threshold)
+            .collect();
+
+        if witness_indexes.len() < threshold {
+            return Err(error!(
+                Other,
+                "transaction reserves {} witness(es) for signatures, but the threshold is {threshold}",
+                witness_indexes.len()
+            ));
+        }
+
+        Ok(Self {
+            id: tx.id(consensus_parameters.chain_id),
+            tx,
+            consensus_parameters: consensus_parameters.clone(),
+            owners,
+            threshold,
+            witness_indexes,
+            signatures: vec![],
+        })
+    }
+
+    pub fn id(&self) -> Bytes32 {
+        self.id
+    }
+
+    /// The message every owner has to sign.
+    pub fn message(&self) -> Message {
+        Message::from_bytes(*self.id)
+    }
+
+    /// The unsigned transaction, e.g. to be handed to the owners for inspection.
+    pub fn transaction(&self) -> &T {
+        &self.tx
+    }
+
+    /// The owners whose signatures were collected so far.
+    pub fn signers(&self) -> impl Iterator<Item = &Bech32Address> {
+        self.signatures.iter().map(|(signer, _)| signer)
+    }
+
+    /// The number of signatures still missing to reach the threshold.
+    pub fn missing_signatures(&self) -> usize {
+        self.threshold.saturating_sub(self.signatures.len())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.missing_signatures() == 0
+    }
+
+    /// Adds a detached signature of [`message`](Self::message). Returns the owner that made it.
+    pub fn add_signature(&mut self, signature: Signature) -> Result<&Bech32Address> {
+        let public_key = signature.recover(&self.message())?;
+        let signer = Bech32Address::new(FUEL_BECH32_HRP, public_key.hash());
+
+        if !self.owners.contains(&signer) {
+            return Err(error!(Other, "`{signer}` is not an owner of the multisig"));
+        }
+
+        if self.signers().any(|existing| existing == &signer) {
+            return Err(error!(Other, "`{signer}` has already signed"));
+        }
+
+        if self.is_complete() {
+            return Err(error!(
+                Other,
+                "the multisig threshold of {} is already reached", self.threshold
+            ));
+        }
+
+        self.signatures.push((signer, signature));
+
+        Ok(&self.signatures.last().expect("was just pushed").0)
+    }
+
+    /// Signs the transaction with `signer`, who must be one of the owners.
+    pub async fn sign_with(&mut self, signer: &impl Signer) -> Result<&Bech32Address> {
+        let signature = signer.sign(self.message()).await?;
+
+        self.add_signature(signature)
+    }
+
+    /// Places the collected signatures into the reserved witnesses and returns the transaction,
+    /// ready to be sent.
+    ///
+    /// The predicates are estimated again once the signatures are in place, as they can use a
+    /// different amount of gas to verify them than to verify the empty placeholders.
+    pub fn finalize(self) -> Result<T> {
+        if !self.is_complete() {
+            return Err(error!(
+                Other,
+                "multisig needs {} signature(s), but only {} were collected",
+                self.threshold,
+                self.signatures.len()
+            ));
+        }
+
+        let mut tx = self.tx;
+        for (idx, (_, signature)) in self.witness_indexes.into_iter().zip(self.signatures) {
+            tx.replace_witness(idx, signature.as_ref().to_vec().into())?;
+        }
+
+        if tx.is_using_predicates() {
+            tx.estimate_predicates(&self.consensus_parameters)?;
+        }
+
+        Ok(tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_asm::{op, GTFArgs, RegId};
+    use fuel_crypto::SecretKey;
+    use fuel_tx::{Script, Transaction as FuelTransaction};
+    use fuel_vm::{
+        checked_transaction::IntoChecked, interpreter::Interpreter, storage::PredicateStorage,
+    };
+    use fuels_core::types::transaction::EstimablePredicates;
+    use fuels_core::types::{coin::Coin, transaction_builders::DryRunner};
+
+    use super::*;
+    use crate::wallet::WalletUnlocked;
+
+    #[derive(Default)]
+    struct NoDryRun {
+        consensus_parameters: ConsensusParameters,
+    }
+
+    #[async_trait::async_trait]
+    impl DryRunner for NoDryRun {
+        async fn dry_run_and_get_used_gas(&self, _: FuelTransaction, _: f32) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn min_gas_price(&self) -> Result<u64> {
+            Ok(0)
+        }
+
+        fn consensus_parameters(&self) -> &ConsensusParameters {
+            &self.consensus_parameters
+        }
+    }
+
+    fn owners(count: usize) -> Vec<WalletUnlocked> {
+        (0..count)
+            .map(|_| WalletUnlocked::new_random(None))
+            .collect()
+    }
+
+    fn multisig(owners: &[WalletUnlocked], threshold: usize) -> MultisigAccount {
+        let addresses = owners.iter().map(|owner| owner.address().clone()).collect();
+
+        MultisigAccount::new(Predicate::from_code(vec![]), addresses, threshold).unwrap()
+    }
+
+    async fn unsigned_tx(
+        account: &MultisigAccount,
+    ) -> Result<MultisigTransaction<ScriptTransaction>> {
+        let mut tb = ScriptTransactionBuilder::default();
+        account.add_witnesses(&mut tb)?;
+        let tx = tb.build_without_signatures(&NoDryRun::default()).await?;
+
+        account.multisig_transaction(tx, &ConsensusParameters::default())
+    }
+
+    #[test]
+    fn threshold_must_be_reachable() {
+        let owners: Vec<_> = owners(2)
+            .iter()
+            .map(|owner| owner.address().clone())
+            .collect();
+
+        for threshold in [0, 3] {
+            let error =
+                MultisigAccount::new(Predicate::from_code(vec![]), owners.clone(), threshold)
+                    .expect_err("should have failed");
+
+            assert_eq!(
+                error.to_string(),
+                format!("multisig threshold must be between 1 and the number of owners (2), got {threshold}")
+            );
+        }
+    }
+
+    #[test]
+    fn owners_must_be_distinct() {
+        let owner = WalletUnlocked::new_random(None).address().clone();
+
+        let error = MultisigAccount::new(
+            Predicate::from_code(vec![]),
+            vec![owner.clone(), owner.clone()],
+            2,
+        )
+        .expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            format!("`{owner}` is listed more than once as an owner of the multisig")
+        );
+    }
+
+    #[tokio::test]
+    async fn finalized_transactions_pass_predicate_verification() -> Result<()> {
+        // loops as many times as the first byte of the first witness, so that verifying the
+        // placeholder uses less gas than verifying the signature
+        let code = [
+            op::gtf_args(0x10, RegId::ZERO, GTFArgs::WitnessData),
+            op::lb(0x11, 0x10, 0),
+            op::jnzf(0x11, RegId::ZERO, 1),
+            op::ret(RegId::ONE),
+            op::subi(0x11, 0x11, 1),
+            op::jnzb(0x11, RegId::ZERO, 0),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect();
+        let owner =
+            WalletUnlocked::new_from_private_key(SecretKey::try_from([1; 32].as_slice())?, None);
+        let account =
+            MultisigAccount::new(Predicate::from_code(code), vec![owner.address().clone()], 1)?;
+
+        let coin = Coin {
+            amount: 100,
+            owner: account.address().clone(),
+            ..Default::default()
+        };
+        let mut tb = ScriptTransactionBuilder::default()
+            .with_inputs(vec![account.resource_input(CoinType::Coin(coin))]);
+        account.add_witnesses(&mut tb)?;
+        let consensus_parameters = ConsensusParameters::default();
+        let mut tx = tb.build_without_signatures(&NoDryRun::default()).await?;
+        tx.estimate_predicates(&consensus_parameters)?;
+
+        let mut multisig_tx = account.multisig_transaction(tx, &consensus_parameters)?;
+        multisig_tx.sign_with(&owner).await?;
+        let tx = multisig_tx.finalize()?;
+
+        let checked = Script::from(tx)
+            .into_checked_basic(0u32.into(), &consensus_parameters)
+            .expect("transaction should be valid");
+        Interpreter::<PredicateStorage, Script>::check_predicates(
+            &checked,
+            &consensus_parameters.into(),
+        )
+        .expect("predicates should pass");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signatures_are_placed_into_reserved_witnesses() -> Result<()> {
+        let owners = owners(3);
+        let account = multisig(&owners, 2);
+        let mut multisig_tx = unsigned_tx(&account).await?;
+
+        assert_eq!(multisig_tx.missing_signatures(), 2);
+        multisig_tx.sign_with(&owners[2]).await?;
+        let signature = owners[0].sign(multisig_tx.message()).await?;
+        multisig_tx.add_signature(signature)?;
+        assert!(multisig_tx.is_complete());
+
+        let tx = multisig_tx.finalize()?;
+
+        let expected_witnesses = vec![
+            owners[2]
+                .sign(Message::from_bytes(
+                    *tx.id(ConsensusParameters::default().chain_id),
+                ))
+                .await?,
+            signature,
+        ]
+        .into_iter()
+        .map(|signature| signature.as_ref().to_vec().into())
+        .collect::<Vec<_>>();
+        assert_eq!(tx.witnesses(), &expected_witnesses);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_signatures_are_rejected() -> Result<()> {
+        let owners = owners(2);
+        let account = multisig(&owners, 1);
+        let mut multisig_tx = unsigned_tx(&account).await?;
+
+        let stranger = WalletUnlocked::new_random(None);
+        let error = multisig_tx
+            .sign_with(&stranger)
+            .await
+            .expect_err("should have failed");
+        assert_eq!(
+            error.to_string(),
+            format!("`{}` is not an owner of the multisig", stranger.address())
+        );
+
+        let error = multisig_tx
+            .clone()
+            .finalize()
+            .expect_err("should have failed");
+        assert_eq!(
+            error.to_string(),
+            "multisig needs 1 signature(s), but only 0 were collected"
+        );
+
+        multisig_tx.sign_with(&owners[0]).await?;
+        let error = multisig_tx
+            .sign_with(&owners[0])
+            .await
+            .expect_err("should have failed");
+        assert_eq!(
+            error.to_string(),
+            format!("`{}` has already signed", owners[0].address())
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,345 @@
+use std::{
+    collections::HashSet,
+    future::Future,
+    sync::{Arc, Mutex as StdMutex},
+};
+
+use async_trait::async_trait;
+use fuel_tx::TxId;
+use fuel_types::AssetId;
+use fuels_core::types::{
+    bech32::Bech32Address,
+    coin_type::CoinType,
+    coin_type_id::CoinTypeId,
+    errors::Result,
+    input::Input,
+    transaction::{ScriptTransaction, TxPolicies},
+    transaction_builders::{BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder},
+    tx_status::TxStatus,
+};
+use futures::{future::BoxFuture, stream, FutureExt, StreamExt};
+use tokio::sync::{Mutex, Notify};
+
+use crate::{
+    provider::{Provider, ResourceFilter},
+    Account, ViewOnlyAccount,
+};
+
+type BuildTx<A> =
+    Box<dyn Fn(PipelineAccount<A>) -> BoxFuture<'static, Result<ScriptTransaction>> + Send + Sync>;
+
+/// The outcome of a request run by a [`TxPipeline`].
+#[derive(Debug)]
+pub struct PipelineResponse {
+    pub tx_id: TxId,
+    pub tx_status: TxStatus,
+    /// How many transactions were submitted for the request. Only the last one, identified by
+    /// `tx_id`, wasn't squeezed out.
+    pub attempts: usize,
+}
+
+/// Submits many transactions from a single account concurrently.
+///
+/// Every request is built with a [`PipelineAccount`], which hands out resources not used by any
+/// other request in flight, so the transactions don't compete for the same UTXOs. Requests that
+/// can't be funded while others are in flight wait for those to resolve and free up their change.
+/// Transactions that get squeezed out are built again, with fresh inputs, and resubmitted.
+pub struct TxPipeline<A> {
+    account: A,
+    requests: Vec<BuildTx<A>>,
+    reservations: Arc<Reservations>,
+    max_in_flight: usize,
+    max_attempts: usize,
+    coin_splits: Vec<(AssetId, u64)>,
+}
+
+impl<A: Account + 'static> TxPipeline<A> {
+    pub fn new(account: A) -> Self {
+        Self {
+            account,
+            requests: vec![],
+            reservations: Default::default(),
+            max_in_flight: 10,
+            max_attempts: 3,
+            coin_splits: vec![],
+        }
+    }
+
+    /// The maximum number of transactions submitted but not yet resolved. Defaults to 10.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = max_in_flight.max(1);
+
+        self
+    }
+
+    /// The maximum number of transactions submitted for a request that keeps getting squeezed
+    /// out. Defaults to 3.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+
+        self
+    }
+
+    /// Before submitting anything, splits coins of `asset_id` so that the account owns at least
+    /// one coin of `amount` for each transaction that can be in flight.
+    pub fn with_coin_split(mut self, asset_id: AssetId, amount: u64) -> Self {
+        self.coin_splits.push((asset_id, amount));
+
+        self
+    }
+
+    /// Adds a request whose transaction is built by `build_tx`. All resources must be taken from
+    /// the given account, e.g. by building a contract call with it through `with_account`.
+    /// `build_tx` is called again for every resubmission.
+    pub fn push<F, Fut>(&mut self, build_tx: F) -> &mut Self
+    where
+        F: Fn(PipelineAccount<A>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ScriptTransaction>> + Send + 'static,
+    {
+        self.requests
+            .push(Box::new(move |account| build_tx(account).boxed()));
+
+        self
+    }
+
+    /// Adds a transfer of `amount` of `asset_id` to `to`.
+    pub fn push_transfer(
+        &mut self,
+        to: &Bech32Address,
+        amount: u64,
+        asset_id: AssetId,
+        tx_policies: TxPolicies,
+    ) -> &mut Self {
+        let to = to.clone();
+
+        self.push(move |account| {
+            let to = to.clone();
+            async move {
+                let inputs = account
+                    .get_asset_inputs_for_amount(asset_id, amount)
+                    .await?;
+                let outputs = account.get_asset_outputs_for_amount(&to, asset_id, amount);
+
+                let mut tx_builder =
+                    ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+
+                account.add_witnesses(&mut tx_builder)?;
+
+                let used_base_amount = if asset_id == AssetId::BASE { amount } else { 0 };
+                account
+                    .adjust_for_fee(&mut tx_builder, used_base_amount)
+                    .await?;
+
+                tx_builder.build(account.try_provider()?).await
+            }
+        })
+    }
+
+    /// Submits all requests and waits for their transactions to resolve. The responses are in
+    /// the order the requests were added. Fails only if the coins couldn't be split.
+    pub async fn run(self) -> Result<Vec<Result<PipelineResponse>>> {
+        self.split_coins().await?;
+
+        let responses = stream::iter(&self.requests)
+            .map(|build_tx| self.run_request(build_tx))
+            .buffered(self.max_in_flight)
+            .collect()
+            .await;
+
+        Ok(responses)
+    }
+
+    async fn split_coins(&self) -> Result<()> {
+        let slots = self.max_in_flight.min(self.requests.len());
+
+        for (asset_id, amount) in &self.coin_splits {
+            let usable = self
+                .account
+                .get_coins(*asset_id)
+                .await?
+                .iter()
+                .filter(|coin| coin.amount >= *amount)
+                .count();
+
+            if usable < slots {
+                self.account
+                    .split_coins(*asset_id, slots - usable, *amount)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn run_request(&self, build_tx: &BuildTx<A>) -> Result<PipelineResponse> {
+        let provider = self.account.try_provider()?;
+        let mut attempts = 0;
+
+        loop {
+            let account = PipelineAccount {
+                account: self.account.clone(),
+                reservations: self.reservations.clone(),
+                reserved: Default::default(),
+            };
+
+            let tx = match build_tx(account.clone()).await {
+                Ok(tx) => tx,
+                Err(error) => {
+                    self.reservations.release(&account.take_reserved()).await;
+
+                    // the request might only lack funds taken by other requests in flight
+                    let released = self.reservations.released.notified();
+                    if self.reservations.is_empty().await {
+                        return Err(error);
+                    }
+                    released.await;
+
+                    continue;
+                }
+            };
+
+            let submitted = Self::submit(provider, tx).await;
+            self.reservations.release(&account.take_reserved()).await;
+            let (tx_id, tx_status) = submitted?;
+            attempts += 1;
+
+            let squeezed_out = matches!(tx_status, TxStatus::SqueezedOut { .. });
+            if !squeezed_out || attempts == self.max_attempts {
+                return Ok(PipelineResponse {
+                    tx_id,
+                    tx_status,
+                    attempts,
+                });
+            }
+        }
+    }
+
+    async fn submit(provider: &Provider, tx: ScriptTransaction) -> Result<(TxId, TxStatus)> {
+        let tx_id = provider.send_transaction(tx).await?;
+        let tx_status = provider
+            .await_transaction_commit::<ScriptTransaction>(tx_id)
+            .await?;
+
+        Ok((tx_id, tx_status))
+    }
+}
+
+/// The resources taken by the requests of a pipeline that are in flight.
+#[derive(Debug, Default)]
+struct Reservations {
+    coins: Mutex<HashSet<CoinTypeId>>,
+    released: Notify,
+}
+
+impl Reservations {
+    async fn release(&self, ids: &[CoinTypeId]) {
+        let mut coins = self.coins.lock().await;
+        for id in ids {
+            coins.remove(id);
+        }
+
+        self.released.notify_waiters();
+    }
+
+    async fn is_empty(&self) -> bool {
+        self.coins.lock().await.is_empty()
+    }
+}
+
+/// The account requests of a [`TxPipeline`] are built with. It spends the resources of the
+/// pipeline's account, but never those used by another request in flight.
+#[derive(Debug, Clone)]
+pub struct PipelineAccount<A> {
+    account: A,
+    reservations: Arc<Reservations>,
+    reserved: Arc<StdMutex<Vec<CoinTypeId>>>,
+}
+
+impl<A> PipelineAccount<A> {
+    /// The account of the pipeline.
+    pub fn inner(&self) -> &A {
+        &self.account
+    }
+
+    fn take_reserved(&self) -> Vec<CoinTypeId> {
+        std::mem::take(
+            &mut self
+                .reserved
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        )
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<A: Account> ViewOnlyAccount for PipelineAccount<A> {
+    fn address(&self) -> &Bech32Address {
+        self.account.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.account.try_provider()
+    }
+
+    async fn get_spendable_resources(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<CoinType>> {
+        // held until the resources are reserved, so that no other request gets them as well
+        let mut reserved_coins = self.reservations.coins.lock().await;
+
+        let (excluded_utxos, excluded_message_nonces) =
+            reserved_coins
+                .iter()
+                .fold((vec![], vec![]), |(mut utxos, mut nonces), id| {
+                    match id {
+                        CoinTypeId::UtxoId(utxo_id) => utxos.push(*utxo_id),
+                        CoinTypeId::Nonce(nonce) => nonces.push(*nonce),
+                    }
+                    (utxos, nonces)
+                });
+        let filter = ResourceFilter {
+            from: self.address().clone(),
+            asset_id,
+            amount,
+            excluded_utxos,
+            excluded_message_nonces,
+        };
+
+        let resources = self.try_provider()?.get_spendable_resources(filter).await?;
+
+        let ids: Vec<_> = resources.iter().map(CoinType::id).collect();
+        reserved_coins.extend(ids.iter().cloned());
+        self.reserved
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .extend(ids);
+
+        Ok(resources)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<A: Account> Account for PipelineAccount<A> {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        Ok(self
+            .get_spendable_resources(asset_id, amount)
+            .await?
+            .into_iter()
+            .map(|resource| self.resource_input(resource))
+            .collect())
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        self.account.add_witnesses(tb)
+    }
+
+    fn resource_input(&self, resource: CoinType) -> Input {
+        self.account.resource_input(resource)
+    }
+}
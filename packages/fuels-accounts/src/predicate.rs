@@ -1,7 +1,10 @@
 use std::{fmt::Debug, fs};
 
 #[cfg(feature = "std")]
-use fuels_core::types::{input::Input, AssetId};
+use fuels_core::types::{
+    coin_type::CoinType, errors::error, input::Input, predicate_evaluation::PredicateEvaluation,
+    transaction::Transaction, AssetId,
+};
 use fuels_core::{
     types::{bech32::Bech32Address, errors::Result, unresolved_bytes::UnresolvedBytes},
     Configurables,
@@ -92,6 +95,34 @@ impl Predicate {
             ..self
         }
     }
+
+    /// Runs the predicate against the input at `input_index` of `tx` in an in-process VM,
+    /// without a node, and reports whether it passed, the gas it used and how it ended. Uses
+    /// the consensus parameters of the provider, or the default ones if there is none.
+    pub fn evaluate<T: Transaction>(
+        &self,
+        tx: &T,
+        input_index: usize,
+    ) -> Result<PredicateEvaluation> {
+        let predicate = tx
+            .inputs()
+            .get(input_index)
+            .and_then(|input| input.input_predicate());
+        if predicate != Some(self.code.as_slice()) {
+            return Err(error!(
+                Other,
+                "input `{input_index}` is not spent by this predicate"
+            ));
+        }
+
+        let consensus_parameters = self
+            .provider
+            .as_ref()
+            .map(|provider| provider.consensus_parameters().clone())
+            .unwrap_or_default();
+
+        tx.evaluate_predicate(input_index, &consensus_parameters)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -117,9 +148,104 @@ impl Account for Predicate {
             .get_spendable_resources(asset_id, amount)
             .await?
             .into_iter()
-            .map(|resource| {
-                Input::resource_predicate(resource, self.code.clone(), self.data.clone())
-            })
+            .map(|resource| self.resource_input(resource))
             .collect::<Vec<Input>>())
     }
+
+    fn resource_input(&self, resource: CoinType) -> Input {
+        Input::resource_predicate(resource, self.code.clone(), self.data.clone())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use fuel_asm::{op, Instruction, PanicReason, RegId};
+    use fuel_tx::{Finalizable, TransactionBuilder, TxPointer, UtxoId};
+    use fuels_core::types::{
+        predicate_evaluation::PredicateOutcome,
+        transaction::{EstimablePredicates, ScriptTransaction},
+    };
+
+    use super::*;
+
+    fn predicate(instructions: Vec<Instruction>) -> Predicate {
+        Predicate::from_code(instructions.into_iter().collect())
+    }
+
+    /// Spends a coin of each predicate, owned by the given address and with the given
+    /// `predicate_gas_used`.
+    fn spending_tx(inputs: &[(&Predicate, &Bech32Address, u64)]) -> ScriptTransaction {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        for (predicate, owner, predicate_gas_used) in inputs {
+            builder.add_input(fuel_tx::Input::coin_predicate(
+                UtxoId::default(),
+                (*owner).into(),
+                100,
+                Default::default(),
+                TxPointer::default(),
+                Default::default(),
+                *predicate_gas_used,
+                predicate.code().clone(),
+                vec![],
+            ));
+        }
+
+        builder.finalize().into()
+    }
+
+    #[test]
+    fn predicates_are_evaluated_offline() -> Result<()> {
+        let passing = predicate(vec![op::ret(RegId::ONE)]);
+        let failing = predicate(vec![op::ret(RegId::ZERO)]);
+        let logging = predicate(vec![op::log(RegId::ONE, 0, 0, 0), op::ret(RegId::ONE)]);
+        let tx = spending_tx(&[
+            (&passing, passing.address(), 0),
+            (&failing, failing.address(), 0),
+            (&logging, logging.address(), 0),
+            (&passing, failing.address(), 0),
+        ]);
+
+        let evaluation = passing.evaluate(&tx, 0)?;
+        assert!(!evaluation.passed());
+        assert_eq!(
+            evaluation.outcome,
+            PredicateOutcome::GasMismatch {
+                predicate_gas_used: 0
+            }
+        );
+        assert!(evaluation.gas_used > 0);
+
+        let mut estimated_tx = spending_tx(&[(&passing, passing.address(), 0)]);
+        estimated_tx.estimate_predicates(&Default::default())?;
+        assert!(passing.evaluate(&estimated_tx, 0)?.passed());
+
+        let evaluation = passing.evaluate(&tx, 3)?;
+        assert!(!evaluation.passed());
+        assert_eq!(evaluation.outcome, PredicateOutcome::InvalidOwner);
+
+        // ANCHOR: evaluate_predicate
+        let evaluation = failing.evaluate(&tx, 1)?;
+        assert!(!evaluation.passed());
+        assert_eq!(evaluation.outcome, PredicateOutcome::Returned(0));
+        // ANCHOR_END: evaluate_predicate
+
+        let evaluation = logging.evaluate(&tx, 2)?;
+        let PredicateOutcome::Panicked(panic) = evaluation.outcome else {
+            panic!(
+                "expected the predicate to panic, got: {:?}",
+                evaluation.outcome
+            );
+        };
+        assert_eq!(*panic.reason(), PanicReason::ContractInstructionNotAllowed);
+
+        let error = passing
+            .evaluate(&tx, 1)
+            .expect_err("should fail for an input of another predicate");
+        assert_eq!(
+            error.to_string(),
+            "input `1` is not spent by this predicate"
+        );
+
+        Ok(())
+    }
 }
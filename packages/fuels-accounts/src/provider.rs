@@ -1,12 +1,13 @@
-use std::{collections::HashMap, fmt::Debug, net::SocketAddr};
+use std::{collections::HashMap, fmt::Debug, net::SocketAddr, ops::Range, sync::Arc};
 
 mod retry_util;
 mod retryable_client;
+mod streams;
 mod supported_versions;
 
 #[cfg(feature = "coin-cache")]
-use std::sync::Arc;
-
+use crate::coin_cache::{CoinLockStore, CoinsCache};
+use crate::{coin_selection::CoinSelector, provider::retryable_client::RetryableClient};
 use chrono::{DateTime, Utc};
 use fuel_core_client::client::{
     pagination::{PageDirection, PaginatedResult, PaginationRequest},
@@ -16,11 +17,13 @@ use fuel_tx::{
     AssetId, ConsensusParameters, Receipt, ScriptExecutionResult, Transaction as FuelTransaction,
     TxId, UtxoId,
 };
-use fuel_types::{Address, Bytes32, ChainId, Nonce};
+use fuel_types::{Address, Bytes32, ChainId, ContractId, Nonce};
 #[cfg(feature = "coin-cache")]
 use fuels_core::types::coin_type_id::CoinTypeId;
 use fuels_core::{
+    codec::LogDecoder,
     constants::{BASE_ASSET_ID, DEFAULT_GAS_ESTIMATION_TOLERANCE},
+    traits::{Parameterize, Tokenizable},
     types::{
         bech32::{Bech32Address, Bech32ContractId},
         block::Block,
@@ -28,7 +31,7 @@ use fuels_core::{
         coin::Coin,
         coin_type::CoinType,
         errors::{error, Result},
-        message::Message,
+        message::{Message, MessageStatus},
         message_proof::MessageProof,
         node_info::NodeInfo,
         transaction::Transaction,
@@ -37,15 +40,15 @@ use fuels_core::{
         tx_status::TxStatus,
     },
 };
+use futures::{stream, stream::BoxStream, StreamExt, TryStreamExt};
 pub use retry_util::{Backoff, RetryConfig};
+pub use streams::StreamCursor;
 use supported_versions::{check_fuel_core_version_compatibility, VersionCompatibility};
 use tai64::Tai64;
-#[cfg(feature = "coin-cache")]
-use tokio::sync::Mutex;
 
+const STREAM_PAGE_SIZE: i32 = 100;
 #[cfg(feature = "coin-cache")]
-use crate::coin_cache::CoinsCache;
-use crate::provider::retryable_client::RetryableClient;
+const MAX_COIN_RESERVATION_ATTEMPTS: usize = 10;
 
 #[derive(Debug)]
 pub struct TransactionCost {
@@ -56,6 +59,16 @@ pub struct TransactionCost {
     pub total_fee: u64,
 }
 
+/// A value logged by a contract, alongside the transaction that logged it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractLog<T> {
+    pub block_height: u32,
+    pub tx_id: TxId,
+    /// The index of the log's receipt among the receipts of the transaction.
+    pub receipt_index: usize,
+    pub value: T,
+}
+
 pub(crate) struct ResourceQueries {
     utxos: Vec<UtxoId>,
     messages: Vec<Nonce>,
@@ -135,8 +148,9 @@ impl Default for ResourceFilter {
 pub struct Provider {
     client: RetryableClient,
     consensus_parameters: ConsensusParameters,
+    coin_selector: Option<Arc<dyn CoinSelector>>,
     #[cfg(feature = "coin-cache")]
-    cache: Arc<Mutex<CoinsCache>>,
+    cache: CoinsCache,
 }
 
 impl Provider {
@@ -160,6 +174,7 @@ impl Provider {
         Ok(Self {
             client,
             consensus_parameters,
+            coin_selector: None,
             #[cfg(feature = "coin-cache")]
             cache: Default::default(),
         })
@@ -175,6 +190,10 @@ impl Provider {
         mut tx: T,
     ) -> Result<TxStatus> {
         self.prepare_transaction_for_sending(&mut tx).await?;
+
+        #[cfg(feature = "coin-cache")]
+        let tx_id = self.lock_used_coins(&tx).await?;
+
         let tx_status = self
             .client
             .submit_and_await_commit(&tx.clone().into())
@@ -182,12 +201,7 @@ impl Provider {
             .into();
 
         #[cfg(feature = "coin-cache")]
-        if matches!(
-            tx_status,
-            TxStatus::SqueezedOut { .. } | TxStatus::Revert { .. }
-        ) {
-            self.cache.lock().await.remove_items(tx.used_coins())
-        }
+        self.release_coins_on_final_status(&tx_id, &tx_status).await;
 
         Ok(tx_status)
     }
@@ -215,7 +229,12 @@ impl Provider {
     }
 
     pub async fn await_transaction_commit<T: Transaction>(&self, id: TxId) -> Result<TxStatus> {
-        Ok(self.client.await_transaction_commit(&id).await?.into())
+        let tx_status = self.client.await_transaction_commit(&id).await?.into();
+
+        #[cfg(feature = "coin-cache")]
+        self.release_coins_on_final_status(&id, &tx_status).await;
+
+        Ok(tx_status)
     }
 
     async fn validate_transaction<T: Transaction>(&self, tx: T) -> Result<()> {
@@ -240,15 +259,95 @@ impl Provider {
 
     #[cfg(feature = "coin-cache")]
     async fn submit<T: Transaction>(&self, tx: T) -> Result<TxId> {
-        let used_utxos = tx.used_coins();
-        let tx_id = self.client.submit(&tx.into()).await?;
-        self.cache.lock().await.insert_multiple(used_utxos);
+        let tx_id = self.lock_used_coins(&tx).await?;
+
+        if let Err(e) = self.client.submit(&tx.into()).await {
+            self.cache.release(&tx_id).await?;
+            return Err(e.into());
+        }
 
         Ok(tx_id)
     }
 
+    /// Locks the coins spent by `tx` in the coin cache, before it is submitted, so that other
+    /// providers sharing the cache's store don't spend them as well. This takes over the
+    /// reservations made when the coins were selected.
+    #[cfg(feature = "coin-cache")]
+    async fn lock_used_coins<T: Transaction>(&self, tx: &T) -> Result<TxId> {
+        let tx_id = tx.id(self.chain_id());
+        self.cache.lock(tx_id, tx.used_coins()).await?;
+
+        Ok(tx_id)
+    }
+
+    /// Releases the coins of the transaction `tx_id` once they are either spent or free again.
+    /// Failing to do so isn't an error, as the locks expire anyway.
+    #[cfg(feature = "coin-cache")]
+    async fn release_coins_on_final_status(&self, tx_id: &TxId, tx_status: &TxStatus) {
+        if matches!(tx_status, TxStatus::Submitted) {
+            return;
+        }
+
+        if let Err(error) = self.cache.release(tx_id).await {
+            tracing::warn!(%error, %tx_id, "could not release the coins locked by a transaction");
+        }
+    }
+
     pub async fn tx_status(&self, tx_id: &TxId) -> Result<TxStatus> {
-        Ok(self.client.transaction_status(tx_id).await?.into())
+        let tx_status = self.client.transaction_status(tx_id).await?.into();
+
+        #[cfg(feature = "coin-cache")]
+        self.release_coins_on_final_status(tx_id, &tx_status).await;
+
+        Ok(tx_status)
+    }
+
+    /// Streams the status of the transaction with id `tx_id` as it changes: `Submitted` while the
+    /// transaction is in the txpool, followed by one of `Success`, `Revert` or `SqueezedOut`, after
+    /// which the stream ends.
+    ///
+    /// The stream is backed by the node's GraphQL subscriptions. If the subscription cannot be
+    /// established, the status is polled instead, with the polls spaced out according to the
+    /// `Backoff` of the provider's `RetryConfig`.
+    pub async fn subscribe_tx_status(
+        &self,
+        tx_id: &TxId,
+    ) -> Result<BoxStream<'_, Result<TxStatus>>> {
+        let statuses = match self.client.subscribe_transaction_status(tx_id).await {
+            Ok(subscription) => streams::until_final_status(
+                subscription
+                    .map(|status| status.map(Into::into).map_err(|e| error!(Provider, "{e}"))),
+            )
+            .boxed(),
+            Err(e) => {
+                tracing::debug!(
+                    error = %e,
+                    "could not subscribe to the transaction status, falling back to polling",
+                );
+                let tx_id = *tx_id;
+                streams::poll_tx_status(
+                    move || async move { Ok(self.client.transaction_status(&tx_id).await?.into()) },
+                    self.client.retry_config().interval().clone(),
+                )
+                .boxed()
+            }
+        };
+
+        #[cfg(feature = "coin-cache")]
+        let statuses = {
+            let tx_id = *tx_id;
+            statuses
+                .then(move |status| async move {
+                    if let Ok(status) = &status {
+                        self.release_coins_on_final_status(&tx_id, status).await;
+                    }
+
+                    status
+                })
+                .boxed()
+        };
+
+        Ok(statuses)
     }
 
     pub async fn chain_info(&self) -> Result<ChainInfo> {
@@ -364,7 +463,13 @@ impl Provider {
         Ok(coins)
     }
 
-    async fn request_coins_to_spend(&self, filter: ResourceFilter) -> Result<Vec<CoinType>> {
+    async fn request_coins_to_spend(&self, filter: &ResourceFilter) -> Result<Vec<CoinType>> {
+        if let Some(coin_selector) = &self.coin_selector {
+            return self
+                .select_coins_to_spend(coin_selector.as_ref(), filter)
+                .await;
+        }
+
         let queries = filter.resource_queries();
 
         let res = self
@@ -383,55 +488,105 @@ impl Provider {
         Ok(res)
     }
 
+    async fn select_coins_to_spend(
+        &self,
+        coin_selector: &dyn CoinSelector,
+        filter: &ResourceFilter,
+    ) -> Result<Vec<CoinType>> {
+        let mut candidates: Vec<CoinType> = self
+            .get_coins(&filter.from, filter.asset_id)
+            .await?
+            .into_iter()
+            .filter(|coin| !filter.excluded_utxos.contains(&coin.utxo_id))
+            .map(CoinType::Coin)
+            .collect();
+
+        // messages carrying data can only be spent by predicates, so only plain transfers count
+        if filter.asset_id == BASE_ASSET_ID {
+            candidates.extend(
+                self.get_messages(&filter.from)
+                    .await?
+                    .into_iter()
+                    .filter(|message| {
+                        message.data.is_empty()
+                            && message.status == MessageStatus::Unspent
+                            && !filter.excluded_message_nonces.contains(&message.nonce)
+                    })
+                    .map(CoinType::Message),
+            );
+        }
+
+        let selected = coin_selector.select(candidates, filter.amount)?;
+
+        let max_inputs = self.consensus_parameters.tx_params().max_inputs as usize;
+        if selected.len() > max_inputs {
+            return Err(error!(
+                Other,
+                "coin selector `{coin_selector:?}` selected {} resources, but a transaction can \
+                 have at most {max_inputs} inputs",
+                selected.len()
+            ));
+        }
+
+        Ok(selected)
+    }
+
     /// Get some spendable coins of asset `asset_id` for address `from` that add up at least to
     /// amount `amount`. The returned coins (UTXOs) are actual coins that can be spent. The number
     /// of coins (UXTOs) is optimized to prevent dust accumulation.
     #[cfg(not(feature = "coin-cache"))]
     pub async fn get_spendable_resources(&self, filter: ResourceFilter) -> Result<Vec<CoinType>> {
-        self.request_coins_to_spend(filter).await
+        self.request_coins_to_spend(&filter).await
     }
 
     /// Get some spendable coins of asset `asset_id` for address `from` that add up at least to
     /// amount `amount`. The returned coins (UTXOs) are actual coins that can be spent. The number
     /// of coins (UXTOs) is optimized to prevent dust accumulation.
-    /// Coins that were recently submitted inside a tx will be ignored from the results.
+    /// Coins that were recently submitted inside a tx, or selected by another provider sharing
+    /// the coin lock store, will be ignored from the results. The returned coins are reserved
+    /// until the transaction spending them is submitted, or the reservation expires.
     #[cfg(feature = "coin-cache")]
     pub async fn get_spendable_resources(
         &self,
         mut filter: ResourceFilter,
     ) -> Result<Vec<CoinType>> {
-        self.extend_filter_with_cached(&mut filter).await;
+        self.extend_filter_with_cached(&mut filter).await?;
+
+        for _ in 0..MAX_COIN_RESERVATION_ATTEMPTS {
+            let resources = self.request_coins_to_spend(&filter).await?;
+
+            let coins = HashMap::from([(
+                (filter.from.clone(), filter.asset_id),
+                resources.iter().map(CoinType::id).collect(),
+            )]);
+            let taken = self.cache.reserve(coins).await?;
+            if taken.is_empty() {
+                return Ok(resources);
+            }
 
-        self.request_coins_to_spend(filter).await
+            // another provider selected some of the coins in the meantime
+            extend_filter(&mut filter, &taken);
+        }
+
+        Err(error!(
+            Other,
+            "could not reserve coins of asset `{}` for `{}`, they kept being selected by other \
+             providers sharing the coin lock store",
+            filter.asset_id,
+            filter.from
+        ))
     }
 
     #[cfg(feature = "coin-cache")]
-    async fn extend_filter_with_cached(&self, filter: &mut ResourceFilter) {
-        let mut cache = self.cache.lock().await;
-        let used_coins = cache.get_active(&(filter.from.clone(), filter.asset_id));
-
-        let excluded_utxos = used_coins
-            .iter()
-            .filter_map(|coin_id| match coin_id {
-                CoinTypeId::UtxoId(utxo_id) => Some(utxo_id),
-                _ => None,
-            })
-            .cloned()
-            .collect::<Vec<_>>();
+    async fn extend_filter_with_cached(&self, filter: &mut ResourceFilter) -> Result<()> {
+        let used_coins = self
+            .cache
+            .get_active(&(filter.from.clone(), filter.asset_id))
+            .await?;
 
-        let excluded_message_nonces = used_coins
-            .iter()
-            .filter_map(|coin_id| match coin_id {
-                CoinTypeId::Nonce(nonce) => Some(nonce),
-                _ => None,
-            })
-            .cloned()
-            .collect::<Vec<_>>();
+        extend_filter(filter, &used_coins);
 
-        filter.excluded_utxos.extend(excluded_utxos);
-        filter
-            .excluded_message_nonces
-            .extend(excluded_message_nonces);
+        Ok(())
     }
 
     /// Get the balance of all spendable coins `asset_id` for address `address`. This is different
@@ -448,6 +603,11 @@ impl Provider {
             .await?)
     }
 
+    /// Checks whether a contract with id `contract_id` is deployed.
+    pub async fn contract_exists(&self, contract_id: &Bech32ContractId) -> Result<bool> {
+        Ok(self.client.contract(&contract_id.into()).await?.is_some())
+    }
+
     /// Get the balance of all spendable coins `asset_id` for contract with id `contract_id`.
     pub async fn get_contract_asset_balance(
         &self,
@@ -599,6 +759,197 @@ impl Provider {
         })
     }
 
+    /// Returns the values of type `T` logged by `contract_id` in the blocks within `heights`,
+    /// oldest first. Only the logs `log_decoder` knows to be of type `T` are decoded, so it must
+    /// contain the log formatters of the contract, e.g. through the contract's bindings. Logs of
+    /// reverted transactions are skipped.
+    pub async fn query_logs<T: Tokenizable + Parameterize + 'static>(
+        &self,
+        contract_id: &Bech32ContractId,
+        heights: Range<u32>,
+        log_decoder: &LogDecoder,
+    ) -> Result<Vec<ContractLog<T>>> {
+        if heights.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let contract_id = contract_id.into();
+        let mut logs = vec![];
+        // the node's block cursor is the height of the last block of the previous page
+        let mut cursor = heights
+            .start
+            .checked_sub(1)
+            .map(|height| height.to_string());
+
+        loop {
+            let page = self
+                .get_blocks(PaginationRequest {
+                    cursor,
+                    results: STREAM_PAGE_SIZE,
+                    direction: PageDirection::Forward,
+                })
+                .await?;
+
+            let blocks: Vec<_> = page
+                .results
+                .iter()
+                .take_while(|block| heights.contains(&block.header.height))
+                .collect();
+            logs.extend(
+                self.logs_in_blocks(&blocks, contract_id, log_decoder)
+                    .await?,
+            );
+
+            let reached_end = page
+                .results
+                .last()
+                .map_or(true, |block| block.header.height + 1 >= heights.end);
+            if reached_end || !page.has_next_page {
+                break;
+            }
+            cursor = page.cursor;
+        }
+
+        Ok(logs)
+    }
+
+    /// Streams the values of type `T` logged by `contract_id` in the blocks produced from now on,
+    /// see `query_logs`. Blocks are polled for through `subscribe_blocks`. The stream ends after
+    /// the first error.
+    pub async fn subscribe_logs<T: Tokenizable + Parameterize + Send + 'static>(
+        &self,
+        contract_id: &Bech32ContractId,
+        log_decoder: &LogDecoder,
+    ) -> Result<BoxStream<'_, Result<ContractLog<T>>>> {
+        let contract_id = contract_id.into();
+        let log_decoder = log_decoder.clone();
+        let blocks = self.subscribe_blocks().await?;
+
+        let logs = blocks
+            .then(move |block| {
+                let log_decoder = log_decoder.clone();
+                async move {
+                    let (block, _) = block?;
+                    self.logs_in_blocks(&[&block], contract_id, &log_decoder)
+                        .await
+                }
+            })
+            .map_ok(|logs| stream::iter(logs.into_iter().map(Ok)))
+            .try_flatten();
+
+        Ok(streams::until_first_error(logs).boxed())
+    }
+
+    async fn logs_in_blocks<T: Tokenizable + Parameterize + 'static>(
+        &self,
+        blocks: &[&Block],
+        contract_id: ContractId,
+        log_decoder: &LogDecoder,
+    ) -> Result<Vec<ContractLog<T>>> {
+        let tx_ids: Vec<_> = blocks
+            .iter()
+            .flat_map(|block| {
+                let height = block.header.height;
+                block.transactions.iter().map(move |tx_id| (height, *tx_id))
+            })
+            .collect();
+        let statuses: Vec<_> = stream::iter(tx_ids)
+            .map(|(height, tx_id)| async move {
+                let status = self.tx_status(&tx_id).await?;
+                Result::Ok((height, tx_id, status))
+            })
+            .buffered(STREAM_PAGE_SIZE as usize)
+            .try_collect()
+            .await?;
+
+        let mut logs = vec![];
+        for (block_height, tx_id, status) in statuses {
+            let TxStatus::Success { receipts } = status else {
+                continue;
+            };
+
+            for (receipt_index, receipt) in receipts.iter().enumerate() {
+                let logged_by_contract = match receipt {
+                    Receipt::Log { id, .. } | Receipt::LogData { id, .. } => *id == contract_id,
+                    _ => false,
+                };
+                if !logged_by_contract {
+                    continue;
+                }
+
+                let values =
+                    log_decoder.decode_logs_with_type::<T>(std::slice::from_ref(receipt))?;
+                logs.extend(values.into_iter().map(|value| ContractLog {
+                    block_height,
+                    tx_id,
+                    receipt_index,
+                    value,
+                }));
+            }
+        }
+
+        Ok(logs)
+    }
+
+    /// Streams every block produced after the current latest block, see `subscribe_blocks_from`.
+    pub async fn subscribe_blocks(&self) -> Result<BoxStream<'_, Result<(Block, StreamCursor)>>> {
+        let cursor = streams::latest_cursor(|request| self.get_blocks(request)).await?;
+
+        Ok(self.subscribe_blocks_from(cursor))
+    }
+
+    /// Streams every block following `cursor`, each paired with its own cursor. Once the latest
+    /// block is reached, the node is polled for new ones, with the polls spaced out according to
+    /// the `Backoff` of the provider's `RetryConfig`. The stream ends after the first error.
+    pub fn subscribe_blocks_from(
+        &self,
+        cursor: StreamCursor,
+    ) -> BoxStream<'_, Result<(Block, StreamCursor)>> {
+        streams::poll_pages(
+            |request| self.get_blocks(request),
+            cursor,
+            STREAM_PAGE_SIZE,
+            self.client.retry_config().interval().clone(),
+        )
+        .boxed()
+    }
+
+    /// Streams every transaction involving `owner` that is included after the current latest
+    /// one, see `watch_owner_from`.
+    pub async fn watch_owner(
+        &self,
+        owner: &Bech32Address,
+    ) -> Result<BoxStream<'_, Result<(TransactionResponse, StreamCursor)>>> {
+        let cursor =
+            streams::latest_cursor(|request| self.get_transactions_by_owner(owner, request))
+                .await?;
+
+        Ok(self.watch_owner_from(owner, cursor))
+    }
+
+    /// Streams every transaction involving `owner` following `cursor`, each paired with its own
+    /// cursor. Once the latest transaction is reached, the node is polled for new ones, with the
+    /// polls spaced out according to the `Backoff` of the provider's `RetryConfig`. The stream
+    /// ends after the first error.
+    pub fn watch_owner_from(
+        &self,
+        owner: &Bech32Address,
+        cursor: StreamCursor,
+    ) -> BoxStream<'_, Result<(TransactionResponse, StreamCursor)>> {
+        let owner = owner.clone();
+
+        streams::poll_pages(
+            move |request| {
+                let owner = owner.clone();
+                async move { self.get_transactions_by_owner(&owner, request).await }
+            },
+            cursor,
+            STREAM_PAGE_SIZE,
+            self.client.retry_config().interval().clone(),
+        )
+        .boxed()
+    }
+
     pub async fn estimate_transaction_cost<T: Transaction>(
         &self,
         tx: T,
@@ -692,6 +1043,35 @@ impl Provider {
 
         self
     }
+
+    /// Selects the resources spent by transactions funded through this provider on the client,
+    /// with `coin_selector`, instead of on the node.
+    pub fn with_coin_selector(mut self, coin_selector: impl CoinSelector + 'static) -> Self {
+        self.coin_selector = Some(Arc::new(coin_selector));
+
+        self
+    }
+
+    /// Keeps the coins locked by transactions submitted through this provider in `store`. Giving
+    /// providers a store backed by the same file, e.g. a `FileCoinLockStore`, keeps them from
+    /// spending each other's coins, even across processes.
+    #[cfg(feature = "coin-cache")]
+    pub fn with_coin_lock_store(mut self, store: impl CoinLockStore + 'static) -> Self {
+        self.cache = self.cache.with_store(store);
+
+        self
+    }
+}
+
+/// Excludes `coins` from the resources selected with `filter`.
+#[cfg(feature = "coin-cache")]
+fn extend_filter(filter: &mut ResourceFilter, coins: &std::collections::HashSet<CoinTypeId>) {
+    for coin in coins {
+        match coin {
+            CoinTypeId::UtxoId(utxo_id) => filter.excluded_utxos.push(*utxo_id),
+            CoinTypeId::Nonce(nonce) => filter.excluded_message_nonces.push(*nonce),
+        }
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
@@ -40,10 +40,16 @@ impl Default for Backoff {
 }
 
 impl Backoff {
+    /// The time to wait after the given attempt. Saturates at `Duration::MAX` instead of
+    /// overflowing, so that it can be used for any number of attempts.
     pub fn wait_duration(&self, attempt: u32) -> Duration {
         match self {
-            Backoff::Linear(base_duration) => *base_duration * (attempt + 1),
-            Backoff::Exponential(base_duration) => *base_duration * 2u32.pow(attempt),
+            Backoff::Linear(base_duration) => {
+                base_duration.saturating_mul(attempt.saturating_add(1))
+            }
+            Backoff::Exponential(base_duration) => 2u32
+                .checked_pow(attempt)
+                .map_or(Duration::MAX, |factor| base_duration.saturating_mul(factor)),
             Backoff::Fixed(interval) => *interval,
         }
     }
@@ -90,6 +96,10 @@ impl RetryConfig {
             interval,
         })
     }
+
+    pub(crate) fn interval(&self) -> &Backoff {
+        &self.interval
+    }
 }
 
 impl Default for RetryConfig {
@@ -150,6 +160,34 @@ where
 
 #[cfg(test)]
 mod tests {
+    mod backoff {
+        use std::time::Duration;
+
+        use crate::provider::Backoff;
+
+        #[test]
+        fn wait_duration_saturates_instead_of_overflowing() {
+            let base_duration = Duration::from_millis(10);
+
+            assert_eq!(
+                Backoff::Exponential(base_duration).wait_duration(3),
+                Duration::from_millis(80)
+            );
+            assert_eq!(
+                Backoff::Exponential(base_duration).wait_duration(32),
+                Duration::MAX
+            );
+            assert_eq!(
+                Backoff::Linear(base_duration).wait_duration(u32::MAX),
+                base_duration * u32::MAX
+            );
+            assert_eq!(
+                Backoff::Linear(Duration::MAX).wait_duration(1),
+                Duration::MAX
+            );
+        }
+    }
+
     mod retry_until {
         use std::time::{Duration, Instant};
 
@@ -4,14 +4,15 @@ use fuel_core_client::client::{
     pagination::{PaginatedResult, PaginationRequest},
     types::{
         primitives::{BlockId, TransactionId},
-        Balance, Block, ChainInfo, Coin, CoinType, ContractBalance, Message, MessageProof,
-        NodeInfo, TransactionResponse, TransactionStatus,
+        Balance, Block, ChainInfo, Coin, CoinType, Contract, ContractBalance, Message,
+        MessageProof, NodeInfo, TransactionResponse, TransactionStatus,
     },
     FuelClient,
 };
 use fuel_tx::{Receipt, Transaction, TxId, UtxoId};
 use fuel_types::{Address, AssetId, BlockHeight, ContractId, Nonce};
 use fuels_core::types::errors::{error, Error, Result};
+use futures::Stream;
 
 use crate::provider::{retry_util, RetryConfig};
 
@@ -52,6 +53,10 @@ impl RetryableClient {
         &self.url
     }
 
+    pub(crate) fn retry_config(&self) -> &RetryConfig {
+        &self.retry_config
+    }
+
     pub(crate) fn set_retry_config(&mut self, retry_config: RetryConfig) {
         self.retry_config = retry_config;
     }
@@ -97,6 +102,14 @@ impl RetryableClient {
         self.our_retry(|| self.client.transaction_status(id)).await
     }
 
+    pub async fn subscribe_transaction_status(
+        &self,
+        id: &TxId,
+    ) -> RequestResult<impl Stream<Item = io::Result<TransactionStatus>> + '_> {
+        self.our_retry(|| self.client.subscribe_transaction_status(id))
+            .await
+    }
+
     pub async fn node_info(&self) -> RequestResult<NodeInfo> {
         self.our_retry(|| self.client.node_info()).await
     }
@@ -142,6 +155,10 @@ impl RetryableClient {
             .await
     }
 
+    pub async fn contract(&self, id: &ContractId) -> RequestResult<Option<Contract>> {
+        self.our_retry(|| self.client.contract(id)).await
+    }
+
     pub async fn contract_balance(
         &self,
         id: &ContractId,
@@ -0,0 +1,374 @@
+use std::{
+    collections::VecDeque,
+    future::{ready, Future},
+};
+
+use fuel_core_client::client::pagination::{PageDirection, PaginatedResult, PaginationRequest};
+use fuels_core::types::{errors::Result, tx_status::TxStatus};
+use futures::{stream, Stream, StreamExt};
+
+use crate::provider::Backoff;
+
+/// Ends `statuses` right after the first status that isn't `Submitted`. Errors end the stream as
+/// well.
+pub(crate) fn until_final_status(
+    statuses: impl Stream<Item = Result<TxStatus>>,
+) -> impl Stream<Item = Result<TxStatus>> {
+    statuses.scan(false, |finished, status| {
+        if *finished {
+            return ready(None);
+        }
+
+        *finished = !matches!(status, Ok(TxStatus::Submitted));
+
+        ready(Some(status))
+    })
+}
+
+/// Ends `items` right after the first error.
+pub(crate) fn until_first_error<T>(
+    items: impl Stream<Item = Result<T>>,
+) -> impl Stream<Item = Result<T>> {
+    items.scan(false, |failed, item| {
+        if *failed {
+            return ready(None);
+        }
+
+        *failed = item.is_err();
+
+        ready(Some(item))
+    })
+}
+
+struct TxStatusPoller<F> {
+    fetch_status: F,
+    interval: Backoff,
+    reported_submitted: bool,
+    finished: bool,
+}
+
+/// Polls the status returned by `fetch_status` until it becomes final. Every status is reported
+/// once. Polls are spaced out according to `interval`, counting the attempts since the last
+/// reported status. The stream ends right after the final status, without polling again. Failing
+/// polls are retried by `fetch_status` itself, e.g. by the `RetryableClient`, and their error
+/// ends the stream.
+pub(crate) fn poll_tx_status<F, Fut>(
+    fetch_status: F,
+    interval: Backoff,
+) -> impl Stream<Item = Result<TxStatus>>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<TxStatus>>,
+{
+    let poller = TxStatusPoller {
+        fetch_status,
+        interval,
+        reported_submitted: false,
+        finished: false,
+    };
+
+    stream::unfold(poller, |mut poller| async move {
+        if poller.finished {
+            return None;
+        }
+
+        let mut attempt = 0;
+        loop {
+            let status = (poller.fetch_status)().await;
+
+            match status {
+                Ok(TxStatus::Submitted) if poller.reported_submitted => {}
+                Ok(TxStatus::Submitted) => {
+                    poller.reported_submitted = true;
+                    return Some((status, poller));
+                }
+                _ => {
+                    poller.finished = true;
+                    return Some((status, poller));
+                }
+            }
+
+            tokio::time::sleep(poller.interval.wait_duration(attempt)).await;
+            attempt += 1;
+        }
+    })
+}
+
+/// The position of an item emitted by one of the `Provider` streams. A stream started from the
+/// cursor of an item continues with the item right after it, so that an indexer can persist the
+/// cursor of the last item it processed and resume from it after a restart.
+///
+/// The default cursor points to the start of the chain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StreamCursor {
+    /// The node's pagination cursor after which the page containing the next item starts.
+    pub page_cursor: Option<String>,
+    /// The number of items of that page which were already emitted.
+    pub skip: usize,
+}
+
+struct PagePoller<T, F> {
+    fetch_page: F,
+    cursor: StreamCursor,
+    page_size: i32,
+    interval: Backoff,
+    buffered: VecDeque<(T, StreamCursor)>,
+    failed: bool,
+}
+
+impl<T, F, Fut> PagePoller<T, F>
+where
+    F: Fn(PaginationRequest<String>) -> Fut,
+    Fut: Future<Output = Result<PaginatedResult<T, String>>>,
+{
+    /// Fetches the page `cursor` points to and buffers the items which weren't emitted yet.
+    /// Returns whether the page was full, i.e. whether the next page might already have items.
+    async fn fetch_next_items(&mut self) -> Result<bool> {
+        let page = (self.fetch_page)(PaginationRequest {
+            cursor: self.cursor.page_cursor.clone(),
+            results: self.page_size,
+            direction: PageDirection::Forward,
+        })
+        .await?;
+
+        let page_len = page.results.len();
+        let page_is_full = page_len == self.page_size as usize;
+
+        for (index, item) in page.results.into_iter().enumerate().skip(self.cursor.skip) {
+            let cursor = StreamCursor {
+                page_cursor: self.cursor.page_cursor.clone(),
+                skip: index + 1,
+            };
+            self.buffered.push_back((item, cursor));
+        }
+
+        self.cursor = match page.cursor {
+            Some(page_cursor) if page_is_full => StreamCursor {
+                page_cursor: Some(page_cursor),
+                skip: 0,
+            },
+            _ => StreamCursor {
+                page_cursor: self.cursor.page_cursor.clone(),
+                skip: self.cursor.skip.max(page_len),
+            },
+        };
+
+        Ok(page_is_full)
+    }
+}
+
+/// Endlessly pages forward through the results of `fetch_page`, starting from `cursor`. Once all
+/// the available items have been emitted, the last page is polled for new ones, with the polls
+/// spaced out according to `interval`. The stream ends after the first error.
+pub(crate) fn poll_pages<T, F, Fut>(
+    fetch_page: F,
+    cursor: StreamCursor,
+    page_size: i32,
+    interval: Backoff,
+) -> impl Stream<Item = Result<(T, StreamCursor)>>
+where
+    F: Fn(PaginationRequest<String>) -> Fut,
+    Fut: Future<Output = Result<PaginatedResult<T, String>>>,
+{
+    let poller = PagePoller {
+        fetch_page,
+        cursor,
+        page_size,
+        interval,
+        buffered: VecDeque::new(),
+        failed: false,
+    };
+
+    stream::unfold(poller, |mut poller| async move {
+        if poller.failed {
+            return None;
+        }
+
+        let mut attempt = 0;
+        loop {
+            if let Some(item) = poller.buffered.pop_front() {
+                return Some((Ok(item), poller));
+            }
+
+            match poller.fetch_next_items().await {
+                Ok(page_was_full) if !page_was_full && poller.buffered.is_empty() => {
+                    tokio::time::sleep(poller.interval.wait_duration(attempt)).await;
+                    attempt += 1;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    poller.failed = true;
+                    return Some((Err(e), poller));
+                }
+            }
+        }
+    })
+}
+
+/// Returns the cursor pointing right after the newest item returned by `fetch_page`.
+pub(crate) async fn latest_cursor<T, F, Fut>(fetch_page: F) -> Result<StreamCursor>
+where
+    F: FnOnce(PaginationRequest<String>) -> Fut,
+    Fut: Future<Output = Result<PaginatedResult<T, String>>>,
+{
+    let page = fetch_page(PaginationRequest {
+        cursor: None,
+        results: 1,
+        direction: PageDirection::Backward,
+    })
+    .await?;
+
+    Ok(StreamCursor {
+        page_cursor: page.cursor,
+        skip: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::errors::error;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn stream_ends_after_final_status() {
+        let statuses = stream::iter([
+            Ok(TxStatus::Submitted),
+            Ok(TxStatus::SqueezedOut {
+                reason: "gas price too low".to_string(),
+            }),
+            Ok(TxStatus::Submitted),
+        ]);
+
+        let statuses: Vec<_> = until_final_status(statuses).collect().await;
+
+        assert!(matches!(
+            statuses.as_slice(),
+            [Ok(TxStatus::Submitted), Ok(TxStatus::SqueezedOut { .. })]
+        ));
+    }
+
+    #[tokio::test]
+    async fn status_is_polled_until_it_is_final() -> Result<()> {
+        let polled_statuses = std::sync::Mutex::new(vec![
+            TxStatus::Submitted,
+            TxStatus::Submitted,
+            TxStatus::Submitted,
+            TxStatus::Success { receipts: vec![] },
+        ]);
+        let fetch_status = || ready(Ok(polled_statuses.lock().unwrap().remove(0)));
+        let interval = crate::provider::RetryConfig::default().interval().clone();
+
+        let statuses = poll_tx_status(fetch_status, interval)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+
+        assert!(matches!(
+            statuses.as_slice(),
+            [TxStatus::Submitted, TxStatus::Success { .. }]
+        ));
+
+        Ok(())
+    }
+
+    fn paginate(items: &[u32], request: PaginationRequest<String>) -> PaginatedResult<u32, String> {
+        let start = request
+            .cursor
+            .map(|cursor| cursor.parse::<usize>().unwrap() + 1)
+            .unwrap_or_default();
+        let results: Vec<_> = items
+            .iter()
+            .skip(start)
+            .take(request.results as usize)
+            .copied()
+            .collect();
+        let end = start + results.len();
+
+        PaginatedResult {
+            cursor: (!results.is_empty()).then(|| (end - 1).to_string()),
+            has_next_page: end < items.len(),
+            has_previous_page: start > 0,
+            results,
+        }
+    }
+
+    #[tokio::test]
+    async fn pages_can_be_resumed_from_any_item() -> Result<()> {
+        let items: Vec<u32> = (0..7).collect();
+        let fetch_page = |request| ready(Ok(paginate(&items, request)));
+        let interval = Backoff::Fixed(std::time::Duration::ZERO);
+
+        let first_five: Vec<_> =
+            poll_pages(fetch_page, StreamCursor::default(), 3, interval.clone())
+                .take(5)
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .collect::<Result<_>>()?;
+        let (_, cursor) = first_five.last().unwrap().clone();
+
+        let rest: Vec<_> = poll_pages(fetch_page, cursor, 3, interval)
+            .take(2)
+            .map(|item| item.map(|(item, _)| item))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_>>()?;
+
+        let first_five: Vec<_> = first_five.into_iter().map(|(item, _)| item).collect();
+        assert_eq!(first_five, vec![0, 1, 2, 3, 4]);
+        assert_eq!(rest, vec![5, 6]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn new_items_are_picked_up_when_polling() -> Result<()> {
+        let items = std::sync::Mutex::new(vec![0, 1]);
+        let fetch_page = |request| {
+            let mut items = items.lock().unwrap();
+            let page = paginate(&items, request);
+            // every poll makes a new item available
+            let next = items.len() as u32;
+            items.push(next);
+
+            ready(Ok(page))
+        };
+        let interval = Backoff::Fixed(std::time::Duration::ZERO);
+
+        let emitted: Vec<_> = poll_pages(fetch_page, StreamCursor::default(), 2, interval)
+            .take(5)
+            .map(|item| item.map(|(item, _)| item))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_>>()?;
+
+        assert_eq!(emitted, vec![0, 1, 2, 3, 4]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stream_ends_after_error() {
+        let statuses = stream::iter([
+            Err(error!(Provider, "connection closed")),
+            Ok(TxStatus::Submitted),
+        ]);
+
+        let statuses: Vec<_> = until_final_status(statuses).collect().await;
+
+        assert!(matches!(statuses.as_slice(), [Err(_)]));
+    }
+
+    #[tokio::test]
+    async fn items_end_after_first_error() {
+        let items = stream::iter([Ok(1), Err(error!(Provider, "connection closed")), Ok(2)]);
+
+        let items: Vec<_> = until_first_error(items).collect().await;
+
+        assert!(matches!(items.as_slice(), [Ok(1), Err(_)]));
+    }
+}
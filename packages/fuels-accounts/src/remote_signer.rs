@@ -0,0 +1,410 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        errors::{error, Result},
+        input::Input,
+        transaction_builders::TransactionBuilder,
+        AssetId,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{accounts_utils::try_provider_error, provider::Provider, Account, ViewOnlyAccount};
+
+/// The JSON-RPC method used to request a signature from a signing service.
+pub const SIGN_METHOD: &str = "fuel_sign";
+
+const JSON_RPC_VERSION: &str = "2.0";
+
+/// A JSON-RPC request asking the signing service to sign `params.message` with the key of
+/// `params.address`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignRequest {
+    pub jsonrpc: String,
+    pub id: u64,
+    pub method: String,
+    pub params: SignParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignParams {
+    /// The bech32 address of the key that should sign the message.
+    pub address: String,
+    /// The hex encoded 32 byte message hash.
+    pub message: String,
+}
+
+/// The JSON-RPC response of a signing service. On success, `result` holds the hex encoded
+/// 64 byte signature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignResponse {
+    pub jsonrpc: String,
+    pub id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl SignRequest {
+    pub fn new(id: u64, address: &Bech32Address, message: &Message) -> Self {
+        Self {
+            jsonrpc: JSON_RPC_VERSION.to_string(),
+            id,
+            method: SIGN_METHOD.to_string(),
+            params: SignParams {
+                address: address.to_string(),
+                message: format!("0x{}", hex::encode(**message)),
+            },
+        }
+    }
+}
+
+impl SignResponse {
+    pub fn success(id: u64, signature: &Signature) -> Self {
+        Self {
+            jsonrpc: JSON_RPC_VERSION.to_string(),
+            id,
+            result: Some(format!("0x{}", hex::encode(**signature))),
+            error: None,
+        }
+    }
+
+    pub fn error(id: u64, code: i64, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: JSON_RPC_VERSION.to_string(),
+            id,
+            result: None,
+            error: Some(RpcError {
+                code,
+                message: message.into(),
+            }),
+        }
+    }
+}
+
+/// Carries sign requests to a signing service and returns its responses.
+///
+/// Implement this trait to reach a signing service over a channel not provided by the SDK.
+#[async_trait]
+pub trait SignerTransport: Send + Sync + 'static {
+    async fn send(&self, request: SignRequest) -> Result<SignResponse>;
+}
+
+/// Sends sign requests as JSON-RPC over HTTP `POST`s to `url`.
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl HttpTransport {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl SignerTransport for HttpTransport {
+    async fn send(&self, request: SignRequest) -> Result<SignResponse> {
+        let response = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&request)?)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| error!(Other, "failed to reach signing service: {err}"))?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|err| error!(Other, "failed to read signing service response: {err}"))?;
+
+        Ok(serde_json::from_slice(&body)?)
+    }
+}
+
+/// Sends sign requests as newline delimited JSON-RPC over a Unix domain socket.
+#[cfg(unix)]
+#[derive(Debug, Clone)]
+pub struct UnixSocketTransport {
+    path: std::path::PathBuf,
+}
+
+#[cfg(unix)]
+impl UnixSocketTransport {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(unix)]
+#[async_trait]
+impl SignerTransport for UnixSocketTransport {
+    async fn send(&self, request: SignRequest) -> Result<SignResponse> {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+        let mut stream = tokio::net::UnixStream::connect(&self.path).await?;
+
+        let mut line = serde_json::to_vec(&request)?;
+        line.push(b'\n');
+        stream.write_all(&line).await?;
+
+        let mut response = String::new();
+        BufReader::new(stream).read_line(&mut response).await?;
+
+        Ok(serde_json::from_str(&response)?)
+    }
+}
+
+/// An account whose private key is held by a separate signing service, e.g. a hardware wallet
+/// or a hosted custody solution.
+///
+/// Signing is delegated to the service through a [`SignerTransport`]. Every signature received is
+/// checked to have been made by the key of the signer's address.
+pub struct RemoteSigner<T> {
+    address: Bech32Address,
+    transport: Arc<T>,
+    provider: Option<Provider>,
+    next_request_id: Arc<AtomicU64>,
+}
+
+impl<T: SignerTransport> RemoteSigner<T> {
+    pub fn new(address: Bech32Address, transport: T, provider: Option<Provider>) -> Self {
+        Self {
+            address,
+            transport: Arc::new(transport),
+            provider,
+            next_request_id: Default::default(),
+        }
+    }
+
+    pub fn provider(&self) -> Option<&Provider> {
+        self.provider.as_ref()
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.provider = Some(provider);
+    }
+
+    pub fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+
+    fn signature_from(&self, response: SignResponse, request_id: u64) -> Result<Signature> {
+        if response.id != request_id {
+            return Err(error!(
+                Other,
+                "signing service answered request `{}` instead of `{request_id}`", response.id
+            ));
+        }
+
+        if let Some(RpcError { code, message }) = response.error {
+            return Err(error!(
+                Other,
+                "signing service returned error `{code}`: {message}"
+            ));
+        }
+
+        let result = response
+            .result
+            .ok_or_else(|| error!(Other, "signing service returned no signature"))?;
+        let bytes = hex::decode(result.strip_prefix("0x").unwrap_or(&result))
+            .map_err(|err| error!(Other, "signing service returned invalid hex: {err}"))?;
+
+        <[u8; Signature::LEN]>::try_from(bytes.as_slice())
+            .map(Signature::from_bytes)
+            .map_err(|_| {
+                error!(
+                    Other,
+                    "signing service returned {} bytes instead of a 64 byte signature",
+                    bytes.len()
+                )
+            })
+    }
+
+    fn verify(&self, signature: &Signature, message: &Message) -> Result<()> {
+        let public_key = signature.recover(message)?;
+        let signed_by = Bech32Address::new(FUEL_BECH32_HRP, public_key.hash());
+
+        if signed_by != self.address {
+            return Err(error!(
+                Other,
+                "signing service signed with the key of `{signed_by}` instead of `{}`",
+                self.address
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Clone for RemoteSigner<T> {
+    fn clone(&self) -> Self {
+        Self {
+            address: self.address.clone(),
+            transport: self.transport.clone(),
+            provider: self.provider.clone(),
+            next_request_id: self.next_request_id.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for RemoteSigner<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+impl<T: SignerTransport> ViewOnlyAccount for RemoteSigner<T> {
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.provider.as_ref().ok_or_else(try_provider_error)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<T: SignerTransport> Account for RemoteSigner<T> {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        Ok(self
+            .get_spendable_resources(asset_id, amount)
+            .await?
+            .into_iter()
+            .map(Input::resource_signed)
+            .collect::<Vec<Input>>())
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.add_signer(self.clone())?;
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<T: SignerTransport> Signer for RemoteSigner<T> {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let request = SignRequest::new(request_id, &self.address, &message);
+
+        let response = self.transport.send(request).await?;
+        let signature = self.signature_from(response, request_id)?;
+        self.verify(&signature, &message)?;
+
+        Ok(signature)
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::WalletUnlocked;
+
+    /// Answers sign requests in-process with the key of `wallet`.
+    struct InProcessTransport {
+        wallet: WalletUnlocked,
+    }
+
+    #[async_trait]
+    impl SignerTransport for InProcessTransport {
+        async fn send(&self, request: SignRequest) -> Result<SignResponse> {
+            let message = hex::decode(request.params.message.trim_start_matches("0x")).unwrap();
+            let message = Message::from_bytes(message.try_into().unwrap());
+            let signature = self.wallet.sign(message).await?;
+
+            Ok(SignResponse::success(request.id, &signature))
+        }
+    }
+
+    fn signer_for(
+        address: Bech32Address,
+        wallet: WalletUnlocked,
+    ) -> RemoteSigner<InProcessTransport> {
+        RemoteSigner::new(address, InProcessTransport { wallet }, None)
+    }
+
+    #[tokio::test]
+    async fn signatures_are_forwarded_from_the_service() -> Result<()> {
+        let wallet = WalletUnlocked::new_random(None);
+        let signer = signer_for(wallet.address().clone(), wallet.clone());
+        let message = Message::new("some message");
+
+        let signature = signer.sign(message).await?;
+
+        assert_eq!(signature, wallet.sign(message).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signatures_by_other_keys_are_rejected() {
+        let wallet = WalletUnlocked::new_random(None);
+        let other_wallet = WalletUnlocked::new_random(None);
+        let signer = signer_for(other_wallet.address().clone(), wallet.clone());
+
+        let error = signer
+            .sign(Message::new("some message"))
+            .await
+            .expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "signing service signed with the key of `{}` instead of `{}`",
+                wallet.address(),
+                other_wallet.address()
+            )
+        );
+    }
+
+    #[test]
+    fn service_errors_are_reported() {
+        let wallet = WalletUnlocked::new_random(None);
+        let signer = signer_for(wallet.address().clone(), wallet);
+
+        let error = signer
+            .signature_from(SignResponse::error(0, -32000, "key is locked"), 0)
+            .expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            "signing service returned error `-32000`: key is locked"
+        );
+    }
+}
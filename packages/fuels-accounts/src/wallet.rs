@@ -14,9 +14,12 @@ use fuels_core::{
     },
 };
 use rand::{CryptoRng, Rng};
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
-use crate::{accounts_utils::try_provider_error, provider::Provider, Account, ViewOnlyAccount};
+use crate::{
+    accounts_utils::try_provider_error, hd_wallet::MnemonicLanguage, provider::Provider, Account,
+    ViewOnlyAccount,
+};
 
 pub const DEFAULT_DERIVATION_PATH_PREFIX: &str = "m/44'/1179993420'";
 
@@ -177,8 +180,9 @@ impl WalletUnlocked {
         P: AsRef<Path>,
         S: AsRef<[u8]>,
     {
-        let secret =
-            eth_keystore::decrypt_key(keypath, password).map_err(|e| error!(Other, "{e}"))?;
+        let secret = eth_keystore::decrypt_key(keypath, password)
+            .map(Zeroizing::new)
+            .map_err(|e| error!(Other, "{e}"))?;
         let secret_key = SecretKey::try_from(secret.as_slice())
             .expect("Decrypted key should have a correct size");
         Ok(Self::new_from_private_key(secret_key, provider))
@@ -260,6 +264,16 @@ pub fn generate_mnemonic_phrase<R: Rng>(rng: &mut R, count: usize) -> Result<Str
     Ok(fuel_crypto::generate_mnemonic_phrase(rng, count)?)
 }
 
+/// Like [`generate_mnemonic_phrase`], but with the words taken from the BIP39 wordlist of
+/// `language`.
+pub fn generate_mnemonic_phrase_with_language<R: Rng>(
+    rng: &mut R,
+    count: usize,
+    language: MnemonicLanguage,
+) -> Result<String> {
+    language.generate_phrase(rng, count)
+}
+
 #[cfg(test)]
 mod tests {
     use tempfile::tempdir;
@@ -1,4 +1,4 @@
-use fuel_abi_types::abi::full_program::{FullABIFunction, FullProgramABI};
+use fuel_abi_types::abi::full_program::{FullABIFunction, FullProgramABI, FullTypeDeclaration};
 use itertools::Itertools;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, TokenStreamExt};
@@ -9,7 +9,7 @@ use crate::{
         abigen::{
             bindings::function_generator::FunctionGenerator,
             configurables::generate_code_for_configurable_constants,
-            logs::log_formatters_instantiation_code,
+            logs::{log_formatters_instantiation_code, revert_error_enum_code},
         },
         generated_code::GeneratedCode,
     },
@@ -30,7 +30,10 @@ pub(crate) fn contract_bindings(
 
     let methods_name = ident(&format!("{name}Methods"));
 
-    let contract_functions = expand_functions(&abi.functions)?;
+    let error_enum_name = revert_error_enum_name(name, &abi.types);
+    let error_enum_code = revert_error_enum_code(&error_enum_name, &abi.logged_types);
+
+    let contract_functions = expand_functions(&abi.functions, &error_enum_name)?;
 
     let configuration_struct_name = ident(&format!("{name}Configurables"));
     let constant_configuration_code =
@@ -89,6 +92,22 @@ pub(crate) fn contract_bindings(
                                   .map_err(::std::convert::Into::into)
             }
 
+            pub fn logs(&self) -> ::fuels::programs::contract::ContractLogs<T> {
+                ::fuels::programs::contract::ContractLogs::new(
+                    self.contract_id.clone(),
+                    self.account.clone(),
+                    self.log_decoder.clone(),
+                )
+            }
+
+            pub fn events(&self) -> ::fuels::programs::contract::ContractEvents<T> {
+                ::fuels::programs::contract::ContractEvents::new(
+                    self.contract_id.clone(),
+                    self.account.clone(),
+                    self.log_decoder.clone(),
+                )
+            }
+
             pub fn methods(&self) -> #methods_name<T> {
                 #methods_name {
                     contract_id: self.contract_id.clone(),
@@ -124,21 +143,46 @@ pub(crate) fn contract_bindings(
         }
 
         #constant_configuration_code
+
+        #error_enum_code
     };
 
     // All publicly available types generated above should be listed here.
-    let type_paths = [name, &methods_name, &configuration_struct_name]
-        .map(|type_name| TypePath::new(type_name).expect("We know the given types are not empty"))
-        .into_iter()
-        .collect();
+    let type_paths = [
+        name,
+        &methods_name,
+        &configuration_struct_name,
+        &error_enum_name,
+    ]
+    .map(|type_name| TypePath::new(type_name).expect("We know the given types are not empty"))
+    .into_iter()
+    .collect();
 
     Ok(GeneratedCode::new(code, type_paths, no_std))
 }
 
-fn expand_functions(functions: &[FullABIFunction]) -> Result<TokenStream> {
+/// Names the error enum `{name}RevertError`, adding a number if the contract already has a type
+/// with that name.
+fn revert_error_enum_name(name: &Ident, types: &[FullTypeDeclaration]) -> Ident {
+    let type_names = types
+        .iter()
+        .filter_map(|ttype| ttype.custom_type_path().ok())
+        .filter_map(|type_path| type_path.ident().map(ToString::to_string))
+        .collect::<Vec<_>>();
+
+    let base_name = format!("{name}RevertError");
+    let enum_name = std::iter::once(base_name.clone())
+        .chain((2..).map(|suffix| format!("{base_name}{suffix}")))
+        .find(|enum_name| !type_names.contains(enum_name))
+        .expect("there are fewer types than names to choose from");
+
+    ident(&enum_name)
+}
+
+fn expand_functions(functions: &[FullABIFunction], error_enum_name: &Ident) -> Result<TokenStream> {
     functions
         .iter()
-        .map(expand_fn)
+        .map(|abi_fun| expand_fn(abi_fun, error_enum_name))
         .fold_ok(TokenStream::default(), |mut all_code, code| {
             all_code.append_all(code);
             all_code
@@ -151,7 +195,7 @@ fn expand_functions(functions: &[FullABIFunction]) -> Result<TokenStream> {
 ///
 /// The generated function prepares the necessary data and proceeds to call
 /// [::fuels_contract::contract::method_hash] for the actual call.
-pub(crate) fn expand_fn(abi_fun: &FullABIFunction) -> Result<TokenStream> {
+pub(crate) fn expand_fn(abi_fun: &FullABIFunction, error_enum_name: &Ident) -> Result<TokenStream> {
     let mut generator = FunctionGenerator::new(abi_fun)?;
 
     generator.set_doc(format!(
@@ -161,7 +205,7 @@ pub(crate) fn expand_fn(abi_fun: &FullABIFunction) -> Result<TokenStream> {
 
     let original_output = generator.output_type();
     generator.set_output_type(
-        quote! {::fuels::programs::contract::ContractCallHandler<T, #original_output> },
+        quote! {::fuels::programs::contract::ContractCallHandler<T, #original_output, #error_enum_name> },
     );
 
     let fn_selector = generator.fn_selector();
@@ -188,13 +232,17 @@ mod tests {
     use std::collections::HashMap;
 
     use fuel_abi_types::abi::{
-        full_program::FullABIFunction,
+        full_program::{FullABIFunction, FullTypeDeclaration},
         program::{ABIFunction, ProgramABI, TypeApplication, TypeDeclaration},
     };
     use pretty_assertions::assert_eq;
     use quote::quote;
 
-    use crate::{error::Result, program_bindings::abigen::bindings::contract::expand_fn};
+    use crate::{
+        error::Result,
+        program_bindings::abigen::bindings::contract::{expand_fn, revert_error_enum_name},
+        utils::ident,
+    };
 
     #[test]
     fn test_expand_fn_simple_abi() -> Result<()> {
@@ -345,10 +393,10 @@ mod tests {
             .collect::<HashMap<usize, TypeDeclaration>>();
 
         // Grabbing the one and only function in it.
-        let result = expand_fn(&FullABIFunction::from_counterpart(
-            &parsed_abi.functions[0],
-            &types,
-        )?)?;
+        let result = expand_fn(
+            &FullABIFunction::from_counterpart(&parsed_abi.functions[0], &types)?,
+            &ident("MyContractRevertError"),
+        )?;
 
         let expected = quote! {
             #[doc = "Calls the contract's `some_abi_funct` function"]
@@ -356,16 +404,17 @@ mod tests {
                 &self,
                 s_1: self::MyStruct1,
                 s_2: self::MyStruct2
-            ) -> ::fuels::programs::contract::ContractCallHandler<T, self::MyStruct1> {
+            ) -> ::fuels::programs::contract::ContractCallHandler<T, self::MyStruct1, MyContractRevertError> {
                 ::fuels::programs::contract::method_hash(
                     self.contract_id.clone(),
                     self.account.clone(),
-                    ::fuels::core::codec::resolve_fn_selector(
+                    ::fuels::core::codec::encode_fn_selector(
                         "some_abi_funct",
                         &[
                             <self::MyStruct1 as ::fuels::core::traits::Parameterize>::param_type(),
                             <self::MyStruct2 as ::fuels::core::traits::Parameterize>::param_type()
-                        ]
+                        ],
+                        self.encoder_config.encoding,
                     ),
                     &[
                         ::fuels::core::traits::Tokenizable::into_token(s_1),
@@ -414,17 +463,21 @@ mod tests {
         ]
         .into_iter()
         .collect::<HashMap<_, _>>();
-        let result = expand_fn(&FullABIFunction::from_counterpart(&the_function, &types)?);
+        let result = expand_fn(
+            &FullABIFunction::from_counterpart(&the_function, &types)?,
+            &ident("MyContractRevertError"),
+        );
 
         let expected = quote! {
             #[doc = "Calls the contract's `HelloWorld` function"]
-            pub fn HelloWorld(&self, bimbam: ::core::primitive::bool) -> ::fuels::programs::contract::ContractCallHandler<T, ()> {
+            pub fn HelloWorld(&self, bimbam: ::core::primitive::bool) -> ::fuels::programs::contract::ContractCallHandler<T, (), MyContractRevertError> {
                 ::fuels::programs::contract::method_hash(
                     self.contract_id.clone(),
                     self.account.clone(),
-                    ::fuels::core::codec::resolve_fn_selector(
+                    ::fuels::core::codec::encode_fn_selector(
                         "HelloWorld",
-                        &[<::core::primitive::bool as ::fuels::core::traits::Parameterize>::param_type()]
+                        &[<::core::primitive::bool as ::fuels::core::traits::Parameterize>::param_type()],
+                        self.encoder_config.encoding,
                     ),
                     &[::fuels::core::traits::Tokenizable::into_token(bimbam)],
                     self.log_decoder.clone(),
@@ -518,7 +571,10 @@ mod tests {
         .collect::<HashMap<_, _>>();
 
         // when
-        let result = expand_fn(&FullABIFunction::from_counterpart(&the_function, &types)?);
+        let result = expand_fn(
+            &FullABIFunction::from_counterpart(&the_function, &types)?,
+            &ident("MyContractRevertError"),
+        );
 
         //then
 
@@ -528,13 +584,14 @@ mod tests {
             pub fn hello_world(
                 &self,
                 the_only_allowed_input: self::SomeWeirdFrenchCuisine
-            ) -> ::fuels::programs::contract::ContractCallHandler<T, self::EntropyCirclesEnum> {
+            ) -> ::fuels::programs::contract::ContractCallHandler<T, self::EntropyCirclesEnum, MyContractRevertError> {
                 ::fuels::programs::contract::method_hash(
                     self.contract_id.clone(),
                     self.account.clone(),
-                    ::fuels::core::codec::resolve_fn_selector(
+                    ::fuels::core::codec::encode_fn_selector(
                         "hello_world",
-                        &[<self::SomeWeirdFrenchCuisine as ::fuels::core::traits::Parameterize>::param_type()]
+                        &[<self::SomeWeirdFrenchCuisine as ::fuels::core::traits::Parameterize>::param_type()],
+                        self.encoder_config.encoding,
                     ),
                     &[::fuels::core::traits::Tokenizable::into_token(
                         the_only_allowed_input
@@ -550,4 +607,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn revert_error_enum_does_not_collide_with_contract_types() -> Result<()> {
+        let type_decl = |type_id, type_field: &str| {
+            FullTypeDeclaration::from_counterpart(
+                &TypeDeclaration {
+                    type_id,
+                    type_field: type_field.to_string(),
+                    ..Default::default()
+                },
+                &HashMap::new(),
+            )
+        };
+        let name = ident("MyContract");
+
+        assert_eq!(
+            revert_error_enum_name(&name, &[type_decl(0, "enum MyContractError")]),
+            "MyContractRevertError"
+        );
+
+        let types = [
+            type_decl(0, "enum MyContractRevertError"),
+            type_decl(1, "struct some_lib::MyContractRevertError2"),
+        ];
+        assert_eq!(
+            revert_error_enum_name(&name, &types),
+            "MyContractRevertError3"
+        );
+
+        Ok(())
+    }
 }
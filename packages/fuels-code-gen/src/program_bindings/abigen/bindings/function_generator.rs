@@ -59,7 +59,13 @@ impl FunctionGenerator {
         let param_type_calls = self.args.param_type_calls();
 
         let name = &self.name;
-        quote! {::fuels::core::codec::resolve_fn_selector(#name, &[#(#param_type_calls),*])}
+        quote! {
+            ::fuels::core::codec::encode_fn_selector(
+                #name,
+                &[#(#param_type_calls),*],
+                self.encoder_config.encoding,
+            )
+        }
     }
 
     pub fn tokenized_args(&self) -> TokenStream {
@@ -129,9 +135,11 @@ mod tests {
         let fn_selector_code = sut.fn_selector();
 
         let expected = quote! {
-            ::fuels::core::codec::resolve_fn_selector(
+            ::fuels::core::codec::encode_fn_selector(
                 "test_function",
-                &[<self::CustomStruct<::core::primitive::u8> as::fuels::core::traits::Parameterize>::param_type()])
+                &[<self::CustomStruct<::core::primitive::u8> as::fuels::core::traits::Parameterize>::param_type()],
+                self.encoder_config.encoding,
+            )
         };
         assert_eq!(fn_selector_code.to_string(), expected.to_string());
 
@@ -109,15 +109,15 @@ fn expand_fn(abi: &FullProgramABI) -> Result<TokenStream> {
 
     let arg_tokens = generator.tokenized_args();
     let body = quote! {
-            let encoded_args = ::fuels::core::codec::ABIEncoder::new(self.encoder_config).encode(&#arg_tokens);
             let provider = ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account).expect("Provider not set up")
                 .clone();
             ::fuels::programs::script_calls::ScriptCallHandler::new(
                 self.binary.clone(),
-                encoded_args,
+                &#arg_tokens,
                 self.account.clone(),
                 provider,
-                self.log_decoder.clone()
+                self.log_decoder.clone(),
+                self.encoder_config
             )
     };
 
@@ -1,8 +1,14 @@
+use std::collections::HashMap;
+
 use fuel_abi_types::abi::full_program::FullLoggedType;
-use proc_macro2::TokenStream;
-use quote::quote;
+use inflector::Inflector;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
 
-use crate::program_bindings::resolved_type::TypeResolver;
+use crate::{
+    program_bindings::resolved_type::{GenericType, ResolvedType, TypeResolver},
+    utils::ident,
+};
 
 pub(crate) fn log_formatters_instantiation_code(
     contract_id: TokenStream,
@@ -51,3 +57,208 @@ fn generate_log_id_log_formatter_pairs(resolved_logs: &[ResolvedLog]) -> Vec<Tok
         })
         .collect()
 }
+
+/// Generates an enum with a variant for every type logged by the contract, so that the error a
+/// contract reverts with through `require` can be matched on.
+pub(crate) fn revert_error_enum_code(
+    enum_name: &Ident,
+    logged_types: &[FullLoggedType],
+) -> TokenStream {
+    let mut variants: Vec<(Ident, ResolvedType)> = vec![];
+    let mut variant_names_taken: HashMap<String, usize> = HashMap::new();
+    let mut log_id_variants = vec![];
+
+    for logged_type in logged_types {
+        let resolved_type = TypeResolver::default()
+            .resolve(&logged_type.application)
+            .expect("Failed to resolve log type");
+
+        let already_generated = variants.iter().find(|(_, existing)| {
+            existing.to_token_stream().to_string() == resolved_type.to_token_stream().to_string()
+        });
+        let variant_name = match already_generated {
+            Some((variant_name, _)) => variant_name.clone(),
+            None => {
+                let name = variant_name(&resolved_type);
+                // different types, e.g. from different modules, can end up with the same name
+                let times_taken = variant_names_taken.entry(name.clone()).or_default();
+                *times_taken += 1;
+                let variant_name = match *times_taken {
+                    1 => ident(&name),
+                    n => ident(&format!("{name}{n}")),
+                };
+
+                variants.push((variant_name.clone(), resolved_type.clone()));
+                variant_name
+            }
+        };
+
+        log_id_variants.push((logged_type.log_id, variant_name, resolved_type));
+    }
+
+    let (variant_names, variant_types): (Vec<_>, Vec<_>) = variants.into_iter().unzip();
+    let decode_arms = log_id_variants
+        .iter()
+        .map(|(log_id, variant_name, resolved_type)| {
+            quote! {
+                #log_id => ::core::option::Option::Some(
+                    ::fuels::core::codec::try_from_bytes::<#resolved_type>(data, decoder_config)
+                        .map(Self::#variant_name)
+                ),
+            }
+        });
+
+    quote! {
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum #enum_name {
+            #(#variant_names(#variant_types),)*
+        }
+
+        impl ::fuels::programs::contract::RevertError for #enum_name {
+            fn decode_revert(
+                log_id: u64,
+                data: &[u8],
+                decoder_config: ::fuels::core::codec::DecoderConfig,
+            ) -> ::core::option::Option<::fuels::types::errors::Result<Self>> {
+                match log_id {
+                    #(#decode_arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    }
+}
+
+fn variant_name(resolved_type: &ResolvedType) -> String {
+    match resolved_type {
+        ResolvedType::Unit => "Unit".to_string(),
+        ResolvedType::Primitive(path) => path
+            .ident()
+            .expect("primitive types have a name")
+            .to_string()
+            .to_pascal_case(),
+        ResolvedType::StructOrEnum { path, generics } => {
+            let name = path.ident().expect("custom types have a name").to_string();
+            let generics: String = generics.iter().map(variant_name).collect();
+
+            format!("{name}{generics}")
+        }
+        ResolvedType::Array(element, len) => format!("{}Array{len}", variant_name(element)),
+        ResolvedType::Tuple(elements) => {
+            let elements: String = elements.iter().map(variant_name).collect();
+
+            format!("Tuple{elements}")
+        }
+        ResolvedType::Generic(GenericType::Named(name)) => name.to_string(),
+        ResolvedType::Generic(GenericType::Constant(constant)) => constant.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_abi_types::abi::full_program::FullProgramABI;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::error::Result;
+
+    #[test]
+    fn revert_error_enum_has_a_variant_per_logged_type() -> Result<()> {
+        let abi = r#"
+            {
+                "types": [
+                  {
+                    "typeId": 0,
+                    "type": "u64",
+                    "components": null,
+                    "typeParameters": null
+                  },
+                  {
+                    "typeId": 1,
+                    "type": "enum AccessError",
+                    "components": [
+                      {
+                        "name": "NotOwner",
+                        "type": 2,
+                        "typeArguments": null
+                      }
+                    ],
+                    "typeParameters": null
+                  },
+                  {
+                    "typeId": 2,
+                    "type": "()",
+                    "components": [],
+                    "typeParameters": null
+                  }
+                ],
+                "functions": [],
+                "loggedTypes": [
+                  {
+                    "logId": 0,
+                    "loggedType": {
+                      "name": "",
+                      "type": 1,
+                      "typeArguments": []
+                    }
+                  },
+                  {
+                    "logId": 1,
+                    "loggedType": {
+                      "name": "",
+                      "type": 0,
+                      "typeArguments": null
+                    }
+                  },
+                  {
+                    "logId": 2,
+                    "loggedType": {
+                      "name": "",
+                      "type": 1,
+                      "typeArguments": []
+                    }
+                  }
+                ]
+              }
+        "#;
+        let abi = FullProgramABI::from_json_abi(abi)?;
+
+        let code = revert_error_enum_code(&ident("MyContractError"), &abi.logged_types);
+
+        let expected = quote! {
+            #[derive(Clone, Debug, PartialEq)]
+            pub enum MyContractError {
+                AccessError(self::AccessError),
+                U64(::core::primitive::u64),
+            }
+
+            impl ::fuels::programs::contract::RevertError for MyContractError {
+                fn decode_revert(
+                    log_id: u64,
+                    data: &[u8],
+                    decoder_config: ::fuels::core::codec::DecoderConfig,
+                ) -> ::core::option::Option<::fuels::types::errors::Result<Self>> {
+                    match log_id {
+                        0u64 => ::core::option::Option::Some(
+                            ::fuels::core::codec::try_from_bytes::<self::AccessError>(data, decoder_config)
+                                .map(Self::AccessError)
+                        ),
+                        1u64 => ::core::option::Option::Some(
+                            ::fuels::core::codec::try_from_bytes::<::core::primitive::u64>(data, decoder_config)
+                                .map(Self::U64)
+                        ),
+                        2u64 => ::core::option::Option::Some(
+                            ::fuels::core::codec::try_from_bytes::<self::AccessError>(data, decoder_config)
+                                .map(Self::AccessError)
+                        ),
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected.to_string());
+
+        Ok(())
+    }
+}
@@ -1,12 +1,14 @@
 mod abi_decoder;
 mod abi_encoder;
 mod function_selector;
+mod json;
 mod logs;
 mod utils;
 
 pub use abi_decoder::*;
 pub use abi_encoder::*;
 pub use function_selector::*;
+pub use json::*;
 pub use logs::*;
 
 use crate::{
@@ -14,6 +16,21 @@ use crate::{
     types::errors::Result,
 };
 
+/// The encoding used by the `ABIEncoder` and the `ABIDecoder`. It has to match the encoding the
+/// called program was compiled with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Encoding {
+    /// Word aligned encoding, where heap types are placed after the rest of the data and
+    /// referenced through pointers.
+    #[cfg_attr(not(experimental), default)]
+    Legacy,
+    /// The experimental (v1) encoding, where every value is encoded in place without padding and
+    /// heap types are prefixed with their length. Used by programs compiled with
+    /// `--experimental-new-encoding`.
+    #[cfg_attr(experimental, default)]
+    Experimental,
+}
+
 /// Decodes `bytes` into type `T` following the schema defined by T's `Parameterize` impl
 pub fn try_from_bytes<T>(bytes: &[u8], decoder_config: DecoderConfig) -> Result<T>
 where
@@ -1,11 +1,14 @@
 mod bounded_decoder;
-#[cfg(experimental)]
 mod experimental_bounded_decoder;
 
-#[cfg(experimental)]
-use crate::codec::abi_decoder::experimental_bounded_decoder::ExperimentalBoundedDecoder;
 use crate::{
-    codec::abi_decoder::bounded_decoder::BoundedDecoder,
+    codec::{
+        abi_decoder::{
+            bounded_decoder::BoundedDecoder,
+            experimental_bounded_decoder::ExperimentalBoundedDecoder,
+        },
+        Encoding,
+    },
     types::{errors::Result, param_types::ParamType, Token},
 };
 
@@ -17,6 +20,8 @@ pub struct DecoderConfig {
     /// Every decoded Token will increase the token count. Decoding will fail if the current
     /// token count becomes greater than `max_tokens` configured here.
     pub max_tokens: usize,
+    /// The encoding of the decoded bytes.
+    pub encoding: Encoding,
 }
 
 // ANCHOR: default_decoder_config
@@ -25,6 +30,7 @@ impl Default for DecoderConfig {
         Self {
             max_depth: 45,
             max_tokens: 10_000,
+            encoding: Encoding::default(),
         }
     }
 }
@@ -40,7 +46,8 @@ impl ABIDecoder {
         Self { config }
     }
 
-    /// Decodes `bytes` following the schema described in `param_type` into its respective `Token`.
+    /// Decodes `bytes` following the schema described in `param_type` into its respective `Token`,
+    /// using the configured `Encoding`.
     ///
     /// # Arguments
     ///
@@ -61,7 +68,12 @@ impl ABIDecoder {
     /// assert_eq!(u64::from_token(token).unwrap(), 7u64);
     /// ```
     pub fn decode(&self, param_type: &ParamType, bytes: &[u8]) -> Result<Token> {
-        BoundedDecoder::new(self.config).decode(param_type, bytes)
+        match self.config.encoding {
+            Encoding::Legacy => BoundedDecoder::new(self.config).decode(param_type, bytes),
+            Encoding::Experimental => {
+                ExperimentalBoundedDecoder::new(self.config).decode(param_type, bytes)
+            }
+        }
     }
 
     /// Same as `decode` but decodes multiple `ParamType`s in one go.
@@ -79,21 +91,14 @@ impl ABIDecoder {
     /// assert_eq!(tokens, vec![Token::U8(7), Token::U8(8)]);
     /// ```
     pub fn decode_multiple(&self, param_types: &[ParamType], bytes: &[u8]) -> Result<Vec<Token>> {
-        BoundedDecoder::new(self.config).decode_multiple(param_types, bytes)
-    }
-
-    #[cfg(experimental)]
-    pub fn experimental_decode(&self, param_type: &ParamType, bytes: &[u8]) -> Result<Token> {
-        ExperimentalBoundedDecoder::new(self.config).decode(param_type, bytes)
-    }
-
-    #[cfg(experimental)]
-    pub fn experimental_decode_multiple(
-        &self,
-        param_types: &[ParamType],
-        bytes: &[u8],
-    ) -> Result<Vec<Token>> {
-        ExperimentalBoundedDecoder::new(self.config).decode_multiple(param_types, bytes)
+        match self.config.encoding {
+            Encoding::Legacy => {
+                BoundedDecoder::new(self.config).decode_multiple(param_types, bytes)
+            }
+            Encoding::Experimental => {
+                ExperimentalBoundedDecoder::new(self.config).decode_multiple(param_types, bytes)
+            }
+        }
     }
 }
 
@@ -683,6 +688,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decoding_follows_the_configured_encoding() -> Result<()> {
+        let param_types = [ParamType::Bool, ParamType::Vector(Box::new(ParamType::U8))];
+        let experimental = DecoderConfig {
+            encoding: Encoding::Experimental,
+            ..Default::default()
+        };
+        let data = [1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4];
+
+        let decoded = ABIDecoder::new(experimental).decode_multiple(&param_types, &data)?;
+
+        let expected = vec![
+            Token::Bool(true),
+            Token::Vector(vec![Token::U8(3), Token::U8(4)]),
+        ];
+        assert_eq!(decoded, expected);
+
+        let legacy = DecoderConfig {
+            encoding: Encoding::Legacy,
+            ..Default::default()
+        };
+        let decoded = ABIDecoder::new(legacy).decode_multiple(&param_types, &data);
+        assert_ne!(decoded.ok(), Some(expected));
+
+        Ok(())
+    }
+
     #[test]
     fn max_depth_surpassed() {
         const MAX_DEPTH: usize = 2;
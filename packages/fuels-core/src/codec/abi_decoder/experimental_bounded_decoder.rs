@@ -1,7 +1,10 @@
 use std::{iter::repeat, str};
 
 use crate::{
-    codec::DecoderConfig,
+    codec::{
+        utils::{CodecDirection, CounterWithLimit},
+        DecoderConfig,
+    },
     constants::WORD_SIZE,
     types::{
         enum_variants::EnumVariants,
@@ -30,8 +33,10 @@ const DISCRIMINANT_BYTES_SIZE: usize = WORD_SIZE;
 
 impl ExperimentalBoundedDecoder {
     pub(crate) fn new(config: DecoderConfig) -> Self {
-        let depth_tracker = CounterWithLimit::new(config.max_depth, "depth");
-        let token_tracker = CounterWithLimit::new(config.max_tokens, "token");
+        let depth_tracker =
+            CounterWithLimit::new(config.max_depth, "depth", CodecDirection::Decoding);
+        let token_tracker =
+            CounterWithLimit::new(config.max_tokens, "token", CodecDirection::Decoding);
         Self {
             depth_tracker,
             token_tracker,
@@ -295,40 +300,6 @@ struct Decoded {
     bytes_read: usize,
 }
 
-struct CounterWithLimit {
-    count: usize,
-    max: usize,
-    name: String,
-}
-
-impl CounterWithLimit {
-    fn new(max: usize, name: impl Into<String>) -> Self {
-        Self {
-            count: 0,
-            max,
-            name: name.into(),
-        }
-    }
-
-    fn increase(&mut self) -> Result<()> {
-        self.count += 1;
-        if self.count > self.max {
-            return Err(error!(
-                Codec,
-                "{} limit `{}` reached while decoding. Try increasing it", self.name, self.max
-            ));
-        }
-
-        Ok(())
-    }
-
-    fn decrease(&mut self) {
-        if self.count > 0 {
-            self.count -= 1;
-        }
-    }
-}
-
 fn peek_u8(bytes: &[u8]) -> Result<u8> {
     let slice = peek_fixed::<U8_BYTES_SIZE>(bytes)?;
     Ok(u8::from_be_bytes(*slice))
@@ -1,9 +1,20 @@
 mod bounded_encoder;
+mod experimental_bounded_encoder;
 use std::default::Default;
 
 use crate::{
-    codec::abi_encoder::bounded_encoder::BoundedEncoder,
-    types::{errors::Result, unresolved_bytes::UnresolvedBytes, Token},
+    codec::{
+        abi_encoder::{
+            bounded_encoder::BoundedEncoder,
+            experimental_bounded_encoder::ExperimentalBoundedEncoder,
+        },
+        Encoding,
+    },
+    types::{
+        errors::Result,
+        unresolved_bytes::{Data, UnresolvedBytes},
+        Token,
+    },
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -15,8 +26,11 @@ pub struct EncoderConfig {
     /// token count becomes greater than `max_tokens` configured here.
     pub max_tokens: usize,
     /// The total memory size of the top-level token must fit in the available memory of the
-    /// system.
+    /// system. Only checked by the `Legacy` encoding, as the `Experimental` encoding does not pad
+    /// enums to the width of their biggest variant.
     pub max_total_enum_width: usize,
+    /// The encoding of the produced bytes.
+    pub encoding: Encoding,
 }
 
 // ANCHOR: default_encoder_config
@@ -26,6 +40,7 @@ impl Default for EncoderConfig {
             max_depth: 45,
             max_tokens: 10_000,
             max_total_enum_width: 10_000,
+            encoding: Encoding::default(),
         }
     }
 }
@@ -43,8 +58,16 @@ impl ABIEncoder {
 
     /// Encodes `Token`s in `args` following the ABI specs defined
     /// [here](https://github.com/FuelLabs/fuel-specs/blob/master/specs/protocol/abi.md)
+    /// using the configured `Encoding`. The `Experimental` encoding contains no pointers, so its
+    /// bytes resolve to the same value at any address.
     pub fn encode(&self, args: &[Token]) -> Result<UnresolvedBytes> {
-        BoundedEncoder::new(self.config).encode(args)
+        match self.config.encoding {
+            Encoding::Legacy => BoundedEncoder::new(self.config).encode(args),
+            Encoding::Experimental => {
+                let bytes = ExperimentalBoundedEncoder::new(self.config).encode(args)?;
+                Ok(UnresolvedBytes::new(vec![Data::Inline(bytes)]))
+            }
+        }
     }
 }
 
@@ -1121,6 +1144,109 @@ mod tests {
             })
     }
 
+    fn experimental_encoder() -> ABIEncoder {
+        ABIEncoder::new(EncoderConfig {
+            encoding: Encoding::Experimental,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn experimental_encoding_has_no_padding() -> Result<()> {
+        let tokens = [
+            Token::Bool(true),
+            Token::U8(1),
+            Token::U16(2),
+            Token::U32(3),
+            Token::U64(4),
+            Token::Unit,
+            Token::StringArray(StaticStringToken::new("abc".to_string(), Some(3))),
+        ];
+
+        let encoded = experimental_encoder().encode(&tokens)?.resolve(0);
+
+        let expected = [
+            vec![1, 1],
+            vec![0, 2],
+            vec![0, 0, 0, 3],
+            vec![0, 0, 0, 0, 0, 0, 0, 4],
+            b"abc".to_vec(),
+        ]
+        .concat();
+        assert_eq!(encoded, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn experimental_encoding_prefixes_heap_types_with_their_length() -> Result<()> {
+        let tokens = [
+            Token::Vector(vec![Token::U8(1), Token::U8(2)]),
+            Token::Bytes(vec![3]),
+            Token::String("hi".to_string()),
+            Token::StringSlice(StaticStringToken::new("a".to_string(), None)),
+        ];
+
+        let encoded = experimental_encoder().encode(&tokens)?;
+
+        let expected = [
+            vec![0, 0, 0, 0, 0, 0, 0, 2, 1, 2],
+            vec![0, 0, 0, 0, 0, 0, 0, 1, 3],
+            vec![0, 0, 0, 0, 0, 0, 0, 2, b'h', b'i'],
+            vec![0, 0, 0, 0, 0, 0, 0, 1, b'a'],
+        ]
+        .concat();
+        assert_eq!(encoded.resolve(0), expected);
+        // there are no pointers to resolve
+        assert_eq!(encoded.resolve(1024), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn experimental_encoding_of_enums_is_discriminant_and_variant() -> Result<()> {
+        let variants = EnumVariants::new(vec![ParamType::U8, ParamType::B256])?;
+        let token = Token::Struct(vec![
+            Token::Enum(Box::new((0, Token::U8(7), variants))),
+            Token::Tuple(vec![Token::Bool(false), Token::U16(1)]),
+        ]);
+
+        let encoded = experimental_encoder().encode(&[token])?.resolve(0);
+
+        assert_eq!(encoded, [0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn experimental_encoding_respects_limits() {
+        let config = EncoderConfig {
+            max_depth: 2,
+            encoding: Encoding::Experimental,
+            ..Default::default()
+        };
+        let msg = "depth limit `2` reached while encoding. Try increasing it";
+
+        [nested_struct, nested_enum, nested_tuple, nested_array]
+            .iter()
+            .map(|fun| fun(3))
+            .for_each(|token| {
+                assert_encoding_failed(config, token, msg);
+            });
+
+        let config = EncoderConfig {
+            max_tokens: 2,
+            encoding: Encoding::Experimental,
+            ..Default::default()
+        };
+        let tokens = Token::Vector(vec![Token::U8(1), Token::U8(2)]);
+        assert_encoding_failed(
+            config,
+            tokens,
+            "token limit `2` reached while encoding. Try increasing it",
+        );
+    }
+
     fn assert_encoding_failed(config: EncoderConfig, token: Token, msg: &str) {
         let encoder = ABIEncoder::new(config);
 
@@ -0,0 +1,128 @@
+use crate::{
+    codec::{
+        utils::{CodecDirection, CounterWithLimit},
+        EncoderConfig,
+    },
+    types::{errors::Result, EnumSelector, StaticStringToken, Token, U256},
+};
+
+/// Encodes `Token`s following the experimental (v1) encoding. Every value is encoded in place,
+/// without padding, and heap types are prefixed with their length instead of being referenced
+/// through a pointer. Implements encoding limits to control resource usage.
+pub(crate) struct ExperimentalBoundedEncoder {
+    depth_tracker: CounterWithLimit,
+    token_tracker: CounterWithLimit,
+}
+
+impl ExperimentalBoundedEncoder {
+    pub(crate) fn new(config: EncoderConfig) -> Self {
+        let depth_tracker =
+            CounterWithLimit::new(config.max_depth, "depth", CodecDirection::Encoding);
+        let token_tracker =
+            CounterWithLimit::new(config.max_tokens, "token", CodecDirection::Encoding);
+        Self {
+            depth_tracker,
+            token_tracker,
+        }
+    }
+
+    pub(crate) fn encode(&mut self, args: &[Token]) -> Result<Vec<u8>> {
+        self.encode_tokens(args)
+    }
+
+    fn encode_tokens(&mut self, tokens: &[Token]) -> Result<Vec<u8>> {
+        let mut data = vec![];
+
+        for token in tokens {
+            data.append(&mut self.encode_token(token)?);
+        }
+
+        Ok(data)
+    }
+
+    fn run_w_depth_tracking(
+        &mut self,
+        encoder: impl FnOnce(&mut Self) -> Result<Vec<u8>>,
+    ) -> Result<Vec<u8>> {
+        self.depth_tracker.increase()?;
+
+        let res = encoder(self);
+
+        self.depth_tracker.decrease();
+        res
+    }
+
+    fn encode_token(&mut self, arg: &Token) -> Result<Vec<u8>> {
+        self.token_tracker.increase()?;
+
+        let encoded_token = match arg {
+            Token::Unit => vec![],
+            Token::Bool(arg_bool) => vec![u8::from(*arg_bool)],
+            Token::U8(arg_u8) => vec![*arg_u8],
+            Token::U16(arg_u16) => arg_u16.to_be_bytes().to_vec(),
+            Token::U32(arg_u32) => arg_u32.to_be_bytes().to_vec(),
+            Token::U64(arg_u64) => arg_u64.to_be_bytes().to_vec(),
+            Token::U128(arg_u128) => arg_u128.to_be_bytes().to_vec(),
+            Token::U256(arg_u256) => Self::encode_u256(*arg_u256),
+            Token::B256(arg_bits256) => arg_bits256.to_vec(),
+            Token::Bytes(data) => Self::encode_with_length(data),
+            Token::RawSlice(data) => Self::encode_with_length(data),
+            Token::String(string) => Self::encode_with_length(string.as_bytes()),
+            Token::StringSlice(arg_string) => Self::encode_string_slice(arg_string)?,
+            Token::StringArray(arg_string) => Self::encode_string_array(arg_string)?,
+            Token::Array(arg_array) => {
+                self.run_w_depth_tracking(|ctx| ctx.encode_tokens(arg_array))?
+            }
+            Token::Struct(arg_struct) => {
+                self.run_w_depth_tracking(|ctx| ctx.encode_tokens(arg_struct))?
+            }
+            Token::Tuple(arg_tuple) => {
+                self.run_w_depth_tracking(|ctx| ctx.encode_tokens(arg_tuple))?
+            }
+            Token::Enum(arg_enum) => self.run_w_depth_tracking(|ctx| ctx.encode_enum(arg_enum))?,
+            Token::Vector(data) => self.run_w_depth_tracking(|ctx| ctx.encode_vector(data))?,
+        };
+
+        Ok(encoded_token)
+    }
+
+    fn encode_u256(arg_u256: U256) -> Vec<u8> {
+        let mut bytes = [0u8; 32];
+        arg_u256.to_big_endian(&mut bytes);
+        bytes.to_vec()
+    }
+
+    fn encode_length(len: usize) -> [u8; 8] {
+        (len as u64).to_be_bytes()
+    }
+
+    fn encode_with_length(data: &[u8]) -> Vec<u8> {
+        [&Self::encode_length(data.len())[..], data].concat()
+    }
+
+    fn encode_string_slice(arg_string: &StaticStringToken) -> Result<Vec<u8>> {
+        let encodable_str = arg_string.get_encodable_str()?;
+
+        Ok(Self::encode_with_length(encodable_str.as_bytes()))
+    }
+
+    fn encode_string_array(arg_string: &StaticStringToken) -> Result<Vec<u8>> {
+        Ok(arg_string.get_encodable_str()?.as_bytes().to_vec())
+    }
+
+    fn encode_enum(&mut self, selector: &EnumSelector) -> Result<Vec<u8>> {
+        let (discriminant, token_within_enum, _) = selector;
+
+        let mut encoded_enum = discriminant.to_be_bytes().to_vec();
+        encoded_enum.append(&mut self.encode_token(token_within_enum)?);
+
+        Ok(encoded_enum)
+    }
+
+    fn encode_vector(&mut self, data: &[Token]) -> Result<Vec<u8>> {
+        let mut encoded_vector = Self::encode_length(data.len()).to_vec();
+        encoded_vector.append(&mut self.encode_tokens(data)?);
+
+        Ok(encoded_vector)
+    }
+}
@@ -1,6 +1,9 @@
 use sha2::{Digest, Sha256};
 
-use crate::types::{param_types::ParamType, ByteArray};
+use crate::{
+    codec::Encoding,
+    types::{param_types::ParamType, ByteArray},
+};
 
 /// Given a function name and its inputs  will return a ByteArray representing
 /// the function selector as specified in the Fuel specs.
@@ -10,6 +13,20 @@ pub fn resolve_fn_selector(name: &str, inputs: &[ParamType]) -> ByteArray {
     first_four_bytes_of_sha256_hash(&fn_signature)
 }
 
+/// Given a function name and its inputs will return the function selector a contract compiled
+/// with `encoding` expects. The `Legacy` selector is the one returned by [`resolve_fn_selector`],
+/// while the `Experimental` one is the function name prefixed with its length.
+pub fn encode_fn_selector(name: &str, inputs: &[ParamType], encoding: Encoding) -> Vec<u8> {
+    match encoding {
+        Encoding::Legacy => resolve_fn_selector(name, inputs).to_vec(),
+        Encoding::Experimental => {
+            let len = name.len() as u64;
+
+            [&len.to_be_bytes(), name.as_bytes()].concat()
+        }
+    }
+}
+
 fn resolve_fn_signature(name: &str, inputs: &[ParamType]) -> String {
     let fn_args = resolve_args(inputs);
 
@@ -121,6 +138,20 @@ mod tests {
     use super::*;
     use crate::types::enum_variants::EnumVariants;
 
+    #[test]
+    fn selector_depends_on_the_encoding() {
+        let inputs = [ParamType::U64];
+
+        let legacy = encode_fn_selector("some_fun", &inputs, Encoding::Legacy);
+        let experimental = encode_fn_selector("some_fun", &inputs, Encoding::Experimental);
+
+        assert_eq!(legacy, resolve_fn_selector("some_fun", &inputs));
+        assert_eq!(
+            experimental,
+            [&[0, 0, 0, 0, 0, 0, 0, 8], b"some_fun".as_slice()].concat()
+        );
+    }
+
     #[test]
     fn handles_primitive_types() {
         let check_selector_for_type = |primitive_type: ParamType, expected_selector: &str| {
@@ -0,0 +1,599 @@
+use serde_json::{Map, Value};
+
+use crate::types::{
+    enum_variants::EnumVariants,
+    errors::{error, Result},
+    param_types::{ParamType, TypeNames},
+    StaticStringToken, Token, U256,
+};
+
+static NO_NAMES: TypeNames = TypeNames {
+    name: String::new(),
+    type_field: String::new(),
+    components: Vec::new(),
+};
+
+/// Converts a JSON `value` into a [`Token`] of the given `param_type`.
+///
+/// The `names` of the type, as found in the JSON ABI, allow structs to be given as objects keyed
+/// by field name and enums as `{"Variant": value}`. Use `TypeNames::default()` if they are not
+/// known, in which case structs can only be given as arrays and enums as `{"<index>": value}`.
+///
+/// - `u128`, `u256` and other integers that don't fit a JSON number are decimal strings
+/// - `b256`, `Bytes` and `raw untyped slice` are hex strings, optionally prefixed with `0x`
+/// - tuples, arrays and vectors are JSON arrays and the unit type is `null`
+/// - enum variants holding a unit can also be given by name, e.g. `"Variant"`
+/// - an `Option` is either `null` or its value, a `Result` is `{"Ok": value}` or `{"Err": value}`
+/// - an `Option` of a unit or of another `Option` is `null` or `{"Some": value}`, since its value
+///   could be `null` itself
+pub fn json_to_token(value: &Value, param_type: &ParamType, names: &TypeNames) -> Result<Token> {
+    let token = match param_type {
+        ParamType::Unit => {
+            if !value.is_null() {
+                return Err(error!(Codec, "expected `null` for `()`, got `{value}`"));
+            }
+            Token::Unit
+        }
+        ParamType::Bool => Token::Bool(
+            value
+                .as_bool()
+                .ok_or_else(|| error!(Codec, "expected a boolean, got `{value}`"))?,
+        ),
+        ParamType::U8 => Token::U8(parse_number(value)?),
+        ParamType::U16 => Token::U16(parse_number(value)?),
+        ParamType::U32 => Token::U32(parse_number(value)?),
+        ParamType::U64 => Token::U64(parse_number(value)?),
+        ParamType::U128 => Token::U128(parse_number(value)?),
+        ParamType::U256 => {
+            let digits = number_as_string(value)?;
+            Token::U256(
+                U256::from_dec_str(&digits)
+                    .map_err(|err| error!(Codec, "invalid `u256` value `{digits}`: {err}"))?,
+            )
+        }
+        ParamType::B256 => Token::B256(
+            parse_hex(value)?
+                .try_into()
+                .map_err(|_| error!(Codec, "expected 32 bytes for `b256`, got `{value}`"))?,
+        ),
+        ParamType::Bytes => Token::Bytes(parse_hex(value)?),
+        ParamType::RawSlice => Token::RawSlice(parse_hex(value)?),
+        ParamType::String => Token::String(as_str(value)?.to_string()),
+        ParamType::StringSlice => {
+            Token::StringSlice(StaticStringToken::new(as_str(value)?.to_string(), None))
+        }
+        ParamType::StringArray(len) => {
+            let string = as_str(value)?;
+            if string.len() != *len {
+                return Err(error!(
+                    Codec,
+                    "expected a string of length {len}, got `{value}`"
+                ));
+            }
+            Token::StringArray(StaticStringToken::new(string.to_string(), Some(*len)))
+        }
+        ParamType::Tuple(param_types) => {
+            let values = as_array(value, Some(param_types.len()))?;
+            Token::Tuple(json_to_components(values, param_types, names)?)
+        }
+        ParamType::Struct { fields, .. } => {
+            Token::Struct(json_to_struct_fields(value, fields, names)?)
+        }
+        ParamType::Array(param_type, len) => {
+            let values = as_array(value, Some(*len))?;
+            Token::Array(json_to_elements(values, param_type, names)?)
+        }
+        ParamType::Vector(param_type) => {
+            let values = as_array(value, None)?;
+            Token::Vector(json_to_elements(values, param_type, names)?)
+        }
+        ParamType::Enum { variants, .. } => json_to_enum(value, variants, names)?,
+    };
+
+    Ok(token)
+}
+
+/// Converts a [`Token`] into JSON, the inverse of [`json_to_token`].
+///
+/// Structs are rendered as objects and enums as `{"Variant": value}` only if the `names` of the
+/// type are known. Otherwise, structs are rendered as arrays and enums as `{"<index>": value}`.
+pub fn token_to_json(token: &Token, names: &TypeNames) -> Result<Value> {
+    let value = match token {
+        Token::Unit => Value::Null,
+        Token::Bool(value) => Value::Bool(*value),
+        Token::U8(value) => Value::from(*value),
+        Token::U16(value) => Value::from(*value),
+        Token::U32(value) => Value::from(*value),
+        Token::U64(value) => Value::from(*value),
+        Token::U128(value) => Value::String(value.to_string()),
+        Token::U256(value) => Value::String(value.to_string()),
+        Token::B256(bytes) => to_hex(bytes),
+        Token::Bytes(bytes) | Token::RawSlice(bytes) => to_hex(bytes),
+        Token::String(string) => Value::String(string.clone()),
+        Token::StringSlice(string) | Token::StringArray(string) => {
+            Value::String(string.get_encodable_str()?.to_string())
+        }
+        Token::Tuple(tokens) => Value::Array(components_to_json(tokens, names)?),
+        Token::Array(tokens) | Token::Vector(tokens) => {
+            let element_names = names.component(0).unwrap_or(&NO_NAMES);
+            let values = tokens
+                .iter()
+                .map(|token| token_to_json(token, element_names))
+                .collect::<Result<_>>()?;
+
+            Value::Array(values)
+        }
+        Token::Struct(tokens) => {
+            let values = components_to_json(tokens, names)?;
+
+            match component_names(names, tokens.len()) {
+                Some(field_names) => Value::Object(
+                    field_names
+                        .into_iter()
+                        .map(str::to_string)
+                        .zip(values)
+                        .collect(),
+                ),
+                None => Value::Array(values),
+            }
+        }
+        Token::Enum(selector) => {
+            let (discriminant, token, variants) = selector.as_ref();
+            let variant_names = component_names(names, variants.param_types().len());
+            let variant_name = variant_names
+                .as_ref()
+                .and_then(|variant_names| variant_names.get(*discriminant as usize));
+
+            match variant_name {
+                Some(&"None") if names.is_option() => Value::Null,
+                Some(&"Some") if names.is_option() && !some_needs_variant_name(variants, names) => {
+                    token_to_json(
+                        token,
+                        names.component(*discriminant as usize).unwrap_or(&NO_NAMES),
+                    )?
+                }
+                Some(variant_name) if matches!(token, Token::Unit) && !names.is_option() => {
+                    Value::String(variant_name.to_string())
+                }
+                Some(variant_name) => {
+                    let variant_names =
+                        names.component(*discriminant as usize).unwrap_or(&NO_NAMES);
+                    let value = token_to_json(token, variant_names)?;
+
+                    Value::Object(Map::from_iter([(variant_name.to_string(), value)]))
+                }
+                None => {
+                    let value = token_to_json(token, &NO_NAMES)?;
+
+                    Value::Object(Map::from_iter([(discriminant.to_string(), value)]))
+                }
+            }
+        }
+    };
+
+    Ok(value)
+}
+
+fn json_to_components(
+    values: &[Value],
+    param_types: &[ParamType],
+    names: &TypeNames,
+) -> Result<Vec<Token>> {
+    values
+        .iter()
+        .zip(param_types)
+        .enumerate()
+        .map(|(index, (value, param_type))| {
+            json_to_token(
+                value,
+                param_type,
+                names.component(index).unwrap_or(&NO_NAMES),
+            )
+        })
+        .collect()
+}
+
+fn json_to_elements(
+    values: &[Value],
+    param_type: &ParamType,
+    names: &TypeNames,
+) -> Result<Vec<Token>> {
+    let element_names = names.component(0).unwrap_or(&NO_NAMES);
+
+    values
+        .iter()
+        .map(|value| json_to_token(value, param_type, element_names))
+        .collect()
+}
+
+fn json_to_struct_fields(
+    value: &Value,
+    fields: &[ParamType],
+    names: &TypeNames,
+) -> Result<Vec<Token>> {
+    let Some(object) = value.as_object() else {
+        let values = as_array(value, Some(fields.len()))?;
+        return json_to_components(values, fields, names);
+    };
+
+    let field_names = component_names(names, fields.len()).ok_or_else(|| {
+        error!(
+            Codec,
+            "the field names of `{}` are unknown, give its fields as an array instead",
+            names.type_field
+        )
+    })?;
+
+    if let Some(unknown_field) = object
+        .keys()
+        .find(|key| !field_names.contains(&key.as_str()))
+    {
+        return Err(error!(
+            Codec,
+            "unknown field `{unknown_field}` in `{}`", names.type_field
+        ));
+    }
+
+    field_names
+        .iter()
+        .zip(fields)
+        .enumerate()
+        .map(|(index, (field_name, param_type))| {
+            let value = object.get(*field_name).ok_or_else(|| {
+                error!(
+                    Codec,
+                    "missing field `{field_name}` in `{}`", names.type_field
+                )
+            })?;
+
+            json_to_token(
+                value,
+                param_type,
+                names.component(index).unwrap_or(&NO_NAMES),
+            )
+        })
+        .collect()
+}
+
+fn json_to_enum(value: &Value, variants: &EnumVariants, names: &TypeNames) -> Result<Token> {
+    let variant_names = component_names(names, variants.param_types().len());
+    let discriminant_of = |name: &str| -> Result<u64> {
+        variant_names
+            .as_ref()
+            .and_then(|variant_names| variant_names.iter().position(|&variant| variant == name))
+            .or_else(|| name.parse().ok())
+            .map(|index| index as u64)
+            .ok_or_else(|| error!(Codec, "unknown variant `{name}` of `{}`", names.type_field))
+    };
+
+    let (discriminant, value) = match value {
+        Value::Null if names.is_option() => (discriminant_of("None")?, &Value::Null),
+        _ if names.is_option() && !some_needs_variant_name(variants, names) => {
+            (discriminant_of("Some")?, value)
+        }
+        Value::String(variant_name) => (discriminant_of(variant_name)?, &Value::Null),
+        Value::Object(object) if object.len() == 1 => {
+            let (variant_name, value) = object.iter().next().expect("has one entry");
+            (discriminant_of(variant_name)?, value)
+        }
+        _ => {
+            return Err(error!(
+                Codec,
+                "expected an object with a single variant, got `{value}`"
+            ))
+        }
+    };
+
+    let variant_type = variants.param_type_of_variant(discriminant)?;
+    let variant_names = names.component(discriminant as usize).unwrap_or(&NO_NAMES);
+    let token = json_to_token(value, variant_type, variant_names)?;
+
+    Ok(Token::Enum(Box::new((
+        discriminant,
+        token,
+        variants.clone(),
+    ))))
+}
+
+/// Whether an `Option` holds a unit or another `Option`, whose value can be `null` and would be
+/// mistaken for a `None` unless given as `{"Some": value}`.
+fn some_needs_variant_name(variants: &EnumVariants, names: &TypeNames) -> bool {
+    names
+        .components
+        .iter()
+        .position(|component| component.name == "Some")
+        .is_some_and(|index| {
+            matches!(variants.param_types().get(index), Some(ParamType::Unit))
+                || names.component(index).is_some_and(TypeNames::is_option)
+        })
+}
+
+fn components_to_json(tokens: &[Token], names: &TypeNames) -> Result<Vec<Value>> {
+    tokens
+        .iter()
+        .enumerate()
+        .map(|(index, token)| token_to_json(token, names.component(index).unwrap_or(&NO_NAMES)))
+        .collect()
+}
+
+/// The names of the components of a type, if all of them are known.
+fn component_names(names: &TypeNames, expected_len: usize) -> Option<Vec<&str>> {
+    let component_names: Vec<&str> = names
+        .components
+        .iter()
+        .map(|component| component.name.as_str())
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    (component_names.len() == expected_len).then_some(component_names)
+}
+
+fn as_array(value: &Value, expected_len: Option<usize>) -> Result<&Vec<Value>> {
+    let values = value
+        .as_array()
+        .ok_or_else(|| error!(Codec, "expected an array, got `{value}`"))?;
+
+    match expected_len {
+        Some(expected_len) if values.len() != expected_len => Err(error!(
+            Codec,
+            "expected {expected_len} elements, got {}: `{value}`",
+            values.len()
+        )),
+        _ => Ok(values),
+    }
+}
+
+fn as_str(value: &Value) -> Result<&str> {
+    value
+        .as_str()
+        .ok_or_else(|| error!(Codec, "expected a string, got `{value}`"))
+}
+
+fn number_as_string(value: &Value) -> Result<String> {
+    match value {
+        Value::Number(number) => Ok(number.to_string()),
+        Value::String(digits) => Ok(digits.clone()),
+        _ => Err(error!(Codec, "expected a number, got `{value}`")),
+    }
+}
+
+fn parse_number<T>(value: &Value) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let digits = number_as_string(value)?;
+
+    digits
+        .parse()
+        .map_err(|err| error!(Codec, "invalid number `{digits}`: {err}"))
+}
+
+fn parse_hex(value: &Value) -> Result<Vec<u8>> {
+    let hex_str = as_str(value)?;
+    let hex_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+
+    hex::decode(hex_str).map_err(|err| error!(Codec, "invalid hex value `{value}`: {err}"))
+}
+
+fn to_hex(bytes: &[u8]) -> Value {
+    Value::String(format!("0x{}", hex::encode(bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn names(name: &str, type_field: &str, components: Vec<TypeNames>) -> TypeNames {
+        TypeNames {
+            name: name.to_string(),
+            type_field: type_field.to_string(),
+            components,
+        }
+    }
+
+    fn option_of_u64() -> (ParamType, TypeNames) {
+        let param_type = ParamType::Enum {
+            variants: EnumVariants::new(vec![ParamType::Unit, ParamType::U64]).unwrap(),
+            generics: vec![ParamType::U64],
+        };
+        let names = names(
+            "",
+            "enum std::option::Option",
+            vec![names("None", "()", vec![]), names("Some", "u64", vec![])],
+        );
+
+        (param_type, names)
+    }
+
+    #[test]
+    fn structs_use_field_names() -> Result<()> {
+        let param_type = ParamType::Struct {
+            fields: vec![ParamType::U128, ParamType::B256, ParamType::Bytes],
+            generics: vec![],
+        };
+        let names = names(
+            "",
+            "struct MyStruct",
+            vec![
+                names("amount", "struct U128", vec![]),
+                names("id", "b256", vec![]),
+                names("data", "struct Bytes", vec![]),
+            ],
+        );
+        let b256 = format!("0x{}", "01".repeat(32));
+        let value = json!({
+            "amount": "340282366920938463463374607431768211455",
+            "id": b256,
+            "data": "0x0102"
+        });
+
+        let token = json_to_token(&value, &param_type, &names)?;
+
+        assert_eq!(
+            token,
+            Token::Struct(vec![
+                Token::U128(u128::MAX),
+                Token::B256([1; 32]),
+                Token::Bytes(vec![1, 2]),
+            ])
+        );
+        assert_eq!(token_to_json(&token, &names)?, value);
+        assert_eq!(
+            json_to_token(&json!({"amount": 1, "id": b256}), &param_type, &names)
+                .unwrap_err()
+                .to_string(),
+            "codec: missing field `data` in `struct MyStruct`"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn enums_use_variant_names() -> Result<()> {
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::U32])?;
+        let param_type = ParamType::Enum {
+            variants: variants.clone(),
+            generics: vec![],
+        };
+        let names = names(
+            "",
+            "enum State",
+            vec![names("Idle", "()", vec![]), names("Busy", "u32", vec![])],
+        );
+
+        let idle = json_to_token(&json!("Idle"), &param_type, &names)?;
+        let busy = json_to_token(&json!({"Busy": 3}), &param_type, &names)?;
+
+        assert_eq!(
+            idle,
+            Token::Enum(Box::new((0, Token::Unit, variants.clone())))
+        );
+        assert_eq!(busy, Token::Enum(Box::new((1, Token::U32(3), variants))));
+        assert_eq!(token_to_json(&idle, &names)?, json!("Idle"));
+        assert_eq!(token_to_json(&busy, &names)?, json!({"Busy": 3}));
+
+        Ok(())
+    }
+
+    #[test]
+    fn options_are_null_or_their_value() -> Result<()> {
+        let (param_type, names) = option_of_u64();
+
+        let none = json_to_token(&Value::Null, &param_type, &names)?;
+        let some = json_to_token(&json!(5), &param_type, &names)?;
+
+        assert!(matches!(&none, Token::Enum(selector) if selector.0 == 0));
+        assert!(matches!(&some, Token::Enum(selector) if selector.1 == Token::U64(5)));
+        assert_eq!(token_to_json(&none, &names)?, Value::Null);
+        assert_eq!(token_to_json(&some, &names)?, json!(5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn options_that_can_hold_null_name_their_some_variant() -> Result<()> {
+        let (option_param_type, option_names) = option_of_u64();
+        let option_of = |param_type: ParamType, inner_names: TypeNames| {
+            let param_type = ParamType::Enum {
+                variants: EnumVariants::new(vec![ParamType::Unit, param_type.clone()]).unwrap(),
+                generics: vec![param_type],
+            };
+            let names = names(
+                "",
+                "enum std::option::Option",
+                vec![
+                    names("None", "()", vec![]),
+                    TypeNames {
+                        name: "Some".to_string(),
+                        ..inner_names
+                    },
+                ],
+            );
+
+            (param_type, names)
+        };
+        let (nested_param_type, nested_names) = option_of(option_param_type, option_names);
+        let (unit_param_type, unit_names) = option_of(ParamType::Unit, names("", "()", vec![]));
+
+        for (value, param_type, names) in [
+            (Value::Null, &nested_param_type, &nested_names),
+            (json!({"Some": null}), &nested_param_type, &nested_names),
+            (json!({"Some": 5}), &nested_param_type, &nested_names),
+            (Value::Null, &unit_param_type, &unit_names),
+            (json!({"Some": null}), &unit_param_type, &unit_names),
+        ] {
+            let token = json_to_token(&value, param_type, names)?;
+
+            assert_eq!(token_to_json(&token, names)?, value);
+        }
+        assert!(matches!(
+            json_to_token(&json!({"Some": null}), &unit_param_type, &unit_names)?,
+            Token::Enum(selector) if selector.0 == 1
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unnamed_types_are_positional() -> Result<()> {
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::U8])?;
+        let param_type = ParamType::Struct {
+            fields: vec![
+                ParamType::Array(Box::new(ParamType::U16), 2),
+                ParamType::Enum {
+                    variants: variants.clone(),
+                    generics: vec![],
+                },
+                ParamType::Vector(Box::new(ParamType::StringArray(2))),
+            ],
+            generics: vec![],
+        };
+        let value = json!([[1, 2], {"1": 7}, ["ab", "cd"]]);
+
+        let token = json_to_token(&value, &param_type, &TypeNames::default())?;
+
+        assert_eq!(
+            token,
+            Token::Struct(vec![
+                Token::Array(vec![Token::U16(1), Token::U16(2)]),
+                Token::Enum(Box::new((1, Token::U8(7), variants))),
+                Token::Vector(vec![
+                    Token::StringArray(StaticStringToken::new("ab".to_string(), Some(2))),
+                    Token::StringArray(StaticStringToken::new("cd".to_string(), Some(2))),
+                ]),
+            ])
+        );
+        assert_eq!(token_to_json(&token, &TypeNames::default())?, value);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        let error = |value: Value, param_type: ParamType| {
+            json_to_token(&value, &param_type, &TypeNames::default())
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            error(json!(256), ParamType::U8),
+            "codec: invalid number `256`: number too large to fit in target type"
+        );
+        assert_eq!(
+            error(json!([1]), ParamType::Tuple(vec![ParamType::U8; 2])),
+            "codec: expected 2 elements, got 1: `[1]`"
+        );
+        assert_eq!(
+            error(json!("0x01"), ParamType::B256),
+            "codec: expected 32 bytes for `b256`, got `\"0x01\"`"
+        );
+        assert_eq!(
+            error(json!("abc"), ParamType::StringArray(2)),
+            "codec: expected a string of length 2, got `\"abc\"`"
+        );
+    }
+}
@@ -7,17 +7,20 @@ use std::{
 
 use fuel_tx::{ContractId, Receipt};
 
-#[cfg(not(experimental))]
-use crate::types::param_types::ParamType;
 use crate::{
-    codec::{ABIDecoder, DecoderConfig},
+    codec::{ABIDecoder, DecoderConfig, Encoding},
     traits::{Parameterize, Tokenizable},
-    types::errors::{error, Error, Result},
+    types::{
+        errors::{error, Error, Result},
+        param_types::ParamType,
+        Token,
+    },
 };
 
 #[derive(Clone)]
 pub struct LogFormatter {
-    formatter: fn(DecoderConfig, &[u8]) -> Result<String>,
+    formatter: fn(DecoderConfig, &ParamType, &[u8]) -> Result<String>,
+    param_type: ParamType,
     type_id: TypeId,
 }
 
@@ -25,29 +28,35 @@ impl LogFormatter {
     pub fn new<T: Tokenizable + Parameterize + Debug + 'static>() -> Self {
         Self {
             formatter: Self::format_log::<T>,
+            param_type: T::param_type(),
             type_id: TypeId::of::<T>(),
         }
     }
 
-    fn format_log<T: Parameterize + Tokenizable + Debug>(
+    /// Formats logs of a type only known at runtime, e.g. from a JSON ABI, as [`Token`]s.
+    pub fn from_param_type(param_type: ParamType) -> Self {
+        Self {
+            formatter: Self::format_log::<Token>,
+            param_type,
+            type_id: TypeId::of::<Token>(),
+        }
+    }
+
+    fn format_log<T: Tokenizable + Debug>(
         decoder_config: DecoderConfig,
+        param_type: &ParamType,
         bytes: &[u8],
     ) -> Result<String> {
-        #[cfg(not(experimental))]
-        let token = {
-            Self::can_decode_log_with_type::<T>()?;
-            ABIDecoder::new(decoder_config).decode(&T::param_type(), bytes)?
-        };
-
-        #[cfg(experimental)]
-        let token = ABIDecoder::new(decoder_config).experimental_decode(&T::param_type(), bytes)?;
+        if decoder_config.encoding == Encoding::Legacy {
+            Self::can_decode_log_with_type(param_type)?;
+        }
+        let token = ABIDecoder::new(decoder_config).decode(param_type, bytes)?;
 
         Ok(format!("{:?}", T::from_token(token)?))
     }
 
-    #[cfg(not(experimental))]
-    fn can_decode_log_with_type<T: Parameterize>() -> Result<()> {
-        match T::param_type() {
+    fn can_decode_log_with_type(param_type: &ParamType) -> Result<()> {
+        match param_type {
             // String slices cannot be decoded from logs as they are encoded as ptr, len
             // TODO: Once https://github.com/FuelLabs/sway/issues/5110 is resolved we can remove this
             ParamType::StringSlice => Err(error!(
@@ -63,7 +72,7 @@ impl LogFormatter {
     }
 
     pub fn format(&self, decoder_config: DecoderConfig, bytes: &[u8]) -> Result<String> {
-        (self.formatter)(decoder_config, bytes)
+        (self.formatter)(decoder_config, &self.param_type, bytes)
     }
 }
 
@@ -195,11 +204,6 @@ impl LogDecoder {
             .extract_log_id_and_data()
             .filter_map(|(log_id, bytes)| {
                 target_ids.contains(&log_id).then(|| {
-                    #[cfg(experimental)]
-                    let token = ABIDecoder::new(self.decoder_config)
-                        .experimental_decode(&T::param_type(), &bytes)?;
-
-                    #[cfg(not(experimental))]
                     let token =
                         ABIDecoder::new(self.decoder_config).decode(&T::param_type(), &bytes)?;
 
@@ -1,7 +1,14 @@
 use async_trait::async_trait;
 use fuel_crypto::{Message, Signature};
 
-use crate::types::{bech32::Bech32Address, errors::Result};
+use crate::{
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::Bech32Address,
+        errors::Result,
+        typed_data::{personal_message_hash, typed_data_hash, TypedDataDomain},
+    },
+};
 
 /// Trait for signing transactions and messages
 ///
@@ -11,4 +18,24 @@ use crate::types::{bech32::Bech32Address, errors::Result};
 pub trait Signer: 'static {
     async fn sign(&self, message: Message) -> Result<Signature>;
     fn address(&self) -> &Bech32Address;
+
+    /// Signs `value` as typed data of `domain`. The signer can be recovered with
+    /// [`recover_typed`](crate::types::typed_data::recover_typed).
+    async fn sign_typed<T>(&self, domain: &TypedDataDomain, value: T) -> Result<Signature>
+    where
+        T: Tokenizable + Parameterize + Send,
+        Self: Sized + Sync,
+    {
+        self.sign(typed_data_hash(domain, value)?).await
+    }
+
+    /// Signs `message` prefixed as a personal message. The signer can be recovered with
+    /// [`recover_personal_message`](crate::types::typed_data::recover_personal_message).
+    async fn sign_personal_message<M>(&self, message: M) -> Result<Signature>
+    where
+        M: AsRef<[u8]> + Send,
+        Self: Sized + Sync,
+    {
+        self.sign(personal_message_hash(message)).await
+    }
 }
@@ -19,11 +19,12 @@ pub mod errors;
 pub mod param_types;
 pub mod transaction_builders;
 pub mod tx_status;
+pub mod typed_data;
 pub mod unresolved_bytes;
 mod wrappers;
 
 pub type ByteArray = [u8; 8];
-pub type Selector = ByteArray;
+pub type Selector = Vec<u8>;
 pub type EnumSelector = (u64, Token, EnumVariants);
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
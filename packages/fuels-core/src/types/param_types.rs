@@ -230,8 +230,63 @@ impl ParamType {
     }
 }
 
+/// The names a JSON ABI gives to a type and its components, i.e. struct field and enum variant
+/// names, which are not part of a [`ParamType`].
+///
+/// The `components` mirror the children of the matching [`ParamType`]: the fields of a struct, the
+/// variants of an enum, the elements of a tuple or the element type of an array or vector.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeNames {
+    /// The name of the struct field, enum variant or argument this type was given to.
+    pub name: String,
+    /// The type as declared in the JSON ABI, e.g. `struct MyStruct` or `enum Option`.
+    pub type_field: String,
+    pub components: Vec<TypeNames>,
+}
+
+impl TypeNames {
+    /// For when you need the names of a ABI JSON's TypeApplication in addition to its ParamType.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_application`: The TypeApplication you wish to get the names of
+    /// * `type_lookup`: The TypeDeclarations mentioned in the TypeApplication by type id
+    pub fn try_from_type_application(
+        type_application: &TypeApplication,
+        type_lookup: &HashMap<usize, TypeDeclaration>,
+    ) -> Result<Self> {
+        Ok((&Type::try_from(type_application, type_lookup)?).into())
+    }
+
+    pub(crate) fn component(&self, index: usize) -> Option<&TypeNames> {
+        self.components.get(index)
+    }
+
+    pub(crate) fn is_option(&self) -> bool {
+        ["enum std::option::Option", "enum Option"].contains(&self.type_field.as_str())
+    }
+}
+
+impl From<&Type> for TypeNames {
+    fn from(the_type: &Type) -> Self {
+        // The components of a vector are its internal fields, the element type is its generic
+        let components = if is_vector(the_type) {
+            &the_type.generic_params
+        } else {
+            &the_type.components
+        };
+
+        Self {
+            name: the_type.name.clone(),
+            type_field: the_type.type_field.clone(),
+            components: components.iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Type {
+    name: String,
     type_field: String,
     generic_params: Vec<Type>,
     components: Vec<Type>,
@@ -276,7 +331,10 @@ impl Type {
                     )
                 })?;
 
-            return Ok(generic_type.clone());
+            return Ok(Type {
+                name: type_application.name.clone(),
+                ..generic_type.clone()
+            });
         }
 
         // Figure out what does the current type do with the inherited generic
@@ -299,6 +357,7 @@ impl Type {
             .collect::<Result<Vec<_>>>()?;
 
         Ok(Type {
+            name: type_application.name.clone(),
             type_field: type_declaration.type_field.clone(),
             components,
             generic_params: generic_params_lookup
@@ -426,8 +485,12 @@ fn has_struct_format(field: &str) -> bool {
     field.starts_with("struct ")
 }
 
+fn is_vector(the_type: &Type) -> bool {
+    ["struct std::vec::Vec", "struct Vec"].contains(&the_type.type_field.as_str())
+}
+
 fn try_vector(the_type: &Type) -> Result<Option<ParamType>> {
-    if !["struct std::vec::Vec", "struct Vec"].contains(&the_type.type_field.as_str()) {
+    if !is_vector(the_type) {
         return Ok(None);
     }
 
@@ -948,6 +1011,69 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn type_names_keep_field_names_of_generic_structs() -> Result<()> {
+        // given
+        let declarations = [
+            TypeDeclaration {
+                type_id: 1,
+                type_field: "generic T".to_string(),
+                components: None,
+                type_parameters: None,
+            },
+            TypeDeclaration {
+                type_id: 2,
+                type_field: "struct SomeStruct".to_string(),
+                components: Some(vec![TypeApplication {
+                    name: "field".to_string(),
+                    type_id: 1,
+                    type_arguments: None,
+                }]),
+                type_parameters: Some(vec![1]),
+            },
+            TypeDeclaration {
+                type_id: 3,
+                type_field: "u8".to_string(),
+                components: None,
+                type_parameters: None,
+            },
+        ];
+
+        let type_application = TypeApplication {
+            name: "arg".to_string(),
+            type_id: 2,
+            type_arguments: Some(vec![TypeApplication {
+                name: "".to_string(),
+                type_id: 3,
+                type_arguments: None,
+            }]),
+        };
+
+        let type_lookup = declarations
+            .into_iter()
+            .map(|decl| (decl.type_id, decl))
+            .collect::<HashMap<_, _>>();
+
+        // when
+        let result = TypeNames::try_from_type_application(&type_application, &type_lookup)?;
+
+        // then
+        assert_eq!(
+            result,
+            TypeNames {
+                name: "arg".to_string(),
+                type_field: "struct SomeStruct".to_string(),
+                components: vec![TypeNames {
+                    name: "field".to_string(),
+                    type_field: "u8".to_string(),
+                    components: vec![],
+                }],
+            }
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn handles_enums() -> Result<()> {
         // given
@@ -1822,6 +1948,7 @@ mod tests {
     #[test]
     fn try_raw_slice_correctly_resolves_param_type() {
         let the_type = Type {
+            name: String::new(),
             type_field: "raw untyped slice".to_string(),
             generic_params: vec![],
             components: vec![],
@@ -1914,6 +2041,7 @@ mod tests {
 
     fn given_type_with_path(path: &str) -> Type {
         Type {
+            name: String::new(),
             type_field: format!("struct {path}"),
             generic_params: vec![],
             components: vec![],
@@ -1922,8 +2050,10 @@ mod tests {
 
     fn given_generic_type_with_path(path: &str) -> Type {
         Type {
+            name: String::new(),
             type_field: format!("struct {path}"),
             generic_params: vec![Type {
+                name: String::new(),
                 type_field: "u8".to_string(),
                 generic_params: vec![],
                 components: vec![],
@@ -12,11 +12,14 @@ use fuel_crypto::{Message as CryptoMessage, Signature};
 use fuel_tx::{
     field::{Inputs, WitnessLimit, Witnesses},
     policies::{Policies, PolicyType},
-    Buildable, Chargeable, ConsensusParameters, Create, Input as FuelInput, Output, Script,
-    StorageSlot, Transaction as FuelTransaction, TransactionFee, TxPointer, UniqueIdentifier,
-    Witness,
+    Buildable, Chargeable, ConsensusParameters, Create, Input as FuelInput, Output, Receipt,
+    Script, ScriptExecutionResult, StorageSlot, Transaction as FuelTransaction, TransactionFee,
+    TxPointer, UniqueIdentifier, Witness,
 };
-use fuel_types::{bytes::padded_len_usize, canonical::Serialize, Bytes32, ChainId, Salt};
+use fuel_types::{
+    bytes::padded_len_usize, canonical::Serialize, BlockHeight, Bytes32, ChainId, Salt,
+};
+use fuel_vm::{checked_transaction::IntoChecked, interpreter::Interpreter, storage::MemoryStorage};
 use itertools::Itertools;
 
 use crate::{
@@ -61,6 +64,76 @@ impl<T: DryRunner> DryRunner for &T {
     }
 }
 
+/// A [`DryRunner`] for building transactions without a connection to a node, e.g. on an
+/// air-gapped machine.
+///
+/// The consensus parameters and the gas price have to be provided, as they can't be queried.
+/// Scripts are dry-run in an in-process VM with empty chain state, the maturity of the
+/// transaction aside. The gas they use is exact as long as they don't depend on that state.
+/// Scripts calling contracts or reading blocks panic offline, so their `script_gas_limit` has to
+/// be set in the [`TxPolicies`] instead.
+#[derive(Debug, Clone)]
+pub struct OfflineDryRunner {
+    consensus_parameters: ConsensusParameters,
+    gas_price: u64,
+}
+
+impl OfflineDryRunner {
+    pub fn new(consensus_parameters: ConsensusParameters, gas_price: u64) -> Self {
+        Self {
+            consensus_parameters,
+            gas_price,
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl DryRunner for OfflineDryRunner {
+    async fn dry_run_and_get_used_gas(&self, tx: FuelTransaction, tolerance: f32) -> Result<u64> {
+        let FuelTransaction::Script(tx) = tx else {
+            return Err(error_transaction!(
+                Builder,
+                "only scripts can be dry-run offline"
+            ));
+        };
+        // the highest block height lets the transaction pass any maturity
+        let block_height = BlockHeight::new(u32::MAX);
+        let tx = tx.into_checked_basic(block_height, &self.consensus_parameters)?;
+
+        let storage = MemoryStorage::new(block_height, Default::default());
+        let mut vm =
+            Interpreter::<_, Script>::with_storage(storage, (&self.consensus_parameters).into());
+        let state = vm.transact(tx).map_err(|err| {
+            error_transaction!(Builder, "could not dry-run the script offline: {err:?}")
+        })?;
+
+        match state.receipts().iter().rfind(|r| matches!(r, Receipt::ScriptResult { .. })) {
+            Some(Receipt::ScriptResult {
+                result: ScriptExecutionResult::Panic,
+                ..
+            }) => Err(error_transaction!(
+                Builder,
+                "the script panicked when dry-run offline, likely because it depends on the chain state. Set the `script_gas_limit` in the `TxPolicies`"
+            )),
+            Some(Receipt::ScriptResult { gas_used, .. }) => {
+                Ok((*gas_used as f64 * (1.0 + tolerance as f64)) as u64)
+            }
+            _ => Err(error_transaction!(
+                Builder,
+                "the offline dry-run of the script produced no result"
+            )),
+        }
+    }
+
+    async fn min_gas_price(&self) -> Result<u64> {
+        Ok(self.gas_price)
+    }
+
+    fn consensus_parameters(&self) -> &ConsensusParameters {
+        &self.consensus_parameters
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 struct UnresolvedWitnessIndexes {
     owner_to_idx_offset: HashMap<Bech32Address, u64>,
@@ -1114,6 +1187,87 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn offline_dry_runner_estimates_the_script_gas_limit() -> Result<()> {
+        // given
+        let offline = OfflineDryRunner::new(ConsensusParameters::default(), 0);
+        let script_gas_limit = |num_noops| {
+            let script = repeat(op::noop())
+                .take(num_noops)
+                .chain([op::ret(RegId::ONE)])
+                .collect();
+
+            ScriptTransactionBuilder::default()
+                .with_script(script)
+                .with_inputs(given_inputs(1))
+                .with_gas_estimation_tolerance(0.0)
+                .build_without_signatures(&offline)
+        };
+
+        // when
+        let short_script_gas_limit = script_gas_limit(1).await?.gas_limit();
+        let long_script_gas_limit = script_gas_limit(11).await?.gas_limit();
+
+        // then
+        let noop_gas = ConsensusParameters::default().gas_costs().noop;
+        assert!(short_script_gas_limit > 0);
+        assert_eq!(
+            long_script_gas_limit - short_script_gas_limit,
+            10 * noop_gas
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn offline_dry_runner_requires_a_script_gas_limit_for_scripts_depending_on_the_chain(
+    ) -> Result<()> {
+        // given
+        let offline = OfflineDryRunner::new(ConsensusParameters::default(), 3);
+
+        // reads the balance of a contract that isn't in the inputs
+        let tb = ScriptTransactionBuilder::default()
+            .with_script(
+                [op::bal(0x10, RegId::ZERO, RegId::ZERO), op::ret(RegId::ONE)]
+                    .into_iter()
+                    .collect(),
+            )
+            .with_inputs(given_inputs(1));
+
+        // when
+        let error = tb
+            .clone_without_signers()
+            .with_tx_policies(TxPolicies::default().with_gas_price(0))
+            .build_without_signatures(&offline)
+            .await
+            .expect_err("should fail without a script gas limit");
+        let tx = tb
+            .with_tx_policies(TxPolicies::default().with_script_gas_limit(1000))
+            .build_without_signatures(&offline)
+            .await?;
+
+        // then
+        assert!(error.to_string().contains("`script_gas_limit`"));
+        assert_eq!(tx.gas_price(), 3);
+        assert_eq!(tx.gas_limit(), 1000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn offline_dry_runner_builds_transfers_without_a_script_gas_limit() -> Result<()> {
+        let offline = OfflineDryRunner::new(ConsensusParameters::default(), 0);
+
+        let tx = ScriptTransactionBuilder::default()
+            .with_inputs(given_inputs(1))
+            .build_without_signatures(&offline)
+            .await?;
+
+        assert_eq!(tx.gas_limit(), 0);
+
+        Ok(())
+    }
+
     #[derive(Clone, Debug, Default)]
     struct MockSigner {
         address: Bech32Address,
@@ -0,0 +1,248 @@
+use fuel_crypto::{Hasher, Message, Signature};
+use fuel_types::{Bytes32, ChainId, ContractId};
+use fuels_macros::{Parameterize, Tokenizable, TryFrom};
+
+use crate::{
+    codec::{ABIEncoder, EncoderConfig, Encoding},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        errors::{error, Result},
+        param_types::ParamType,
+    },
+};
+
+/// Precedes the domain separator and the value hash in the hash of typed data, so that typed
+/// data can't be mistaken for a transaction id or a personal message.
+pub const TYPED_DATA_PREFIX: [u8; 2] = [0x19, 0x01];
+
+/// Precedes the length and the bytes of a personal message in its hash, so that a personal
+/// message can't be mistaken for a transaction id or typed data.
+pub const PERSONAL_MESSAGE_PREFIX: &str = "\x19Fuel Signed Message:\n";
+
+/// The domain typed data is signed for. Including it in the signed hash keeps signatures meant
+/// for one contract, chain or version of a scheme from being replayed against another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Parameterize, Tokenizable, TryFrom)]
+#[FuelsCorePath = "crate"]
+#[FuelsTypesPath = "crate::types"]
+pub struct TypedDataDomain {
+    pub chain_id: u64,
+    /// The contract verifying the signatures.
+    pub verifying_contract: ContractId,
+    /// The version of the signing scheme.
+    pub version: u64,
+}
+
+impl TypedDataDomain {
+    pub fn new(chain_id: ChainId, verifying_contract: ContractId, version: u64) -> Self {
+        Self {
+            chain_id: *chain_id,
+            verifying_contract,
+            version,
+        }
+    }
+
+    /// The SHA-256 hash of the encoded domain.
+    pub fn separator(&self) -> Result<Bytes32> {
+        hash_encoded(*self)
+    }
+}
+
+/// Hashes `value` as typed data of `domain`, i.e.
+/// `sha256(TYPED_DATA_PREFIX ++ domain.separator() ++ sha256(encode(value)))`.
+/// This is the message [`Signer::sign_typed`] signs.
+///
+/// Values are encoded the way Sway's `sha256` hashes them: fields are packed one after the
+/// other, without padding, e.g. a `bool` or an `u8` takes a single byte. Types stored on the heap,
+/// like `Vec`, `Bytes` or `String`, can't be hashed as their encoding has no equivalent in Sway.
+///
+/// [`Signer::sign_typed`]: crate::traits::Signer::sign_typed
+pub fn typed_data_hash<T>(domain: &TypedDataDomain, value: T) -> Result<Message>
+where
+    T: Tokenizable + Parameterize,
+{
+    let hash = Hasher::default()
+        .chain(TYPED_DATA_PREFIX)
+        .chain(domain.separator()?)
+        .chain(hash_encoded(value)?)
+        .finalize();
+
+    Ok(Message::from_bytes(*hash))
+}
+
+/// Hashes a personal message, i.e. `sha256(PERSONAL_MESSAGE_PREFIX ++ len ++ message)`, with the
+/// length of the message written in decimal digits. This is the message
+/// [`Signer::sign_personal_message`] signs.
+///
+/// [`Signer::sign_personal_message`]: crate::traits::Signer::sign_personal_message
+pub fn personal_message_hash<M: AsRef<[u8]>>(message: M) -> Message {
+    let message = message.as_ref();
+
+    let hash = Hasher::default()
+        .chain(PERSONAL_MESSAGE_PREFIX)
+        .chain(message.len().to_string())
+        .chain(message)
+        .finalize();
+
+    Message::from_bytes(*hash)
+}
+
+/// Recovers the address that signed `value` as typed data of `domain`.
+pub fn recover_typed<T>(
+    signature: &Signature,
+    domain: &TypedDataDomain,
+    value: T,
+) -> Result<Bech32Address>
+where
+    T: Tokenizable + Parameterize,
+{
+    recover(signature, &typed_data_hash(domain, value)?)
+}
+
+/// Recovers the address that signed the personal message `message`.
+pub fn recover_personal_message<M: AsRef<[u8]>>(
+    signature: &Signature,
+    message: M,
+) -> Result<Bech32Address> {
+    recover(signature, &personal_message_hash(message))
+}
+
+fn recover(signature: &Signature, message: &Message) -> Result<Bech32Address> {
+    let public_key = signature.recover(message)?;
+
+    Ok(Bech32Address::new(FUEL_BECH32_HRP, public_key.hash()))
+}
+
+fn hash_encoded<T: Tokenizable + Parameterize>(value: T) -> Result<Bytes32> {
+    let param_type = T::param_type();
+    if contains_heap_type(&param_type) {
+        return Err(error!(
+            Codec,
+            "typed data can't contain heap types, but `{param_type:?}` does"
+        ));
+    }
+
+    // the experimental encoding holds no pointers and doesn't pad small types
+    let encoded = ABIEncoder::new(EncoderConfig {
+        encoding: Encoding::Experimental,
+        ..Default::default()
+    })
+    .encode(&[value.into_token()])?
+    .resolve(0);
+
+    Ok(Hasher::hash(encoded))
+}
+
+fn contains_heap_type(param_type: &ParamType) -> bool {
+    match param_type {
+        ParamType::Vector(_)
+        | ParamType::Bytes
+        | ParamType::String
+        | ParamType::RawSlice
+        | ParamType::StringSlice => true,
+        ParamType::Array(param_type, _) => contains_heap_type(param_type),
+        ParamType::Struct { fields, .. } => fields.iter().any(contains_heap_type),
+        ParamType::Tuple(param_types) => param_types.iter().any(contains_heap_type),
+        ParamType::Enum { variants, .. } => variants.param_types().iter().any(contains_heap_type),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_crypto::SecretKey;
+
+    use super::*;
+
+    fn domain() -> TypedDataDomain {
+        TypedDataDomain::new(ChainId::new(9889), ContractId::from([1; 32]), 1)
+    }
+
+    #[test]
+    fn domain_separator_hashes_the_encoded_fields() -> Result<()> {
+        let expected = Hasher::default()
+            .chain(9889u64.to_be_bytes())
+            .chain([1; 32])
+            .chain(1u64.to_be_bytes())
+            .finalize();
+
+        assert_eq!(domain().separator()?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_data_signatures_are_bound_to_the_domain() -> Result<()> {
+        let secret = SecretKey::try_from([7; 32].as_slice())?;
+        let address = Bech32Address::new(FUEL_BECH32_HRP, secret.public_key().hash());
+        let order = (42u64, true);
+
+        let signature = Signature::sign(&secret, &typed_data_hash(&domain(), order)?);
+
+        assert_eq!(recover_typed(&signature, &domain(), order)?, address);
+
+        let other_domain = TypedDataDomain {
+            version: 2,
+            ..domain()
+        };
+        assert_ne!(recover_typed(&signature, &other_domain, order)?, address);
+        assert_ne!(
+            recover_typed(&signature, &domain(), (43u64, true))?,
+            address
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn values_are_hashed_like_sway_hashes_them() -> Result<()> {
+        #[derive(Parameterize, Tokenizable)]
+        #[FuelsCorePath = "crate"]
+        #[FuelsTypesPath = "crate::types"]
+        struct Flags {
+            enabled: bool,
+            level: u8,
+            amount: u64,
+        }
+        let flags = Flags {
+            enabled: true,
+            level: 7,
+            amount: 42,
+        };
+
+        // what `sha256(flags)` hashes in Sway, the fields written one after the other
+        let preimage = [1, 7, 0, 0, 0, 0, 0, 0, 0, 42];
+        let expected = Hasher::default()
+            .chain(TYPED_DATA_PREFIX)
+            .chain(domain().separator()?)
+            .chain(Hasher::hash(preimage))
+            .finalize();
+
+        assert_eq!(*typed_data_hash(&domain(), flags)?, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn heap_types_are_rejected() {
+        let error = typed_data_hash(&domain(), (1u64, vec![1u8, 2])).expect_err("should fail");
+
+        assert!(error
+            .to_string()
+            .starts_with("codec: typed data can't contain heap types"));
+    }
+
+    #[test]
+    fn personal_messages_are_prefixed() -> Result<()> {
+        let expected = Hasher::hash(b"\x19Fuel Signed Message:\n5hello");
+        assert_eq!(*personal_message_hash("hello"), *expected);
+
+        let secret = SecretKey::try_from([7; 32].as_slice())?;
+        let signature = Signature::sign(&secret, &personal_message_hash("hello"));
+        let address = recover_personal_message(&signature, "hello")?;
+
+        assert_eq!(address.hash(), secret.public_key().hash());
+
+        Ok(())
+    }
+}
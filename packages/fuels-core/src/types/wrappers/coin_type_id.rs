@@ -1,7 +1,7 @@
 use fuel_tx::UtxoId;
 use fuel_types::Nonce;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CoinTypeId {
     UtxoId(UtxoId),
     Nonce(Nonce),
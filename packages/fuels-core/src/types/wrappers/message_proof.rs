@@ -3,9 +3,16 @@
 use fuel_core_client::client::types::{
     primitives::Nonce, MerkleProof as ClientMerkleProof, MessageProof as ClientMessageProof,
 };
-use fuel_types::Bytes32;
+use fuel_crypto::Hasher;
+use fuel_tx::Input;
+use fuel_types::{Bytes32, MessageId};
+use tai64::Tai64;
 
-use crate::types::{bech32::Bech32Address, block::Header};
+use crate::types::{
+    bech32::Bech32Address,
+    block::Header,
+    errors::{error, Result},
+};
 
 #[derive(Debug)]
 pub struct MerkleProof {
@@ -15,6 +22,16 @@ pub struct MerkleProof {
     pub proof_index: u64,
 }
 
+impl MerkleProof {
+    /// Checks that `leaf` is the leaf at `proof_index` of the binary Merkle tree with `root` and
+    /// `num_leaves` leaves.
+    pub fn verify(&self, root: &Bytes32, leaf: &[u8], num_leaves: u64) -> bool {
+        let proof_set = self.proof_set.iter().map(|node| **node).collect();
+
+        fuel_merkle::binary::verify(root, &leaf, &proof_set, self.proof_index, num_leaves)
+    }
+}
+
 impl From<ClientMerkleProof> for MerkleProof {
     fn from(client_merkle_proof: ClientMerkleProof) -> Self {
         Self {
@@ -43,6 +60,122 @@ pub struct MessageProof {
     pub data: Vec<u8>,
 }
 
+impl MessageProof {
+    /// The id of the proven message, computed from its contents.
+    pub fn message_id(&self) -> MessageId {
+        Input::compute_message_id(
+            &(&self.sender).into(),
+            &(&self.recipient).into(),
+            &self.nonce,
+            self.amount,
+            &self.data,
+        )
+    }
+
+    /// Verifies the proof without trusting the node it came from. Checks that:
+    /// - the ids of both headers match their contents,
+    /// - the message is included in the `message_receipt_root` of `message_block_header`,
+    /// - `message_block_header` is included in the block history committed to by
+    ///   `commit_block_header`.
+    ///
+    /// The `commit_block_header` itself still has to be checked against a trusted source, e.g.
+    /// the block committed on the base layer.
+    pub fn verify(&self) -> Result<()> {
+        verify_header_id(&self.message_block_header)?;
+        verify_header_id(&self.commit_block_header)?;
+
+        let message_id = self.message_id();
+        let message_header = &self.message_block_header;
+        if !self.message_proof.verify(
+            &message_header.message_receipt_root,
+            message_id.as_ref(),
+            message_header.message_receipt_count,
+        ) {
+            return Err(error!(
+                Other,
+                "message `{message_id}` is not included in block `{}`", message_header.id
+            ));
+        }
+
+        let commit_header = &self.commit_block_header;
+        if message_header.height >= commit_header.height {
+            return Err(error!(
+                Other,
+                "message block height `{}` must be lower than the commit block height `{}`",
+                message_header.height,
+                commit_header.height
+            ));
+        }
+
+        // The block history committed to by `prev_root` holds every block up to the one before
+        // the commit block. Blocks are leaves in order of height, starting at the genesis block.
+        if self.block_proof.proof_index != u64::from(message_header.height) {
+            return Err(error!(
+                Other,
+                "block proof index `{}` does not match the message block height `{}`",
+                self.block_proof.proof_index,
+                message_header.height
+            ));
+        }
+
+        if !self.block_proof.verify(
+            &commit_header.prev_root,
+            message_header.id.as_ref(),
+            u64::from(commit_header.height),
+        ) {
+            return Err(error!(
+                Other,
+                "block `{}` is not included in the history of block `{}`",
+                message_header.id,
+                commit_header.id
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn verify_header_id(header: &Header) -> Result<()> {
+    let id = compute_header_id(header)?;
+
+    if id != header.id {
+        return Err(error!(
+            Other,
+            "block header `{}` does not match its contents, expected id `{id}`", header.id
+        ));
+    }
+
+    Ok(())
+}
+
+fn compute_header_id(header: &Header) -> Result<Bytes32> {
+    let time = header
+        .time
+        .ok_or_else(|| error!(Other, "block header `{}` has no valid time", header.id))?;
+
+    let application_hash = Hasher::default()
+        .chain(header.da_height.to_be_bytes())
+        .chain(header.transactions_count.to_be_bytes())
+        .chain(header.message_receipt_count.to_be_bytes())
+        .chain(header.transactions_root)
+        .chain(header.message_receipt_root)
+        .finalize();
+
+    if application_hash != header.application_hash {
+        return Err(error!(
+            Other,
+            "application hash of block header `{}` does not match its contents", header.id
+        ));
+    }
+
+    Ok(Hasher::default()
+        .chain(header.prev_root)
+        .chain(header.height.to_be_bytes())
+        .chain(Tai64::from_unix(time.timestamp()).0.to_be_bytes())
+        .chain(application_hash)
+        .finalize())
+}
+
 impl From<ClientMessageProof> for MessageProof {
     fn from(client_message_proof: ClientMessageProof) -> Self {
         Self {
@@ -58,3 +191,162 @@ impl From<ClientMessageProof> for MessageProof {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use fuel_merkle::binary::in_memory::MerkleTree;
+    use fuel_types::Address;
+
+    use super::*;
+
+    fn merkle_proof(leaves: &[&[u8]], proof_index: u64) -> (Bytes32, MerkleProof) {
+        let mut tree = MerkleTree::new();
+        leaves.iter().for_each(|leaf| tree.push(leaf));
+
+        let (root, proof_set) = tree.prove(proof_index).expect("leaf exists");
+        let proof = MerkleProof {
+            proof_set: proof_set.into_iter().map(Bytes32::from).collect(),
+            proof_index,
+        };
+
+        (root.into(), proof)
+    }
+
+    fn header(height: u32, prev_root: Bytes32, message_root: Bytes32, num_messages: u64) -> Header {
+        let mut header = Header {
+            id: Default::default(),
+            da_height: 1,
+            transactions_count: 1,
+            message_receipt_count: num_messages,
+            transactions_root: Bytes32::new([5; 32]),
+            message_receipt_root: message_root,
+            height,
+            prev_root,
+            time: DateTime::<Utc>::from_timestamp(1_700_000_000, 0),
+            application_hash: Default::default(),
+        };
+        header.application_hash = Hasher::default()
+            .chain(header.da_height.to_be_bytes())
+            .chain(header.transactions_count.to_be_bytes())
+            .chain(header.message_receipt_count.to_be_bytes())
+            .chain(header.transactions_root)
+            .chain(header.message_receipt_root)
+            .finalize();
+        header.id = compute_header_id(&header).expect("header has a time");
+
+        header
+    }
+
+    /// A proof of the second of three messages in block 2, committed to by block 5.
+    fn valid_proof() -> MessageProof {
+        let mut proof = MessageProof {
+            message_proof: MerkleProof {
+                proof_set: vec![],
+                proof_index: 0,
+            },
+            block_proof: MerkleProof {
+                proof_set: vec![],
+                proof_index: 0,
+            },
+            message_block_header: header(0, Default::default(), Default::default(), 0),
+            commit_block_header: header(0, Default::default(), Default::default(), 0),
+            sender: Address::new([1; 32]).into(),
+            recipient: Address::new([2; 32]).into(),
+            nonce: Nonce::new([3; 32]),
+            amount: 100,
+            data: vec![4, 5],
+        };
+
+        let message_id = proof.message_id();
+        let (message_root, message_proof) =
+            merkle_proof(&[&[0; 32], message_id.as_ref(), &[1; 32]], 1);
+        let message_block_header = header(2, Bytes32::new([7; 32]), message_root, 3);
+
+        let (history_root, block_proof) = merkle_proof(
+            &[
+                &[10; 32],
+                &[11; 32],
+                message_block_header.id.as_ref(),
+                &[13; 32],
+                &[14; 32],
+            ],
+            2,
+        );
+        let commit_block_header = header(5, history_root, Default::default(), 0);
+
+        proof.message_proof = message_proof;
+        proof.block_proof = block_proof;
+        proof.message_block_header = message_block_header;
+        proof.commit_block_header = commit_block_header;
+
+        proof
+    }
+
+    #[test]
+    fn valid_proof_is_verified() -> Result<()> {
+        valid_proof().verify()
+    }
+
+    #[test]
+    fn proof_of_a_different_message_is_rejected() {
+        let mut proof = valid_proof();
+        proof.amount += 1;
+
+        let error = proof.verify().expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "message `{}` is not included in block `{}`",
+                proof.message_id(),
+                proof.message_block_header.id
+            )
+        );
+    }
+
+    #[test]
+    fn tampered_header_is_rejected() {
+        let mut proof = valid_proof();
+        proof.message_block_header.message_receipt_root = Bytes32::new([9; 32]);
+
+        let error = proof.verify().expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "application hash of block header `{}` does not match its contents",
+                proof.message_block_header.id
+            )
+        );
+    }
+
+    #[test]
+    fn block_missing_from_the_history_is_rejected() {
+        let mut proof = valid_proof();
+        proof.block_proof.proof_set[0] = Bytes32::new([9; 32]);
+
+        let error = proof.verify().expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "block `{}` is not included in the history of block `{}`",
+                proof.message_block_header.id, proof.commit_block_header.id
+            )
+        );
+    }
+
+    #[test]
+    fn tampered_block_proof_index_is_rejected() {
+        let mut proof = valid_proof();
+        proof.block_proof.proof_index = 3;
+
+        let error = proof.verify().expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            "block proof index `3` does not match the message block height `2`"
+        );
+    }
+}
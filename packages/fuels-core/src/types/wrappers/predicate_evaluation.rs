@@ -0,0 +1,133 @@
+use fuel_asm::{PanicInstruction, PanicReason, RegId, Word};
+use fuel_tx::{ConsensusParameters, Input, Receipt};
+use fuel_vm::{
+    checked_transaction::CheckPredicateParams,
+    context::Context,
+    error::InterpreterError,
+    interpreter::{ExecutableTransaction, Interpreter},
+    predicate::RuntimePredicate,
+    state::ExecuteState,
+    storage::PredicateStorage,
+};
+
+use crate::types::errors::{error, Result};
+
+/// How the run of a predicate ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredicateOutcome {
+    /// The predicate returned the value. Only `1`, i.e. `true`, lets the input be spent.
+    Returned(Word),
+    /// The predicate reverted with the revert code.
+    Reverted(Word),
+    /// The predicate panicked, e.g. by running out of gas or using an instruction that isn't
+    /// allowed in predicates.
+    Panicked(PanicInstruction),
+    /// The owner of the input isn't the address of the predicate, so the predicate wasn't run.
+    InvalidOwner,
+    /// The predicate returned `true`, but used a different amount of gas than the
+    /// `predicate_gas_used` of the input, which the node rejects. Estimating the predicates of
+    /// the transaction sets the right amount.
+    GasMismatch { predicate_gas_used: u64 },
+}
+
+/// The result of running a predicate against a transaction, as returned by
+/// [`EstimablePredicates::evaluate_predicate`].
+///
+/// [`EstimablePredicates::evaluate_predicate`]: crate::types::transaction::EstimablePredicates::evaluate_predicate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredicateEvaluation {
+    pub outcome: PredicateOutcome,
+    pub gas_used: u64,
+    /// The receipts produced by the predicate until it ended. The VM doesn't let predicates log
+    /// yet, so they are only available once it does.
+    pub receipts: Vec<Receipt>,
+}
+
+impl PredicateEvaluation {
+    /// Whether the predicate allows the input to be spent, i.e. whether it returned `true` and
+    /// the node would accept the input as it is.
+    pub fn passed(&self) -> bool {
+        self.outcome == PredicateOutcome::Returned(1)
+    }
+}
+
+/// Runs the predicate of input `input_index` the way the node estimates it, but reports how
+/// the run ended instead of only whether it succeeded.
+pub(crate) fn evaluate_predicate<Tx>(
+    tx: &Tx,
+    input_index: usize,
+    consensus_parameters: &ConsensusParameters,
+) -> Result<PredicateEvaluation>
+where
+    Tx: ExecutableTransaction,
+{
+    let params = CheckPredicateParams::from(consensus_parameters);
+
+    let predicate = RuntimePredicate::from_tx(tx, params.tx_offset, input_index)
+        .ok_or_else(|| error!(Other, "input `{input_index}` is not spent by a predicate"))?;
+    let program = predicate.program().words();
+
+    let input = &tx.inputs()[input_index];
+    let (code, _, &predicate_gas_used) = input.predicate().expect("is a predicate input");
+    let owner = input.input_owner().or_else(|| input.recipient());
+    if owner.is_some_and(|owner| !Input::is_predicate_owner_valid(owner, code)) {
+        return Ok(PredicateEvaluation {
+            outcome: PredicateOutcome::InvalidOwner,
+            gas_used: 0,
+            receipts: vec![],
+        });
+    }
+
+    let gas_limit = params.max_gas_per_predicate.min(params.max_gas_per_tx);
+    let mut vm =
+        Interpreter::<PredicateStorage, Tx>::with_storage(PredicateStorage {}, params.into());
+    vm.init_predicate(
+        Context::PredicateEstimation { program: predicate },
+        tx.clone(),
+        gas_limit,
+    )
+    .map_err(|err| error!(Other, "could not initialize the predicate: {err:?}"))?;
+
+    vm.registers_mut()[RegId::PC] = program.start;
+    vm.registers_mut()[RegId::IS] = program.start;
+
+    let outcome = loop {
+        if program.end <= vm.registers()[RegId::PC] {
+            break panicked(PanicReason::MemoryOverflow);
+        }
+
+        match vm.execute() {
+            Ok(ExecuteState::Return(value)) => break PredicateOutcome::Returned(value),
+            // predicates are not allowed to return data
+            Ok(ExecuteState::ReturnData(_)) => {
+                break panicked(PanicReason::ContractInstructionNotAllowed)
+            }
+            Ok(ExecuteState::Revert(code)) => break PredicateOutcome::Reverted(code),
+            Ok(ExecuteState::Proceed | ExecuteState::DebugEvent(_)) => {}
+            Err(InterpreterError::PanicInstruction(panic)) => {
+                break PredicateOutcome::Panicked(panic)
+            }
+            Err(InterpreterError::Panic(reason)) => break panicked(reason),
+            Err(err) => return Err(error!(Other, "could not run the predicate: {err:?}")),
+        }
+    };
+
+    let gas_used = gas_limit.saturating_sub(vm.remaining_gas());
+    let outcome = match outcome {
+        PredicateOutcome::Returned(1) if gas_used != predicate_gas_used => {
+            PredicateOutcome::GasMismatch { predicate_gas_used }
+        }
+        outcome => outcome,
+    };
+
+    Ok(PredicateEvaluation {
+        outcome,
+        gas_used,
+        receipts: vm.receipts().to_vec(),
+    })
+}
+
+fn panicked(reason: PanicReason) -> PredicateOutcome {
+    // the instruction isn't known, like for the panics the VM raises outside of instructions
+    PredicateOutcome::Panicked(PanicInstruction::error(reason, 0))
+}
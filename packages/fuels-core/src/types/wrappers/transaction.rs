@@ -17,7 +17,11 @@ use fuel_tx::{
     Input, Mint, Output, Salt as FuelSalt, Script, StorageSlot, Transaction as FuelTransaction,
     TransactionFee, UniqueIdentifier, Witness,
 };
-use fuel_types::{bytes::padded_len_usize, AssetId, ChainId};
+use fuel_types::{
+    bytes::padded_len_usize,
+    canonical::{Deserialize as _, Serialize as _},
+    AssetId, ChainId,
+};
 use fuel_vm::checked_transaction::EstimatePredicates;
 use itertools::Itertools;
 
@@ -26,7 +30,8 @@ use crate::{
     traits::Signer,
     types::{
         bech32::Bech32Address,
-        errors::{error_transaction, Result},
+        errors::{error, error_transaction, Result},
+        predicate_evaluation::{evaluate_predicate, PredicateEvaluation},
     },
     utils::{calculate_witnesses_size, sealed},
 };
@@ -170,6 +175,15 @@ pub trait EstimablePredicates: sealed::Sealed {
     /// before sending the transaction to the node. The estimation will check
     /// all predicates and set the `predicate_gas_used` to the actual consumed gas.
     fn estimate_predicates(&mut self, consensus_parameters: &ConsensusParameters) -> Result<()>;
+
+    /// Runs the predicate of the input at `input_index` against the transaction in an
+    /// in-process interpreter, without a node. Unlike the estimation, it reports how the
+    /// predicate ended, e.g. why it panicked, along with the gas it used and its receipts.
+    fn evaluate_predicate(
+        &self,
+        input_index: usize,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<PredicateEvaluation>;
 }
 
 pub trait GasValidation: sealed::Sealed {
@@ -220,6 +234,10 @@ pub trait Transaction:
     /// Append witness and return the corresponding witness index
     fn append_witness(&mut self, witness: Witness) -> Result<usize>;
 
+    /// Replace the witness at `index`. Witnesses are not part of the transaction ID, so
+    /// replacing one does not invalidate existing signatures.
+    fn replace_witness(&mut self, index: usize, witness: Witness) -> Result<()>;
+
     fn used_coins(&self) -> HashMap<(Bech32Address, AssetId), Vec<CoinTypeId>>;
 
     async fn sign_with(
@@ -301,6 +319,60 @@ macro_rules! impl_tx_wrapper {
             }
         }
 
+        impl $wrapper {
+            /// Encodes the transaction into the canonical bytes understood by the node. The
+            /// bytes can be stored or moved to another machine, e.g. to be signed offline, and
+            /// decoded back with [`Self::from_bytes`].
+            pub fn to_bytes(&self) -> Vec<u8> {
+                FuelTransaction::from(self.tx.clone()).to_bytes()
+            }
+
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+                let tx = FuelTransaction::from_bytes(bytes)
+                    .map_err(|err| error!(Codec, "could not decode transaction: {err}"))?;
+
+                match tx {
+                    FuelTransaction::$wrapped(tx) => Ok(tx.into()),
+                    _ => Err(error!(
+                        Codec,
+                        "expected a `{}` transaction",
+                        stringify!($wrapped)
+                    )),
+                }
+            }
+
+            /// The canonical bytes of the transaction as a `0x` prefixed hex string.
+            pub fn to_hex(&self) -> String {
+                format!("0x{}", hex::encode(self.to_bytes()))
+            }
+
+            pub fn from_hex(hex: &str) -> Result<Self> {
+                let bytes = hex::decode(hex.strip_prefix("0x").unwrap_or(hex))
+                    .map_err(|err| error!(Codec, "could not decode transaction hex: {err}"))?;
+
+                Self::from_bytes(&bytes)
+            }
+        }
+
+        impl serde::Serialize for $wrapper {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_hex())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $wrapper {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                let hex = String::deserialize(deserializer)?;
+
+                Self::from_hex(&hex).map_err(serde::de::Error::custom)
+            }
+        }
+
         impl sealed::Sealed for $wrapper {}
 
         #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
@@ -397,6 +469,36 @@ macro_rules! impl_tx_wrapper {
                 }
             }
 
+            fn replace_witness(&mut self, index: usize, witness: Witness) -> Result<()> {
+                let num_witnesses = self.tx.witnesses().len();
+                if index >= num_witnesses {
+                    return Err(error_transaction!(
+                        Validation,
+                        "witness index `{index}` out of bounds, the transaction has `{num_witnesses}` witnesses"
+                    ));
+                }
+
+                let new_witnesses_size = padded_len_usize(calculate_witnesses_size(
+                    self.tx
+                        .witnesses()
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, current)| if idx == index { &witness } else { current }),
+                )) as u64;
+
+                if new_witnesses_size > self.tx.witness_limit() {
+                    return Err(error_transaction!(
+                        Validation,
+                        "Witness limit exceeded. Consider setting the limit manually with \
+                        a transaction builder. The new limit should be: `{new_witnesses_size}`"
+                    ));
+                }
+
+                self.tx.witnesses_mut()[index] = witness;
+
+                Ok(())
+            }
+
             fn used_coins(&self) -> HashMap<(Bech32Address, AssetId), Vec<CoinTypeId>> {
                 self.inputs()
                     .iter()
@@ -443,6 +545,14 @@ impl EstimablePredicates for CreateTransaction {
 
         Ok(())
     }
+
+    fn evaluate_predicate(
+        &self,
+        input_index: usize,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<PredicateEvaluation> {
+        evaluate_predicate(&self.tx, input_index, consensus_parameters)
+    }
 }
 
 impl CreateTransaction {
@@ -486,6 +596,14 @@ impl EstimablePredicates for ScriptTransaction {
 
         Ok(())
     }
+
+    fn evaluate_predicate(
+        &self,
+        input_index: usize,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<PredicateEvaluation> {
+        evaluate_predicate(&self.tx, input_index, consensus_parameters)
+    }
 }
 
 impl GasValidation for ScriptTransaction {
@@ -560,4 +678,37 @@ mod test {
 
         assert_eq!(&err.to_string(), expected_err_str);
     }
+
+    #[test]
+    fn transactions_survive_a_bytes_and_hex_round_trip() -> Result<()> {
+        let script = FuelTransaction::script(
+            100,
+            vec![1, 2, 3],
+            vec![4, 5],
+            Policies::default().with_gas_price(7),
+            vec![],
+            vec![],
+            vec![vec![6; 64].into()],
+        );
+        let tx = ScriptTransaction::from(script.clone());
+
+        let from_bytes = ScriptTransaction::from_bytes(&tx.to_bytes())?;
+        let from_hex = ScriptTransaction::from_hex(&tx.to_hex())?;
+        let from_json: ScriptTransaction = serde_json::from_str(&serde_json::to_string(&tx)?)?;
+
+        for decoded in [from_bytes, from_hex, from_json] {
+            assert_eq!(Script::from(decoded), script);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn decoding_the_wrong_transaction_type_fails() {
+        let create = CreateTransaction::from(Create::default());
+
+        let err = ScriptTransaction::from_bytes(&create.to_bytes()).expect_err("should error");
+
+        assert_eq!(err.to_string(), "codec: expected a `Script` transaction");
+    }
 }
@@ -7,6 +7,8 @@ use fuels_core::{
     types::errors::Result,
 };
 
+use crate::call_trace::{CallTrace, CallTraceBuilder};
+
 /// [`FuelCallResponse`] is a struct that is returned by a call to the contract or script. Its value
 /// field holds the decoded typed value returned by the contract's method. The other field holds all
 /// the receipts returned by the call.
@@ -56,4 +58,12 @@ impl<D> FuelCallResponse<D> {
     pub fn decode_logs_with_type<T: Tokenizable + Parameterize + 'static>(&self) -> Result<Vec<T>> {
         self.log_decoder.decode_logs_with_type::<T>(&self.receipts)
     }
+
+    /// Reconstructs the calls made by the transaction, with the logs decoded by the
+    /// `log_decoder`. Use a [`CallTraceBuilder`] to also decode arguments and return values.
+    pub fn call_trace(&self) -> CallTrace {
+        CallTraceBuilder::default()
+            .with_log_decoder(self.log_decoder.clone())
+            .build(&self.receipts)
+    }
 }
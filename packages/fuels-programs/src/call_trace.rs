@@ -0,0 +1,779 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+use fuel_tx::{
+    Address, AssetId, ConsensusParameters, ContractId, PanicReason, Receipt, ScriptExecutionResult,
+};
+use fuel_types::bytes::padded_len_usize;
+use fuels_core::{
+    codec::{ABIDecoder, DecoderConfig, Encoding, LogDecoder},
+    offsets::base_offset_script,
+    types::{errors::Result, param_types::ParamType, transaction::ScriptTransaction, Token},
+};
+use itertools::Itertools;
+
+use crate::contract::{functions_from_abi, DynamicFunction};
+
+/// The calls made by a transaction, reconstructed from its receipts.
+///
+/// The root frame is the script. Every `Call` receipt opens a nested frame that collects the
+/// receipts of the called contract until it returns, panics or reverts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallTrace {
+    pub root: CallFrame,
+    /// Taken from the `ScriptResult` receipt.
+    pub gas_used: Option<u64>,
+    pub result: Option<ScriptExecutionResult>,
+}
+
+/// A contract call, or the script at the root of a [`CallTrace`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallFrame {
+    /// The called contract, or the zeroed id for the script.
+    pub contract_id: ContractId,
+    /// The function called, if the ABI of the contract is known.
+    pub function: Option<String>,
+    /// The `param1` of the `Call` receipt: the function selector in the legacy encoding, a
+    /// pointer to it in the experimental one.
+    pub selector: u64,
+    /// The arguments of the call, if the ABI of the contract and the script data of the
+    /// transaction are known.
+    pub arguments: Option<Vec<Token>>,
+    pub amount: u64,
+    pub asset_id: AssetId,
+    pub gas_forwarded: u64,
+    /// The gas used by the frame. Receipts don't record the gas left when a contract returns, so
+    /// it is only known for the script, and always `None` for contract calls.
+    pub gas_used: Option<u64>,
+    pub events: Vec<TraceEvent>,
+    /// `None` if the frame was still running when the transaction ended, e.g. because one of
+    /// the contracts it called panicked.
+    pub exit: Option<FrameExit>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceEvent {
+    Call(CallFrame),
+    /// A log, formatted by the `LogDecoder` if it knows its type.
+    Log(String),
+    Transfer {
+        to: ContractId,
+        amount: u64,
+        asset_id: AssetId,
+    },
+    TransferOut {
+        to: Address,
+        amount: u64,
+        asset_id: AssetId,
+    },
+    Mint {
+        amount: u64,
+    },
+    Burn {
+        amount: u64,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrameExit {
+    /// A return with `Return` or `ReturnData`. `decoded` is set if the ABI of the function is
+    /// known and `data` could be decoded according to it.
+    Return {
+        data: Vec<u8>,
+        decoded: Option<Token>,
+    },
+    Panic(PanicReason),
+    Revert(u64),
+}
+
+/// Builds [`CallTrace`]s, decoding logs, arguments and return values with the given decoders.
+#[derive(Debug, Clone, Default)]
+pub struct CallTraceBuilder {
+    log_decoder: LogDecoder,
+    functions: HashMap<ContractId, HashMap<String, DynamicFunction>>,
+    decoder_config: DecoderConfig,
+    script_data: Option<ScriptData>,
+}
+
+/// The script data of the traced transaction and the address it is loaded at in the VM.
+#[derive(Debug, Clone)]
+struct ScriptData {
+    bytes: Vec<u8>,
+    address: usize,
+}
+
+impl CallTraceBuilder {
+    pub fn with_log_decoder(mut self, log_decoder: LogDecoder) -> Self {
+        self.log_decoder = log_decoder;
+        self
+    }
+
+    /// Names the functions of `contract_id` and decodes their return values using the contract's
+    /// JSON ABI.
+    pub fn with_contract_abi(
+        mut self,
+        contract_id: impl Into<ContractId>,
+        json_abi: &str,
+    ) -> Result<Self> {
+        self.functions
+            .insert(contract_id.into(), functions_from_abi(json_abi)?);
+
+        Ok(self)
+    }
+
+    /// Decodes the arguments of the calls from the script data of `tx`, the transaction that made
+    /// them. The functions called in the experimental encoding are only named with it, as their
+    /// selector is passed by pointer.
+    pub fn with_script_transaction(
+        mut self,
+        tx: &ScriptTransaction,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Self {
+        self.script_data = Some(ScriptData {
+            bytes: tx.script_data().clone(),
+            address: base_offset_script(consensus_parameters) + padded_len_usize(tx.script().len()),
+        });
+        self
+    }
+
+    pub fn with_decoder_config(mut self, decoder_config: DecoderConfig) -> Self {
+        self.decoder_config = decoder_config;
+        self
+    }
+
+    pub fn build(&self, receipts: &[Receipt]) -> CallTrace {
+        // every frame is kept alongside the function it runs, if known
+        let mut stack = vec![(CallFrame::script(), None)];
+        let mut gas_used = None;
+        let mut result = None;
+
+        for receipt in receipts {
+            let (frame, function) = stack.last_mut().expect("the script frame is never popped");
+
+            match receipt {
+                Receipt::Call {
+                    to,
+                    amount,
+                    asset_id,
+                    gas,
+                    param1,
+                    param2,
+                    ..
+                } => {
+                    let function = self.function(to, *param1);
+                    let frame = CallFrame {
+                        contract_id: *to,
+                        function: function.map(|function| function.name.clone()),
+                        selector: *param1,
+                        arguments: function.and_then(|function| self.arguments(function, *param2)),
+                        amount: *amount,
+                        asset_id: *asset_id,
+                        gas_forwarded: *gas,
+                        gas_used: None,
+                        events: vec![],
+                        exit: None,
+                    };
+                    stack.push((frame, function));
+                }
+                Receipt::Return { val, .. } => {
+                    let data = val.to_be_bytes().to_vec();
+                    frame.exit = Some(self.return_exit(*function, data));
+                    Self::close_frame(&mut stack);
+                }
+                Receipt::ReturnData { data, .. } => {
+                    let data = data.clone().unwrap_or_default();
+                    frame.exit = Some(self.return_exit(*function, data));
+                    Self::close_frame(&mut stack);
+                }
+                Receipt::Panic { reason, .. } => {
+                    frame.exit = Some(FrameExit::Panic(*reason.reason()));
+                }
+                Receipt::Revert { ra, .. } => {
+                    frame.exit = Some(FrameExit::Revert(*ra));
+                }
+                Receipt::Log { .. } | Receipt::LogData { .. } => {
+                    frame.events.push(TraceEvent::Log(self.format_log(receipt)));
+                }
+                Receipt::Transfer {
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => frame.events.push(TraceEvent::Transfer {
+                    to: *to,
+                    amount: *amount,
+                    asset_id: *asset_id,
+                }),
+                Receipt::TransferOut {
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => frame.events.push(TraceEvent::TransferOut {
+                    to: *to,
+                    amount: *amount,
+                    asset_id: *asset_id,
+                }),
+                Receipt::Mint { val, .. } => frame.events.push(TraceEvent::Mint { amount: *val }),
+                Receipt::Burn { val, .. } => frame.events.push(TraceEvent::Burn { amount: *val }),
+                Receipt::ScriptResult {
+                    result: script_result,
+                    gas_used: script_gas_used,
+                } => {
+                    result = Some(*script_result);
+                    gas_used = Some(*script_gas_used);
+                }
+                Receipt::MessageOut { .. } => {}
+            }
+        }
+
+        // frames left open were unwound by a panic or revert further down
+        while stack.len() > 1 {
+            Self::close_frame(&mut stack);
+        }
+
+        let (mut root, _) = stack.pop().expect("the script frame is never popped");
+        root.gas_used = gas_used;
+
+        CallTrace {
+            root,
+            gas_used,
+            result,
+        }
+    }
+
+    /// The function of `contract_id` called with `param1` in the `Call` receipt.
+    fn function(&self, contract_id: &ContractId, param1: u64) -> Option<&DynamicFunction> {
+        let functions = self.functions.get(contract_id)?;
+
+        match self.decoder_config.encoding {
+            Encoding::Legacy => functions
+                .values()
+                .find(|function| function.selector() == param1),
+            // `param1` points to the encoded name of the function
+            Encoding::Experimental => {
+                let selector = self.script_data_at(param1)?;
+                let len = u64::from_be_bytes(selector.get(..8)?.try_into().ok()?);
+                let name = selector.get(8..8usize.checked_add(usize::try_from(len).ok()?)?)?;
+
+                functions.get(std::str::from_utf8(name).ok()?)
+            }
+        }
+    }
+
+    /// Decodes the arguments of a call to `function` from `param2` of the `Call` receipt.
+    fn arguments(&self, function: &DynamicFunction, param2: u64) -> Option<Vec<Token>> {
+        let decoder = ABIDecoder::new(self.decoder_config);
+
+        match self.decoder_config.encoding {
+            // the heap data of the arguments is only referenced by pointers
+            Encoding::Legacy
+                if function
+                    .inputs
+                    .iter()
+                    .any(|input| input.is_extra_receipt_needed(false)) =>
+            {
+                None
+            }
+            // a single argument fitting in a word is passed by value
+            Encoding::Legacy if !passes_arguments_by_pointer(&function.inputs) => decoder
+                .decode_multiple(&function.inputs, &param2.to_be_bytes())
+                .ok(),
+            _ => decoder
+                .decode_multiple(&function.inputs, self.script_data_at(param2)?)
+                .ok(),
+        }
+    }
+
+    /// The script data starting at the VM memory address `address`.
+    fn script_data_at(&self, address: u64) -> Option<&[u8]> {
+        let script_data = self.script_data.as_ref()?;
+        let start = usize::try_from(address)
+            .ok()?
+            .checked_sub(script_data.address)?;
+
+        script_data.bytes.get(start..)
+    }
+
+    fn return_exit(&self, function: Option<&DynamicFunction>, data: Vec<u8>) -> FrameExit {
+        let decoded = function
+            .filter(|function| {
+                self.decoder_config.encoding == Encoding::Experimental
+                    || !function.output.is_extra_receipt_needed(true)
+            })
+            .and_then(|function| self.decode(&function.output, &data));
+
+        FrameExit::Return { data, decoded }
+    }
+
+    fn decode(&self, param_type: &ParamType, data: &[u8]) -> Option<Token> {
+        ABIDecoder::new(self.decoder_config)
+            .decode(param_type, data)
+            .ok()
+    }
+
+    fn format_log(&self, receipt: &Receipt) -> String {
+        let decoded = self
+            .log_decoder
+            .decode_logs(std::slice::from_ref(receipt))
+            .results
+            .pop();
+
+        match (decoded, receipt) {
+            (Some(Ok(log)), _) => log,
+            (_, Receipt::Log { ra, rb, .. }) => format!("{ra} (log id {rb})"),
+            (_, Receipt::LogData { data, rb, .. }) => format!(
+                "0x{} (log id {rb})",
+                hex::encode(data.as_deref().unwrap_or_default())
+            ),
+            _ => unreachable!("only log receipts are formatted"),
+        }
+    }
+
+    /// Moves the innermost frame into the events of its caller. The script frame stays.
+    fn close_frame(stack: &mut Vec<(CallFrame, Option<&DynamicFunction>)>) {
+        if stack.len() > 1 {
+            let (frame, _) = stack.pop().expect("checked above");
+            stack
+                .last_mut()
+                .expect("checked above")
+                .0
+                .events
+                .push(TraceEvent::Call(frame));
+        }
+    }
+}
+
+// Mirrors how the arguments of legacy contract calls are passed: by value if there is only one
+// and it fits in a word, by pointer otherwise
+fn passes_arguments_by_pointer(inputs: &[ParamType]) -> bool {
+    inputs.len() > 1
+        || inputs.iter().any(|input| {
+            !matches!(
+                input,
+                ParamType::Unit
+                    | ParamType::Bool
+                    | ParamType::U8
+                    | ParamType::U16
+                    | ParamType::U32
+                    | ParamType::U64
+            )
+        })
+}
+
+impl CallTrace {
+    /// Builds a trace without decoding any logs or return values.
+    pub fn from_receipts(receipts: &[Receipt]) -> Self {
+        CallTraceBuilder::default().build(receipts)
+    }
+
+    /// Returns the frames in the order they were called, the script first.
+    pub fn frames(&self) -> Vec<&CallFrame> {
+        let mut frames = vec![];
+        let mut pending = vec![&self.root];
+
+        while let Some(frame) = pending.pop() {
+            frames.push(frame);
+            pending.extend(frame.events.iter().rev().filter_map(|event| match event {
+                TraceEvent::Call(frame) => Some(frame),
+                _ => None,
+            }));
+        }
+
+        frames
+    }
+}
+
+impl CallFrame {
+    fn script() -> Self {
+        Self {
+            contract_id: ContractId::zeroed(),
+            function: None,
+            selector: 0,
+            arguments: None,
+            amount: 0,
+            asset_id: AssetId::zeroed(),
+            gas_forwarded: 0,
+            gas_used: None,
+            events: vec![],
+            exit: None,
+        }
+    }
+
+    fn is_script(&self) -> bool {
+        self.contract_id == ContractId::zeroed()
+    }
+
+    fn fmt_indented(&self, f: &mut Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+
+        if self.is_script() {
+            writeln!(f, "{indent}script")?;
+        } else {
+            let mut function = match &self.function {
+                Some(name) => name.clone(),
+                None => format!("{:#018x}", self.selector),
+            };
+            if let Some(arguments) = &self.arguments {
+                function.push_str(&format!("({})", arguments.iter().join(", ")));
+            }
+            write!(
+                f,
+                "{indent}call {}::{function} (gas forwarded: {}",
+                self.contract_id, self.gas_forwarded
+            )?;
+            if self.amount > 0 {
+                write!(f, ", amount: {} of {}", self.amount, self.asset_id)?;
+            }
+            writeln!(f, ")")?;
+        }
+
+        let inner_indent = "  ".repeat(depth + 1);
+        for event in &self.events {
+            match event {
+                TraceEvent::Call(frame) => frame.fmt_indented(f, depth + 1)?,
+                TraceEvent::Log(log) => writeln!(f, "{inner_indent}log: {log}")?,
+                TraceEvent::Transfer {
+                    to,
+                    amount,
+                    asset_id,
+                } => writeln!(
+                    f,
+                    "{inner_indent}transfer: {amount} of {asset_id} to contract {to}"
+                )?,
+                TraceEvent::TransferOut {
+                    to,
+                    amount,
+                    asset_id,
+                } => writeln!(
+                    f,
+                    "{inner_indent}transfer: {amount} of {asset_id} to address {to}"
+                )?,
+                TraceEvent::Mint { amount } => writeln!(f, "{inner_indent}mint: {amount}")?,
+                TraceEvent::Burn { amount } => writeln!(f, "{inner_indent}burn: {amount}")?,
+            }
+        }
+
+        match &self.exit {
+            Some(FrameExit::Return {
+                decoded: Some(token),
+                ..
+            }) => writeln!(f, "{inner_indent}return: {token}"),
+            Some(FrameExit::Return { data, .. }) => {
+                writeln!(f, "{inner_indent}return: 0x{}", hex::encode(data))
+            }
+            Some(FrameExit::Panic(reason)) => writeln!(f, "{inner_indent}panic: {reason:?}"),
+            Some(FrameExit::Revert(code)) => writeln!(f, "{inner_indent}revert: {code:#x}"),
+            None => writeln!(f, "{inner_indent}unwound"),
+        }
+    }
+}
+
+impl Display for CallTrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.root.fmt_indented(f, 0)?;
+
+        if let (Some(result), Some(gas_used)) = (&self.result, self.gas_used) {
+            writeln!(f, "result: {result:?}, gas used: {gas_used}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{policies::Policies, PanicInstruction, Transaction};
+    use fuels_core::codec::ABIEncoder;
+
+    use super::*;
+
+    const ABI: &str = r#"{
+        "types": [
+            { "typeId": 0, "type": "u64", "components": null, "typeParameters": null },
+            { "typeId": 1, "type": "bool", "components": null, "typeParameters": null }
+        ],
+        "functions": [
+            {
+                "inputs": [{ "name": "value", "type": 0, "typeArguments": null }],
+                "name": "double",
+                "output": { "name": "", "type": 0, "typeArguments": null },
+                "attributes": null
+            },
+            {
+                "inputs": [
+                    { "name": "value", "type": 0, "typeArguments": null },
+                    { "name": "flag", "type": 1, "typeArguments": null }
+                ],
+                "name": "pick",
+                "output": { "name": "", "type": 0, "typeArguments": null },
+                "attributes": null
+            }
+        ],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    fn contract(byte: u8) -> ContractId {
+        ContractId::new([byte; 32])
+    }
+
+    fn call(to: ContractId, selector: u64) -> Receipt {
+        call_with_arguments(to, selector, 0)
+    }
+
+    fn call_with_arguments(to: ContractId, param1: u64, param2: u64) -> Receipt {
+        Receipt::call(
+            ContractId::zeroed(),
+            to,
+            0,
+            AssetId::zeroed(),
+            1000,
+            param1,
+            param2,
+            0,
+            0,
+        )
+    }
+
+    /// A transaction with `script_data`, and the address the script data is loaded at.
+    fn script_transaction(script_data: Vec<u8>) -> (ScriptTransaction, u64) {
+        let script = vec![0; 4];
+        let address =
+            base_offset_script(&ConsensusParameters::default()) + padded_len_usize(script.len());
+        let tx = Transaction::script(
+            0,
+            script,
+            script_data,
+            Policies::default(),
+            vec![],
+            vec![],
+            vec![],
+        );
+
+        (tx.into(), address as u64)
+    }
+
+    fn ret(id: ContractId, val: u64) -> Receipt {
+        Receipt::ret(id, val, 0, 0)
+    }
+
+    fn log(id: ContractId, val: u64) -> Receipt {
+        Receipt::log(id, val, 0, 0, 0, 0, 0)
+    }
+
+    fn script_result(result: ScriptExecutionResult) -> Receipt {
+        Receipt::script_result(result, 500)
+    }
+
+    #[test]
+    fn frames_are_nested_by_call_and_return() {
+        let receipts = [
+            call(contract(1), 1),
+            log(contract(1), 7),
+            call(contract(2), 2),
+            ret(contract(2), 2),
+            ret(contract(1), 1),
+            ret(ContractId::zeroed(), 0),
+            script_result(ScriptExecutionResult::Success),
+        ];
+
+        let trace = CallTrace::from_receipts(&receipts);
+
+        let contract_ids: Vec<_> = trace
+            .frames()
+            .iter()
+            .map(|frame| frame.contract_id)
+            .collect();
+        assert_eq!(
+            contract_ids,
+            [ContractId::zeroed(), contract(1), contract(2)]
+        );
+
+        let outer_call = trace.frames()[1];
+        assert_eq!(outer_call.events.len(), 2);
+        assert_eq!(
+            outer_call.events[0],
+            TraceEvent::Log("7 (log id 0)".to_string())
+        );
+        assert_eq!(
+            outer_call.exit,
+            Some(FrameExit::Return {
+                data: 1u64.to_be_bytes().to_vec(),
+                decoded: None
+            })
+        );
+        assert_eq!(trace.gas_used, Some(500));
+        assert_eq!(trace.root.gas_used, Some(500));
+        assert_eq!(outer_call.gas_used, None);
+        assert_eq!(trace.result, Some(ScriptExecutionResult::Success));
+    }
+
+    #[test]
+    fn frames_of_a_panic_are_unwound() {
+        let receipts = [
+            call(contract(1), 1),
+            call(contract(2), 2),
+            Receipt::panic(
+                contract(2),
+                PanicInstruction::error(PanicReason::NotEnoughBalance, 0),
+                0,
+                0,
+            ),
+            script_result(ScriptExecutionResult::Panic),
+        ];
+
+        let trace = CallTrace::from_receipts(&receipts);
+
+        let exits: Vec<_> = trace.frames().iter().map(|frame| &frame.exit).collect();
+        assert_eq!(
+            exits,
+            [
+                &None,
+                &None,
+                &Some(FrameExit::Panic(PanicReason::NotEnoughBalance))
+            ]
+        );
+    }
+
+    #[test]
+    fn abi_names_functions_and_decodes_return_values() -> Result<()> {
+        let function = functions_from_abi(ABI)?.remove("double").unwrap();
+        let receipts = [
+            call(contract(1), function.selector()),
+            ret(contract(1), 42),
+            ret(ContractId::zeroed(), 0),
+        ];
+
+        let trace = CallTraceBuilder::default()
+            .with_contract_abi(contract(1), ABI)?
+            .build(&receipts);
+
+        let call = trace.frames()[1];
+        assert_eq!(call.function.as_deref(), Some("double"));
+        assert_eq!(
+            call.exit,
+            Some(FrameExit::Return {
+                data: 42u64.to_be_bytes().to_vec(),
+                decoded: Some(Token::U64(42))
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn arguments_are_decoded_from_the_script_data() -> Result<()> {
+        let selector = |name: &str| {
+            let functions = functions_from_abi(ABI).unwrap();
+            functions[name].selector()
+        };
+        let arguments = ABIEncoder::default()
+            .encode(&[Token::U64(42), Token::Bool(true)])?
+            .resolve(0);
+        let (tx, address) = script_transaction([[0; 8].as_slice(), &arguments].concat());
+        let receipts = [
+            // a single word is passed by value
+            call_with_arguments(contract(1), selector("double"), 21),
+            ret(contract(1), 42),
+            call_with_arguments(contract(1), selector("pick"), address + 8),
+            ret(contract(1), 42),
+        ];
+
+        let trace = CallTraceBuilder::default()
+            .with_contract_abi(contract(1), ABI)?
+            .with_script_transaction(&tx, &ConsensusParameters::default())
+            .build(&receipts);
+
+        let arguments: Vec<_> = trace.frames()[1..]
+            .iter()
+            .map(|frame| frame.arguments.clone())
+            .collect();
+        assert_eq!(
+            arguments,
+            [
+                Some(vec![Token::U64(21)]),
+                Some(vec![Token::U64(42), Token::Bool(true)])
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn experimental_calls_are_named_from_the_script_data() -> Result<()> {
+        let script_data = [
+            4u64.to_be_bytes().as_slice(),
+            b"pick",
+            &42u64.to_be_bytes(),
+            &[1],
+        ]
+        .concat();
+        let (tx, address) = script_transaction(script_data);
+        let receipts = [
+            call_with_arguments(contract(1), address, address + 12),
+            ret(contract(1), 42),
+        ];
+
+        let trace = CallTraceBuilder::default()
+            .with_contract_abi(contract(1), ABI)?
+            .with_script_transaction(&tx, &ConsensusParameters::default())
+            .with_decoder_config(DecoderConfig {
+                encoding: Encoding::Experimental,
+                ..Default::default()
+            })
+            .build(&receipts);
+
+        let call = trace.frames()[1];
+        assert_eq!(call.function.as_deref(), Some("pick"));
+        assert_eq!(
+            call.arguments,
+            Some(vec![Token::U64(42), Token::Bool(true)])
+        );
+        assert_eq!(
+            call.exit,
+            Some(FrameExit::Return {
+                data: 42u64.to_be_bytes().to_vec(),
+                decoded: Some(Token::U64(42))
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn trace_is_rendered_as_an_indented_report() -> Result<()> {
+        let function = functions_from_abi(ABI)?.remove("double").unwrap();
+        let receipts = [
+            call_with_arguments(contract(1), function.selector(), 21),
+            log(contract(1), 7),
+            call(contract(2), 3),
+            Receipt::revert(contract(2), 0xbad, 0, 0),
+            script_result(ScriptExecutionResult::Revert),
+        ];
+
+        let report = CallTraceBuilder::default()
+            .with_contract_abi(contract(1), ABI)?
+            .build(&receipts)
+            .to_string();
+
+        let expected = format!(
+            "script\n  \
+               call {}::double(U64(21)) (gas forwarded: 1000)\n    \
+                 log: 7 (log id 0)\n    \
+                 call {}::0x0000000000000003 (gas forwarded: 1000)\n      \
+                   revert: 0xbad\n    \
+                 unwound\n  \
+               unwound\n\
+             result: Revert, gas used: 500\n",
+            contract(1),
+            contract(2)
+        );
+        assert_eq!(report, expected);
+
+        Ok(())
+    }
+}
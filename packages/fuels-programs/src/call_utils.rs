@@ -4,8 +4,9 @@ use fuel_abi_types::error_codes::FAILED_TRANSFER_TO_ADDRESS_SIGNAL;
 use fuel_asm::{op, RegId};
 use fuel_tx::{AssetId, Bytes32, ContractId, Output, PanicReason, Receipt, TxPointer, UtxoId};
 use fuel_types::{Address, Word};
-use fuels_accounts::Account;
+use fuels_accounts::{Account, FeePayer};
 use fuels_core::{
+    codec::Encoding,
     constants::WORD_SIZE,
     error,
     offsets::call_script_data_offset,
@@ -145,6 +146,7 @@ pub(crate) async fn build_tx_from_contract_calls(
     calls: &[ContractCall],
     tx_policies: TxPolicies,
     account: &impl Account,
+    fee_payer: Option<&dyn FeePayer>,
 ) -> Result<ScriptTransaction> {
     let mut tb = transaction_builder_from_contract_calls(calls, tx_policies, account).await?;
 
@@ -156,7 +158,10 @@ pub(crate) async fn build_tx_from_contract_calls(
         .unwrap_or_default();
 
     account.add_witnesses(&mut tb)?;
-    account.adjust_for_fee(&mut tb, used_base_amount).await?;
+    match fee_payer {
+        Some(fee_payer) => fee_payer.pay_fee(&mut tb, used_base_amount).await?,
+        None => account.adjust_for_fee(&mut tb, used_base_amount).await?,
+    }
 
     tb.build(account.try_provider()?).await
 }
@@ -177,7 +182,7 @@ fn compute_calls_instructions_len(calls: &[ContractCall]) -> Result<usize> {
                 call_opcode_params.gas_forwarded_offset = Some(0);
             }
 
-            get_single_call_instructions(&call_opcode_params, &c.output_param)
+            get_single_call_instructions(&call_opcode_params, &c.output_param, c.encoding)
                 .map(|instructions| instructions.len())
         })
         .process_results(|c| c.sum())
@@ -236,7 +241,9 @@ pub(crate) fn get_instructions(
     calls
         .iter()
         .zip(&offsets)
-        .map(|(call, offset)| get_single_call_instructions(offset, &call.output_param))
+        .map(|(call, offset)| {
+            get_single_call_instructions(offset, &call.output_param, call.encoding)
+        })
         .process_results(|iter| iter.flatten().collect::<Vec<_>>())
         .map(|mut bytes| {
             bytes.extend(op::ret(RegId::ONE).to_bytes());
@@ -252,6 +259,12 @@ pub(crate) fn get_instructions(
 /// 5. Function selector `(1 * `[`WORD_SIZE`]`)`
 /// 6. Calldata offset (optional) `(1 * `[`WORD_SIZE`]`)`
 /// 7. Encoded arguments (optional) (variable length)
+///
+/// With the experimental encoding, items 5. to 7. are instead:
+/// 5. Function selector offset `(1 * `[`WORD_SIZE`]`)`
+/// 6. Calldata offset `(1 * `[`WORD_SIZE`]`)`
+/// 7. Encoded function selector (variable length)
+/// 8. Encoded arguments (variable length)
 pub(crate) fn build_script_data_from_contract_calls(
     calls: &[ContractCall],
     data_offset: usize,
@@ -277,7 +290,6 @@ pub(crate) fn build_script_data_from_contract_calls(
             .unwrap_or_default();
 
         script_data.extend(call.contract_id.hash().as_ref());
-        script_data.extend(call.encoded_selector);
 
         let call_param_offsets = CallOpcodeParamsOffset {
             amount_offset: segment_offset,
@@ -287,25 +299,44 @@ pub(crate) fn build_script_data_from_contract_calls(
         };
         param_offsets.push(call_param_offsets);
 
-        // If the method call takes custom inputs or has more than
-        // one argument, we need to calculate the `call_data_offset`,
-        // which points to where the data for the custom types start in the
-        // transaction. If it doesn't take any custom inputs, this isn't necessary.
-        let encoded_args_start_offset = if call.compute_custom_input_offset {
-            // Custom inputs are stored after the previously added parameters,
-            // including custom_input_offset
-            let custom_input_offset = segment_offset
-                + WORD_SIZE // amount size
-                + AssetId::LEN
-                + gas_forwarded_size
-                + ContractId::LEN
-                + WORD_SIZE // encoded_selector size
-                + WORD_SIZE; // custom_input_offset size
-            script_data.extend((custom_input_offset as Word).to_be_bytes());
-
-            custom_input_offset
-        } else {
-            segment_offset
+        let call_data_end_offset = segment_offset
+            + WORD_SIZE // amount size
+            + AssetId::LEN
+            + gas_forwarded_size
+            + ContractId::LEN
+            + WORD_SIZE // encoded_selector or encoded_selector_offset size
+            + WORD_SIZE; // custom_input_offset size
+
+        let encoded_args_start_offset = match call.encoding {
+            Encoding::Legacy => {
+                script_data.extend(&call.encoded_selector);
+
+                // If the method call takes custom inputs or has more than
+                // one argument, we need to calculate the `call_data_offset`,
+                // which points to where the data for the custom types start in the
+                // transaction. If it doesn't take any custom inputs, this isn't necessary.
+                if call.compute_custom_input_offset {
+                    // Custom inputs are stored after the previously added parameters,
+                    // including custom_input_offset
+                    let custom_input_offset = call_data_end_offset;
+                    script_data.extend((custom_input_offset as Word).to_be_bytes());
+
+                    custom_input_offset
+                } else {
+                    segment_offset
+                }
+            }
+            Encoding::Experimental => {
+                // Both the selector and the arguments are passed by pointer, they are stored
+                // after the call data
+                let encoded_selector_offset = call_data_end_offset;
+                let custom_input_offset = encoded_selector_offset + call.encoded_selector.len();
+                script_data.extend((encoded_selector_offset as Word).to_be_bytes());
+                script_data.extend((custom_input_offset as Word).to_be_bytes());
+                script_data.extend(&call.encoded_selector);
+
+                custom_input_offset
+            }
         };
 
         let bytes = call
@@ -337,6 +368,7 @@ pub(crate) fn build_script_data_from_contract_calls(
 pub(crate) fn get_single_call_instructions(
     offsets: &CallOpcodeParamsOffset,
     output_param_type: &ParamType,
+    encoding: Encoding,
 ) -> Result<Vec<u8>> {
     let call_data_offset = offsets
         .call_data_offset
@@ -375,7 +407,10 @@ pub(crate) fn get_single_call_instructions(
         None => instructions.push(op::call(0x10, 0x11, 0x12, RegId::CGAS)),
     };
 
-    instructions.extend(extract_heap_data(output_param_type)?);
+    // With the experimental encoding the heap data is part of the returned data
+    if encoding == Encoding::Legacy {
+        instructions.extend(extract_heap_data(output_param_type)?);
+    }
 
     #[allow(clippy::iter_cloned_collect)]
     Ok(instructions.into_iter().collect::<Vec<u8>>())
@@ -591,7 +626,7 @@ mod test {
 
     use fuels_accounts::wallet::WalletUnlocked;
     use fuels_core::{
-        codec::ABIEncoder,
+        codec::{encode_fn_selector, ABIEncoder, EncoderConfig},
         types::{
             bech32::Bech32ContractId,
             coin::{Coin, CoinStatus},
@@ -609,7 +644,7 @@ mod test {
             ContractCall {
                 contract_id: random_bech32_contract_id(),
                 encoded_args: Ok(Default::default()),
-                encoded_selector: [0; 8],
+                encoded_selector: vec![0; 8],
                 call_parameters: Default::default(),
                 compute_custom_input_offset: false,
                 variable_outputs: vec![],
@@ -617,6 +652,7 @@ mod test {
                 output_param: ParamType::Unit,
                 is_payable: false,
                 custom_assets: Default::default(),
+                encoding: Encoding::Legacy,
             }
         }
     }
@@ -658,7 +694,7 @@ mod test {
         let calls: Vec<ContractCall> = (0..NUM_CALLS)
             .map(|i| ContractCall {
                 contract_id: contract_ids[i].clone(),
-                encoded_selector: selectors[i],
+                encoded_selector: selectors[i].to_vec(),
                 encoded_args: Ok(args[i].clone()),
                 call_parameters: CallParameters::new(i as u64, asset_ids[i], i as u64),
                 compute_custom_input_offset: i == 1,
@@ -667,6 +703,7 @@ mod test {
                 output_param: ParamType::Unit,
                 is_payable: false,
                 custom_assets: Default::default(),
+                encoding: Encoding::Legacy,
             })
             .collect();
 
@@ -726,6 +763,45 @@ mod test {
         assert_eq!(custom_input, args[1].resolve(0));
     }
 
+    #[test]
+    fn experimental_encoding_passes_selector_and_arguments_by_pointer() {
+        let selector = encode_fn_selector("some_fun", &[], Encoding::Experimental);
+        let args = ABIEncoder::new(EncoderConfig {
+            encoding: Encoding::Experimental,
+            ..Default::default()
+        })
+        .encode(&[Token::U8(1), Token::Bool(true)])
+        .unwrap();
+        let call = ContractCall {
+            encoded_selector: selector.clone(),
+            encoded_args: Ok(args),
+            compute_custom_input_offset: true,
+            encoding: Encoding::Experimental,
+            ..ContractCall::new_with_random_id()
+        };
+        let data_offset = 100;
+
+        let (script_data, param_offsets) =
+            build_script_data_from_contract_calls(&[call], data_offset).unwrap();
+
+        let read_word = |offset: usize| {
+            let offset = offset - data_offset;
+            Word::from_be_bytes(script_data[offset..offset + WORD_SIZE].try_into().unwrap())
+        };
+        let read_bytes = |offset: usize, len: usize| {
+            let offset = offset - data_offset;
+            script_data[offset..offset + len].to_vec()
+        };
+
+        let param1_offset = param_offsets[0].call_data_offset + ContractId::LEN;
+        let selector_offset = read_word(param1_offset) as usize;
+        let args_offset = read_word(param1_offset + WORD_SIZE) as usize;
+
+        assert_eq!(read_bytes(selector_offset, selector.len()), selector);
+        assert_eq!(read_bytes(args_offset, 2), [1, 1]);
+        assert_eq!(args_offset + 2, data_offset + script_data.len());
+    }
+
     #[test]
     fn contract_input_present() {
         let call = ContractCall::new_with_random_id();
@@ -946,7 +1022,10 @@ mod test {
 
     mod compute_calls_instructions_len {
         use fuel_asm::Instruction;
-        use fuels_core::types::{enum_variants::EnumVariants, param_types::ParamType};
+        use fuels_core::{
+            codec::Encoding,
+            types::{enum_variants::EnumVariants, param_types::ParamType},
+        };
 
         use crate::{call_utils::compute_calls_instructions_len, contract::ContractCall};
 
@@ -996,6 +1075,15 @@ mod test {
             }
         }
 
+        #[test]
+        fn test_with_heap_type_in_experimental_encoding() {
+            let mut call = ContractCall::new_with_random_id();
+            call.output_param = ParamType::Vector(Box::new(ParamType::U8));
+            call.encoding = Encoding::Experimental;
+            let instructions_len = compute_calls_instructions_len(&[call]).unwrap();
+            assert_eq!(instructions_len, Instruction::SIZE * BASE_INSTRUCTION_COUNT);
+        }
+
         #[test]
         fn test_with_gas_offset_and_heap_type() {
             let mut call = ContractCall::new_with_random_id();
@@ -5,14 +5,15 @@ use std::{
     fs, io,
     marker::PhantomData,
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use fuel_tx::{
     AssetId, Bytes32, Contract as FuelContract, ContractId, Output, Receipt, Salt, StorageSlot,
 };
-use fuels_accounts::{provider::TransactionCost, Account};
+use fuels_accounts::{provider::TransactionCost, Account, FeePayer};
 use fuels_core::{
-    codec::{ABIEncoder, DecoderConfig, EncoderConfig, LogDecoder},
+    codec::{ABIEncoder, DecoderConfig, EncoderConfig, Encoding, LogDecoder},
     constants::{BASE_ASSET_ID, DEFAULT_CALL_PARAMS_AMOUNT},
     traits::{Parameterize, Tokenizable},
     types::{
@@ -38,6 +39,17 @@ use crate::{
     submit_response::{SubmitResponse, SubmitResponseMultiple},
 };
 
+mod dynamic;
+mod error;
+mod loader;
+mod logs;
+
+pub(crate) use dynamic::functions_from_abi;
+pub use dynamic::{DynamicContract, DynamicFunction};
+pub use error::{ContractError, RevertError};
+pub use loader::LoaderContract;
+pub use logs::{ContractEvents, ContractLogs};
+
 #[derive(Debug, Clone)]
 pub struct CallParameters {
     amount: u64,
@@ -240,7 +252,7 @@ impl LoadConfiguration {
 
 /// [`Contract`] is a struct to interface with a contract. That includes things such as
 /// compiling, deploying, and running transactions against a contract.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Contract {
     binary: Vec<u8>,
     salt: Salt,
@@ -327,6 +339,22 @@ impl Contract {
         Ok(self.contract_id.into())
     }
 
+    /// Deploys a contract too large for a single transaction by splitting it into chunks of at
+    /// most `chunk_size` bytes, see [`LoaderContract`]. Calls must go to the returned loader's
+    /// [`contract_id`](LoaderContract::contract_id) and include its
+    /// [`chunk_ids`](LoaderContract::chunk_ids) as external contracts.
+    pub async fn deploy_in_chunks(
+        self,
+        account: &impl Account,
+        tx_policies: TxPolicies,
+        chunk_size: usize,
+    ) -> Result<LoaderContract> {
+        let loader = LoaderContract::new(self, chunk_size)?;
+        loader.deploy(account, tx_policies).await?;
+
+        Ok(loader)
+    }
+
     pub fn load_from(binary_filepath: impl AsRef<Path>, config: LoadConfiguration) -> Result<Self> {
         let binary_filepath = binary_filepath.as_ref();
         validate_path_and_extension(binary_filepath, "bin")?;
@@ -415,6 +443,8 @@ pub struct ContractCall {
     pub output_param: ParamType,
     pub is_payable: bool,
     pub custom_assets: HashMap<(AssetId, Option<Bech32Address>), u64>,
+    /// The encoding the called contract was compiled with.
+    pub encoding: Encoding,
 }
 
 impl ContractCall {
@@ -465,22 +495,25 @@ impl ContractCall {
 
 #[derive(Debug)]
 #[must_use = "contract calls do nothing unless you `call` them"]
-/// Helper that handles submitting a call to a client and formatting the response
-pub struct ContractCallHandler<T: Account, D> {
+/// Helper that handles submitting a call to a client and formatting the response. `E` is the
+/// error enum reverts are decoded into by [`call_typed`](Self::call_typed), set by `abigen`.
+pub struct ContractCallHandler<T: Account, D, E = ()> {
     pub contract_call: ContractCall,
     pub tx_policies: TxPolicies,
     decoder_config: DecoderConfig,
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
+    fee_payer: Option<Arc<dyn FeePayer>>,
     pub account: T,
     pub datatype: PhantomData<D>,
     pub log_decoder: LogDecoder,
+    revert_error: PhantomData<E>,
 }
 
-impl<T, D> ContractCallHandler<T, D>
+impl<T, D, E> ContractCallHandler<T, D, E>
 where
     T: Account,
-    D: Tokenizable + Parameterize + Debug,
+    D: Tokenizable + Debug,
 {
     /// Sets external contracts as dependencies to this contract's call.
     /// Effectively, this will be used to create [`fuel_tx::Input::Contract`]/[`fuel_tx::Output::Contract`]
@@ -554,6 +587,17 @@ where
         self
     }
 
+    /// Sets an account paying the transaction fee instead of the account making the call, e.g.
+    /// for users holding no base asset. Both accounts sign the transaction.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    /// ```ignore
+    /// my_contract_instance.my_method(...).with_fee_payer(sponsor_wallet).call()
+    /// ```
+    pub fn with_fee_payer(mut self, fee_payer: impl FeePayer + 'static) -> Self {
+        self.fee_payer = Some(Arc::new(fee_payer));
+        self
+    }
+
     pub fn with_decoder_config(mut self, decoder_config: DecoderConfig) -> Self {
         self.decoder_config = decoder_config;
         self.log_decoder.set_decoder_config(decoder_config);
@@ -590,6 +634,7 @@ where
             std::slice::from_ref(&self.contract_call),
             self.tx_policies,
             &self.account,
+            self.fee_payer.as_deref(),
         )
         .await
     }
@@ -599,16 +644,6 @@ where
         self.call_or_simulate(false).await
     }
 
-    pub async fn submit(mut self) -> Result<SubmitResponse<T, D>> {
-        let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
-
-        let tx_id = provider.send_transaction(tx.clone()).await?;
-        self.cached_tx_id = Some(tx_id);
-
-        Ok(SubmitResponse::new(tx_id, self))
-    }
-
     /// Call a contract's method on the node, in a simulated manner, meaning the state of the
     /// blockchain is *not* modified but simulated.
     pub async fn simulate(&mut self) -> Result<FuelCallResponse<D>> {
@@ -668,13 +703,66 @@ where
     }
 }
 
-impl<T: Account, D> sealed::Sealed for ContractCallHandler<T, D> {}
+impl<T, D, E> ContractCallHandler<T, D, E>
+where
+    T: Account,
+    D: Tokenizable + Debug,
+    E: RevertError,
+{
+    /// Like [`call`](Self::call), but reverts of the contract through `require` come back as
+    /// [`ContractError::Revert`], decoded into the error enum `abigen` generated for the
+    /// contract, e.g. `MyContractRevertError` for `MyContract`.
+    pub async fn call_typed(self) -> std::result::Result<FuelCallResponse<D>, ContractError<E>> {
+        let contract_id = (&self.contract_call.contract_id).into();
+        let decoder_config = self.decoder_config;
+
+        self.call()
+            .await
+            .map_err(|error| ContractError::from_error(error, &contract_id, decoder_config))
+    }
+}
+
+impl<T, D, E> ContractCallHandler<T, D, E>
+where
+    T: Account,
+    D: Tokenizable + Debug,
+{
+    pub async fn submit(mut self) -> Result<SubmitResponse<T, D>> {
+        let tx = self.build_tx().await?;
+        let provider = self.account.try_provider()?;
+
+        let tx_id = provider.send_transaction(tx.clone()).await?;
+        self.cached_tx_id = Some(tx_id);
+
+        Ok(SubmitResponse::new(tx_id, self.without_revert_error()))
+    }
+}
+
+impl<T: Account, D, E> ContractCallHandler<T, D, E> {
+    // submitted calls are awaited with `response()`, which doesn't decode reverts
+    fn without_revert_error(self) -> ContractCallHandler<T, D> {
+        ContractCallHandler {
+            contract_call: self.contract_call,
+            tx_policies: self.tx_policies,
+            decoder_config: self.decoder_config,
+            cached_tx_id: self.cached_tx_id,
+            fee_payer: self.fee_payer,
+            account: self.account,
+            datatype: PhantomData,
+            log_decoder: self.log_decoder,
+            revert_error: PhantomData,
+        }
+    }
+}
+
+impl<T: Account, D, E> sealed::Sealed for ContractCallHandler<T, D, E> {}
 
 #[async_trait::async_trait]
-impl<T, D> TxDependencyExtension for ContractCallHandler<T, D>
+impl<T, D, E> TxDependencyExtension for ContractCallHandler<T, D, E>
 where
     T: Account,
-    D: Tokenizable + Parameterize + Debug + Send + Sync,
+    D: Tokenizable + Debug + Send + Sync,
+    E: Send + Sync,
 {
     async fn simulate(&mut self) -> Result<()> {
         self.simulate().await?;
@@ -711,7 +799,7 @@ where
 /// For more details see `code_gen` in `fuels-core`.
 ///
 /// Note that this needs an account because the contract instance needs an account for the calls
-pub fn method_hash<D: Tokenizable + Parameterize + Debug, T: Account>(
+pub fn method_hash<D: Tokenizable + Parameterize + Debug, T: Account, E>(
     contract_id: Bech32ContractId,
     account: T,
     signature: Selector,
@@ -719,13 +807,41 @@ pub fn method_hash<D: Tokenizable + Parameterize + Debug, T: Account>(
     log_decoder: LogDecoder,
     is_payable: bool,
     encoder_config: EncoderConfig,
-) -> ContractCallHandler<T, D> {
+) -> ContractCallHandler<T, D, E> {
+    method_hash_with_output_param(
+        contract_id,
+        account,
+        signature,
+        args,
+        log_decoder,
+        is_payable,
+        encoder_config,
+        D::param_type(),
+    )
+}
+
+/// Same as [`method_hash`], but with the output type given as a [`ParamType`] instead of being
+/// derived from `D`. Used when the output type is only known at runtime.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn method_hash_with_output_param<D: Tokenizable + Debug, T: Account, E>(
+    contract_id: Bech32ContractId,
+    account: T,
+    signature: Selector,
+    args: &[Token],
+    mut log_decoder: LogDecoder,
+    is_payable: bool,
+    encoder_config: EncoderConfig,
+    output_param: ParamType,
+) -> ContractCallHandler<T, D, E> {
     let encoded_selector = signature;
 
     let tx_policies = TxPolicies::default();
     let call_parameters = CallParameters::default();
 
-    let compute_custom_input_offset = should_compute_custom_input_offset(args);
+    let encoding = encoder_config.encoding;
+    // The experimental encoding is always passed by pointer
+    let compute_custom_input_offset =
+        encoding == Encoding::Experimental || should_compute_custom_input_offset(args);
 
     let unresolved_bytes = ABIEncoder::new(encoder_config).encode(args);
     let contract_call = ContractCall {
@@ -736,19 +852,29 @@ pub fn method_hash<D: Tokenizable + Parameterize + Debug, T: Account>(
         compute_custom_input_offset,
         variable_outputs: vec![],
         external_contracts: vec![],
-        output_param: D::param_type(),
+        output_param,
         is_payable,
         custom_assets: Default::default(),
+        encoding,
+    };
+
+    // The output and the logs are decoded with the encoding the arguments were encoded with
+    let decoder_config = DecoderConfig {
+        encoding,
+        ..Default::default()
     };
+    log_decoder.set_decoder_config(decoder_config);
 
     ContractCallHandler {
         contract_call,
         tx_policies,
         cached_tx_id: None,
+        fee_payer: None,
         account,
         datatype: PhantomData,
         log_decoder,
-        decoder_config: Default::default(),
+        decoder_config,
+        revert_error: PhantomData,
     }
 }
 
@@ -787,6 +913,7 @@ pub struct MultiContractCallHandler<T: Account> {
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
     decoder_config: DecoderConfig,
+    fee_payer: Option<Arc<dyn FeePayer>>,
     pub account: T,
 }
 
@@ -799,6 +926,7 @@ impl<T: Account> MultiContractCallHandler<T> {
             account,
             log_decoder: LogDecoder::new(Default::default()),
             decoder_config: DecoderConfig::default(),
+            fee_payer: None,
         }
     }
 
@@ -810,9 +938,9 @@ impl<T: Account> MultiContractCallHandler<T> {
 
     /// Adds a contract call to be bundled in the transaction
     /// Note that this is a builder method
-    pub fn add_call(
+    pub fn add_call<E>(
         &mut self,
-        call_handler: ContractCallHandler<impl Account, impl Tokenizable>,
+        call_handler: ContractCallHandler<impl Account, impl Tokenizable, E>,
     ) -> &mut Self {
         self.log_decoder.merge(call_handler.log_decoder);
         self.contract_calls.push(call_handler.contract_call);
@@ -826,6 +954,13 @@ impl<T: Account> MultiContractCallHandler<T> {
         self
     }
 
+    /// Sets an account paying the transaction fee instead of the account making the calls.
+    /// Note that this is a builder method
+    pub fn with_fee_payer(mut self, fee_payer: impl FeePayer + 'static) -> Self {
+        self.fee_payer = Some(Arc::new(fee_payer));
+        self
+    }
+
     fn validate_contract_calls(&self) -> Result<()> {
         if self.contract_calls.is_empty() {
             return Err(error!(
@@ -880,7 +1015,13 @@ impl<T: Account> MultiContractCallHandler<T> {
     pub async fn build_tx(&self) -> Result<ScriptTransaction> {
         self.validate_contract_calls()?;
 
-        build_tx_from_contract_calls(&self.contract_calls, self.tx_policies, &self.account).await
+        build_tx_from_contract_calls(
+            &self.contract_calls,
+            self.tx_policies,
+            &self.account,
+            self.fee_payer.as_deref(),
+        )
+        .await
     }
 
     /// Call contract methods on the node, in a state-modifying manner.
@@ -0,0 +1,436 @@
+use std::{collections::HashMap, iter::zip};
+
+use fuel_abi_types::abi::program::{ProgramABI, TypeDeclaration};
+use fuels_accounts::Account;
+use fuels_core::{
+    codec::{
+        encode_fn_selector, log_formatters_lookup, resolve_fn_selector, EncoderConfig, LogDecoder,
+        LogFormatter,
+    },
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        param_types::ParamType,
+        Token,
+    },
+};
+
+use crate::contract::{method_hash_with_output_param, ContractCallHandler, SettableContract};
+
+/// A contract function resolved from a JSON ABI at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicFunction {
+    pub name: String,
+    pub inputs: Vec<ParamType>,
+    pub output: ParamType,
+    pub is_payable: bool,
+}
+
+fn parse_abi(json_abi: &str) -> Result<(ProgramABI, HashMap<usize, TypeDeclaration>)> {
+    let abi: ProgramABI = serde_json::from_str(json_abi)?;
+    let type_lookup = abi
+        .types
+        .iter()
+        .map(|decl| (decl.type_id, decl.clone()))
+        .collect();
+
+    Ok((abi, type_lookup))
+}
+
+/// Resolves the functions of a JSON ABI, keyed by their names.
+pub(crate) fn functions_from_abi(json_abi: &str) -> Result<HashMap<String, DynamicFunction>> {
+    let (abi, type_lookup) = parse_abi(json_abi)?;
+
+    abi.functions
+        .iter()
+        .map(|function| {
+            let inputs = function
+                .inputs
+                .iter()
+                .map(|input| ParamType::try_from_type_application(input, &type_lookup))
+                .collect::<Result<Vec<_>>>()?;
+            let output = ParamType::try_from_type_application(&function.output, &type_lookup)?;
+
+            let dynamic_function = DynamicFunction {
+                name: function.name.clone(),
+                inputs,
+                output,
+                is_payable: function.is_payable(),
+            };
+
+            Ok((function.name.clone(), dynamic_function))
+        })
+        .collect()
+}
+
+/// Creates a [`LogDecoder`] for the types the contract `contract_id` logs according to its JSON
+/// ABI. The logs are formatted as [`Token`]s.
+fn log_decoder_from_abi(json_abi: &str, contract_id: &Bech32ContractId) -> Result<LogDecoder> {
+    let (abi, type_lookup) = parse_abi(json_abi)?;
+
+    let log_formatters = abi
+        .logged_types
+        .unwrap_or_default()
+        .iter()
+        .map(|logged_type| {
+            let param_type =
+                ParamType::try_from_type_application(&logged_type.application, &type_lookup)?;
+
+            Ok((
+                logged_type.log_id,
+                LogFormatter::from_param_type(param_type),
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(LogDecoder::new(log_formatters_lookup(
+        log_formatters,
+        contract_id.into(),
+    )))
+}
+
+/// Whether `token` is a value of `param_type`. Only such tokens are encoded the way the contract
+/// expects.
+fn token_matches(token: &Token, param_type: &ParamType) -> bool {
+    let all_match = |tokens: &[Token], param_types: &[ParamType]| {
+        tokens.len() == param_types.len()
+            && zip(tokens, param_types).all(|(token, param_type)| token_matches(token, param_type))
+    };
+
+    match (token, param_type) {
+        (Token::Unit, ParamType::Unit)
+        | (Token::Bool(_), ParamType::Bool)
+        | (Token::U8(_), ParamType::U8)
+        | (Token::U16(_), ParamType::U16)
+        | (Token::U32(_), ParamType::U32)
+        | (Token::U64(_), ParamType::U64)
+        | (Token::U128(_), ParamType::U128)
+        | (Token::U256(_), ParamType::U256)
+        | (Token::B256(_), ParamType::B256)
+        | (Token::Bytes(_), ParamType::Bytes)
+        | (Token::String(_), ParamType::String)
+        | (Token::RawSlice(_), ParamType::RawSlice)
+        | (Token::StringSlice(_), ParamType::StringSlice) => true,
+        (Token::StringArray(string), ParamType::StringArray(len)) => string
+            .get_encodable_str()
+            .is_ok_and(|string| string.len() == *len),
+        (Token::Array(tokens), ParamType::Array(param_type, len)) => {
+            tokens.len() == *len && tokens.iter().all(|token| token_matches(token, param_type))
+        }
+        (Token::Vector(tokens), ParamType::Vector(param_type)) => {
+            tokens.iter().all(|token| token_matches(token, param_type))
+        }
+        (Token::Struct(tokens), ParamType::Struct { fields, .. }) => all_match(tokens, fields),
+        (Token::Tuple(tokens), ParamType::Tuple(param_types)) => all_match(tokens, param_types),
+        (Token::Enum(selector), ParamType::Enum { variants, .. }) => {
+            let (discriminant, token, token_variants) = selector.as_ref();
+
+            token_variants == variants
+                && variants
+                    .param_type_of_variant(*discriminant)
+                    .is_ok_and(|param_type| token_matches(token, param_type))
+        }
+        _ => false,
+    }
+}
+
+impl DynamicFunction {
+    /// The selector identifying this function in the `param1` of a `Call` receipt, in the legacy
+    /// encoding.
+    pub(crate) fn selector(&self) -> u64 {
+        u64::from_be_bytes(resolve_fn_selector(&self.name, &self.inputs))
+    }
+}
+
+/// [`DynamicContract`] is used to call a deployed contract whose JSON ABI is only known at
+/// runtime, i.e. without generating bindings with `abigen!`.
+///
+/// Arguments are given as [`Token`]s and the call returns a [`ContractCallHandler`] that decodes
+/// the output into a [`Token`] according to the ABI.
+#[derive(Debug, Clone)]
+pub struct DynamicContract<T: Account> {
+    contract_id: Bech32ContractId,
+    account: T,
+    functions: HashMap<String, DynamicFunction>,
+    log_decoder: LogDecoder,
+    encoder_config: EncoderConfig,
+}
+
+impl<T: Account> DynamicContract<T> {
+    /// Creates a new [`DynamicContract`] from the contents of a JSON ABI file.
+    pub fn new(
+        json_abi: &str,
+        contract_id: impl Into<Bech32ContractId>,
+        account: T,
+    ) -> Result<Self> {
+        let contract_id = contract_id.into();
+        let functions = functions_from_abi(json_abi)?;
+        let log_decoder = log_decoder_from_abi(json_abi, &contract_id)?;
+
+        Ok(Self {
+            contract_id,
+            account,
+            functions,
+            log_decoder,
+            encoder_config: EncoderConfig::default(),
+        })
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        &self.contract_id
+    }
+
+    pub fn account(&self) -> T {
+        self.account.clone()
+    }
+
+    pub fn with_account<U: Account>(self, account: U) -> DynamicContract<U> {
+        DynamicContract {
+            contract_id: self.contract_id,
+            account,
+            functions: self.functions,
+            log_decoder: self.log_decoder,
+            encoder_config: self.encoder_config,
+        }
+    }
+
+    pub fn with_encoder_config(mut self, encoder_config: EncoderConfig) -> Self {
+        self.encoder_config = encoder_config;
+        self
+    }
+
+    /// Returns the function named `name` as resolved from the JSON ABI.
+    pub fn function(&self, name: &str) -> Result<&DynamicFunction> {
+        self.functions
+            .get(name)
+            .ok_or_else(|| error!(Other, "function `{name}` not found in the contract ABI"))
+    }
+
+    pub fn functions(&self) -> impl Iterator<Item = &DynamicFunction> {
+        self.functions.values()
+    }
+
+    /// Prepares a call to the contract's function `name` with the given arguments.
+    ///
+    /// Each argument must be a value of the type the ABI declares for it. The output of the call
+    /// is decoded into a [`Token`] of the type declared in the ABI, and so are the logs.
+    pub fn call(&self, name: &str, args: Vec<Token>) -> Result<ContractCallHandler<T, Token>> {
+        let function = self.function(name)?;
+
+        if function.inputs.len() != args.len() {
+            return Err(error!(
+                Other,
+                "function `{name}` expects {} argument(s), but {} were given",
+                function.inputs.len(),
+                args.len()
+            ));
+        }
+
+        if let Some((index, (arg, param_type))) = zip(&args, &function.inputs)
+            .enumerate()
+            .find(|(_, (arg, param_type))| !token_matches(arg, param_type))
+        {
+            return Err(error!(
+                Codec,
+                "argument {index} of function `{name}` is not a `{param_type:?}`: `{arg}`"
+            ));
+        }
+
+        Ok(method_hash_with_output_param(
+            self.contract_id.clone(),
+            self.account.clone(),
+            encode_fn_selector(name, &function.inputs, self.encoder_config.encoding),
+            &args,
+            self.log_decoder.clone(),
+            function.is_payable,
+            self.encoder_config,
+            function.output.clone(),
+        ))
+    }
+}
+
+impl<T: Account> SettableContract for DynamicContract<T> {
+    fn id(&self) -> Bech32ContractId {
+        self.contract_id.clone()
+    }
+
+    fn log_decoder(&self) -> LogDecoder {
+        self.log_decoder.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::Receipt;
+    use fuels_accounts::wallet::WalletUnlocked;
+    use fuels_core::{codec::Encoding, types::ContractId};
+
+    use super::*;
+
+    const ABI: &str = r#"
+    {
+        "types": [
+            { "typeId": 0, "type": "()", "components": [], "typeParameters": null },
+            { "typeId": 1, "type": "bool", "components": null, "typeParameters": null },
+            {
+                "typeId": 2,
+                "type": "struct MyStruct",
+                "components": [
+                    { "name": "a", "type": 3, "typeArguments": null },
+                    { "name": "b", "type": 1, "typeArguments": null }
+                ],
+                "typeParameters": null
+            },
+            { "typeId": 3, "type": "u64", "components": null, "typeParameters": null }
+        ],
+        "functions": [
+            {
+                "inputs": [ { "name": "value", "type": 3, "typeArguments": null } ],
+                "name": "deposit",
+                "output": { "name": "", "type": 0, "typeArguments": null },
+                "attributes": [ { "name": "payable", "arguments": [] } ]
+            },
+            {
+                "inputs": [ { "name": "s", "type": 2, "typeArguments": null } ],
+                "name": "takes_struct",
+                "output": { "name": "", "type": 1, "typeArguments": null },
+                "attributes": null
+            }
+        ],
+        "loggedTypes": [
+            { "logId": 3, "loggedType": { "name": "", "type": 2, "typeArguments": null } }
+        ],
+        "messagesTypes": [],
+        "configurables": []
+    }
+    "#;
+
+    fn dynamic_contract() -> DynamicContract<WalletUnlocked> {
+        DynamicContract::new(ABI, ContractId::zeroed(), WalletUnlocked::new_random(None)).unwrap()
+    }
+
+    #[test]
+    fn functions_are_resolved_from_the_abi() {
+        let contract = dynamic_contract();
+
+        let function = contract.function("takes_struct").unwrap();
+
+        assert_eq!(
+            function,
+            &DynamicFunction {
+                name: "takes_struct".to_string(),
+                inputs: vec![ParamType::Struct {
+                    fields: vec![ParamType::U64, ParamType::Bool],
+                    generics: vec![],
+                }],
+                output: ParamType::Bool,
+                is_payable: false,
+            }
+        );
+        assert!(contract.function("deposit").unwrap().is_payable);
+    }
+
+    #[test]
+    fn call_is_prepared_according_to_the_abi() {
+        let contract = dynamic_contract();
+        let args = vec![Token::Struct(vec![Token::U64(1), Token::Bool(true)])];
+
+        let call_handler = contract.call("takes_struct", args).unwrap();
+
+        let call = &call_handler.contract_call;
+        let inputs = &contract.function("takes_struct").unwrap().inputs;
+        assert_eq!(
+            call.encoded_selector,
+            resolve_fn_selector("takes_struct", inputs).to_vec()
+        );
+        assert_eq!(call.output_param, ParamType::Bool);
+        assert!(call.compute_custom_input_offset);
+        assert!(!call_handler.is_payable());
+    }
+
+    #[test]
+    fn experimental_encoding_passes_selector_and_arguments_by_pointer() {
+        let contract = dynamic_contract().with_encoder_config(EncoderConfig {
+            encoding: Encoding::Experimental,
+            ..Default::default()
+        });
+        let args = vec![Token::Struct(vec![Token::U64(1), Token::Bool(true)])];
+
+        let deposit = contract.call("deposit", vec![Token::U64(1)]).unwrap();
+        let takes_struct = contract.call("takes_struct", args).unwrap();
+
+        assert!(deposit.contract_call.compute_custom_input_offset);
+        let call = takes_struct.contract_call;
+        assert_eq!(
+            call.encoded_selector,
+            [[0, 0, 0, 0, 0, 0, 0, 12].as_slice(), b"takes_struct"].concat()
+        );
+        assert_eq!(
+            call.encoded_args.unwrap().resolve(0),
+            [0, 0, 0, 0, 0, 0, 0, 1, 1]
+        );
+        assert_eq!(call.encoding, Encoding::Experimental);
+    }
+
+    #[test]
+    fn call_fails_for_unknown_function_or_wrong_arguments() {
+        let contract = dynamic_contract();
+
+        let unknown_function = contract
+            .call("does_not_exist", vec![])
+            .expect_err("should have failed");
+        let wrong_arguments = contract
+            .call("deposit", vec![Token::U64(1), Token::U64(2)])
+            .expect_err("should have failed");
+        let wrong_argument_type = contract
+            .call("deposit", vec![Token::Bool(true)])
+            .expect_err("should have failed");
+        let wrong_field_type = contract
+            .call(
+                "takes_struct",
+                vec![Token::Struct(vec![Token::U64(1), Token::U64(2)])],
+            )
+            .expect_err("should have failed");
+
+        assert_eq!(
+            unknown_function.to_string(),
+            "function `does_not_exist` not found in the contract ABI"
+        );
+        assert_eq!(
+            wrong_arguments.to_string(),
+            "function `deposit` expects 1 argument(s), but 2 were given"
+        );
+        assert_eq!(
+            wrong_argument_type.to_string(),
+            "codec: argument 0 of function `deposit` is not a `U64`: `Bool(true)`"
+        );
+        assert!(wrong_field_type
+            .to_string()
+            .starts_with("codec: argument 0 of function `takes_struct` is not a `Struct"));
+    }
+
+    #[test]
+    fn logs_are_decoded_according_to_the_abi() {
+        let contract = dynamic_contract();
+        let contract_id = ContractId::zeroed();
+        let data = [0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 0];
+        let receipts = [Receipt::LogData {
+            id: contract_id,
+            ra: 0,
+            rb: 3,
+            ptr: 0,
+            len: data.len() as u64,
+            digest: Default::default(),
+            pc: 0,
+            is: 0,
+            data: Some(data.to_vec()),
+        }];
+
+        let logs = contract.log_decoder().decode_logs(&receipts);
+
+        assert_eq!(
+            logs.filter_succeeded(),
+            vec!["Struct([U64(7), Bool(true)])"]
+        );
+    }
+}
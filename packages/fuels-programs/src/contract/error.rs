@@ -0,0 +1,201 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+use fuel_abi_types::error_codes::FAILED_REQUIRE_SIGNAL;
+use fuel_tx::{ContractId, Receipt};
+use fuels_core::{
+    codec::DecoderConfig,
+    types::errors::{transaction::Reason, Error, Result},
+};
+
+/// The errors a contract can revert with. `abigen` implements it for the
+/// `<Contract>RevertError` enum it generates for every contract, with one variant per type the
+/// contract logs.
+pub trait RevertError: Sized {
+    /// Decodes `data`, logged under `log_id` right before the contract reverted. Returns `None`
+    /// if the contract doesn't log anything under `log_id`.
+    fn decode_revert(
+        log_id: u64,
+        data: &[u8],
+        decoder_config: DecoderConfig,
+    ) -> Option<Result<Self>>;
+}
+
+/// The error of a contract call whose reverts are decoded into `E`, e.g. the
+/// `MyContractRevertError` generated by `abigen` for `MyContract`.
+#[derive(Debug)]
+pub enum ContractError<E> {
+    /// The contract reverted through `require` with one of its errors.
+    Revert(E),
+    /// Any other failure, including reverts that didn't log one of the contract's errors.
+    Other(Error),
+}
+
+impl<E: RevertError> ContractError<E> {
+    /// Decodes the error the contract with `contract_id` reverted with, if `error` is such a
+    /// revert. Otherwise `error` is kept as [`ContractError::Other`].
+    pub fn from_error(
+        error: Error,
+        contract_id: &ContractId,
+        decoder_config: DecoderConfig,
+    ) -> Self {
+        match decode_revert_error(&error, contract_id, decoder_config) {
+            Some(revert_error) => Self::Revert(revert_error),
+            None => Self::Other(error),
+        }
+    }
+}
+
+fn decode_revert_error<E: RevertError>(
+    error: &Error,
+    contract_id: &ContractId,
+    decoder_config: DecoderConfig,
+) -> Option<E> {
+    let Error::Transaction(Reason::Reverted {
+        revert_id: FAILED_REQUIRE_SIGNAL,
+        receipts,
+        ..
+    }) = error
+    else {
+        return None;
+    };
+
+    let mut receipts = receipts
+        .iter()
+        .rev()
+        .skip_while(|receipt| !matches!(receipt, Receipt::Revert { .. }));
+
+    // errors of nested calls can't be decoded with the error type of the called contract
+    match receipts.next() {
+        Some(Receipt::Revert { id, .. }) if id == contract_id => {}
+        _ => return None,
+    }
+
+    // `require` logs the error right before reverting
+    let (log_contract_id, log_id, data) = receipts.find_map(|receipt| match receipt {
+        Receipt::LogData {
+            id,
+            rb,
+            data: Some(data),
+            ..
+        } => Some((id, *rb, data.clone())),
+        Receipt::Log { id, ra, rb, .. } => Some((id, *rb, ra.to_be_bytes().to_vec())),
+        _ => None,
+    })?;
+    if log_contract_id != contract_id {
+        return None;
+    }
+
+    E::decode_revert(log_id, &data, decoder_config)?.ok()
+}
+
+impl<E> From<Error> for ContractError<E> {
+    fn from(error: Error) -> Self {
+        Self::Other(error)
+    }
+}
+
+impl<E: Debug> Display for ContractError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Revert(revert_error) => write!(f, "contract reverted with: `{revert_error:?}`"),
+            Self::Other(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl<E: Debug> std::error::Error for ContractError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Revert(_) => None,
+            Self::Other(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::codec::try_from_bytes;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum MyContractError {
+        Code(u64),
+    }
+
+    impl RevertError for MyContractError {
+        fn decode_revert(
+            log_id: u64,
+            data: &[u8],
+            decoder_config: DecoderConfig,
+        ) -> Option<Result<Self>> {
+            match log_id {
+                7 => Some(try_from_bytes(data, decoder_config).map(Self::Code)),
+                _ => None,
+            }
+        }
+    }
+
+    fn revert(contract_id: ContractId, log_id: u64, revert_id: u64) -> Error {
+        let receipts = vec![
+            Receipt::Log {
+                id: contract_id,
+                ra: 42,
+                rb: log_id,
+                rc: 0,
+                rd: 0,
+                pc: 0,
+                is: 0,
+            },
+            Receipt::Revert {
+                id: contract_id,
+                ra: revert_id,
+                pc: 0,
+                is: 0,
+            },
+        ];
+
+        Error::Transaction(Reason::Reverted {
+            reason: "Revert".to_string(),
+            revert_id,
+            receipts,
+        })
+    }
+
+    fn from_error(error: Error, contract_id: ContractId) -> ContractError<MyContractError> {
+        ContractError::from_error(error, &contract_id, DecoderConfig::default())
+    }
+
+    #[test]
+    fn require_reverts_are_decoded() {
+        let contract_id = ContractId::from([1; 32]);
+
+        let error = from_error(revert(contract_id, 7, FAILED_REQUIRE_SIGNAL), contract_id);
+
+        assert!(matches!(
+            error,
+            ContractError::Revert(MyContractError::Code(42))
+        ));
+        assert_eq!(error.to_string(), "contract reverted with: `Code(42)`");
+    }
+
+    #[test]
+    fn other_errors_are_kept() {
+        let contract_id = ContractId::from([1; 32]);
+        let other_contract_id = ContractId::from([2; 32]);
+
+        let unknown_log_id = from_error(revert(contract_id, 8, FAILED_REQUIRE_SIGNAL), contract_id);
+        let not_a_require = from_error(revert(contract_id, 7, 0), contract_id);
+        let nested_call = from_error(
+            revert(other_contract_id, 7, FAILED_REQUIRE_SIGNAL),
+            contract_id,
+        );
+
+        for error in [unknown_log_id, not_a_require, nested_call] {
+            assert!(matches!(
+                error,
+                ContractError::Other(Error::Transaction(Reason::Reverted { .. }))
+            ));
+        }
+    }
+}
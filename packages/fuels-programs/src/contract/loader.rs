@@ -0,0 +1,394 @@
+use fuel_asm::{op, Instruction, RegId};
+use fuel_tx::{ContractId, Salt};
+use fuels_accounts::Account;
+use fuels_core::{
+    constants::WORD_SIZE,
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        transaction::TxPolicies,
+    },
+};
+use itertools::Itertools;
+
+use crate::contract::Contract;
+
+const CHUNK_ID_SIZE: usize = ContractId::LEN;
+// Every chunk is described by its contract id followed by its length, as a word.
+const CHUNK_ENTRY_SIZE: usize = CHUNK_ID_SIZE + WORD_SIZE;
+
+/// A contract split into chunk contracts, each small enough to be deployed in its own
+/// transaction, and a loader contract assembling them when called.
+///
+/// The loader copies the code of all chunks into memory with `LDC` and jumps to it, so that the
+/// original contract runs as the loader. Calls go to the loader's [`contract_id`] and, as `LDC`
+/// can only read contracts that are inputs of the transaction, must include the
+/// [`chunk_ids`] as external contracts.
+///
+/// [`contract_id`]: LoaderContract::contract_id
+/// [`chunk_ids`]: LoaderContract::chunk_ids
+#[derive(Debug, Clone)]
+pub struct LoaderContract {
+    loader: Contract,
+    chunks: Vec<Contract>,
+}
+
+impl LoaderContract {
+    /// Splits `contract` into chunks of at most `chunk_size` bytes. The loader keeps the salt and
+    /// the storage slots of `contract`.
+    ///
+    /// Fails if `contract` contains absolute jumps, as they would land in the loader's code
+    /// instead of the loaded one. Only the instructions before the data section, whose offset is
+    /// read from the preamble of Sway programs, are checked. Binaries without a preamble are
+    /// checked as a whole.
+    pub fn new(contract: Contract, chunk_size: usize) -> Result<Self> {
+        if chunk_size == 0 || chunk_size % WORD_SIZE != 0 {
+            return Err(error!(
+                Other,
+                "chunk size must be a non-zero multiple of {WORD_SIZE}, got {chunk_size}"
+            ));
+        }
+
+        check_no_absolute_jumps(&contract.binary)?;
+
+        let chunks: Vec<Contract> = contract
+            .binary
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let mut code = chunk.to_vec();
+                code.resize(chunk.len().next_multiple_of(WORD_SIZE), 0);
+
+                Contract::new(code, Salt::zeroed(), vec![])
+            })
+            .collect();
+
+        let loader_code = loader_code(
+            chunks
+                .iter()
+                .map(|chunk| (chunk.contract_id(), chunk.binary.len())),
+        );
+        let loader = Contract::new(loader_code, contract.salt, contract.storage_slots);
+
+        Ok(Self { loader, chunks })
+    }
+
+    /// The id of the loader, i.e. the id to call the contract at.
+    pub fn contract_id(&self) -> Bech32ContractId {
+        self.loader.contract_id().into()
+    }
+
+    /// The ids of the chunk contracts, in the order they are loaded. Chunks with the same code
+    /// share one contract, so every id is listed once.
+    pub fn chunk_ids(&self) -> Vec<Bech32ContractId> {
+        self.chunks
+            .iter()
+            .map(|chunk| chunk.contract_id())
+            .unique()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Deploys the chunks, one per transaction, and then the loader. Chunks, or a loader, that
+    /// are already deployed are skipped, so an interrupted deployment can be resumed by calling
+    /// this again.
+    pub async fn deploy(
+        &self,
+        account: &impl Account,
+        tx_policies: TxPolicies,
+    ) -> Result<Bech32ContractId> {
+        let provider = account.try_provider()?;
+
+        for contract in self.chunks.iter().chain([&self.loader]) {
+            let contract_id = contract.contract_id().into();
+            if !provider.contract_exists(&contract_id).await? {
+                contract.clone().deploy(account, tx_policies).await?;
+            }
+        }
+
+        Ok(self.contract_id())
+    }
+}
+
+/// The offset of a program's data section is stored in the word following the first two
+/// instructions of its preamble.
+const DATA_SECTION_OFFSET_START: usize = 2 * Instruction::SIZE;
+const PREAMBLE_SIZE: usize = DATA_SECTION_OFFSET_START + WORD_SIZE;
+
+/// Absolute jumps target an offset from `$is`, which points to the start of the loader while the
+/// loaded code runs, so they can't be used by contracts deployed in chunks.
+fn check_no_absolute_jumps(binary: &[u8]) -> Result<()> {
+    let instruction_ranges = match data_section_offset(binary) {
+        // the data section offset in the preamble isn't an instruction
+        Some(data_section_offset) => [
+            0..DATA_SECTION_OFFSET_START,
+            PREAMBLE_SIZE..data_section_offset,
+        ],
+        None => [0..binary.len(), 0..0],
+    };
+
+    let absolute_jump = instruction_ranges
+        .into_iter()
+        .flat_map(|range| {
+            (range.start..range.end.saturating_sub(Instruction::SIZE - 1))
+                .step_by(Instruction::SIZE)
+        })
+        .find(|&offset| {
+            matches!(
+                decode(&binary[offset..offset + Instruction::SIZE]),
+                Some(
+                    Instruction::JI(_)
+                        | Instruction::JNEI(_)
+                        | Instruction::JNZI(_)
+                        | Instruction::JMP(_)
+                        | Instruction::JNE(_)
+                )
+            )
+        });
+
+    match absolute_jump {
+        Some(offset) => Err(error!(
+            Other,
+            "cannot deploy the contract in chunks: it uses an absolute jump at byte offset {offset}, only relative jumps can be used by loaded code"
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Reads the offset of the data section from the preamble of `binary`, if it starts with one,
+/// i.e. with `JI 4; NOOP` or `MOVE $rA $pc; JMPF $zero imm` jumping over the offset.
+fn data_section_offset(binary: &[u8]) -> Option<usize> {
+    let preamble = binary.get(..PREAMBLE_SIZE)?;
+    let has_preamble = match (decode(&preamble[..4])?, decode(&preamble[4..8])?) {
+        (Instruction::JI(ji), Instruction::NOOP(_)) => {
+            u32::from(ji.unpack()) as usize == PREAMBLE_SIZE / Instruction::SIZE
+        }
+        (Instruction::MOVE(move_), Instruction::JMPF(jmpf)) => {
+            move_.unpack().1 == RegId::PC && jmpf.unpack().0 == RegId::ZERO
+        }
+        _ => false,
+    };
+
+    let offset = u64::from_be_bytes(preamble[DATA_SECTION_OFFSET_START..].try_into().ok()?);
+    let offset = usize::try_from(offset).ok()?;
+
+    (has_preamble && (PREAMBLE_SIZE..=binary.len()).contains(&offset)).then_some(offset)
+}
+
+fn decode(bytes: &[u8]) -> Option<Instruction> {
+    let bytes: [u8; Instruction::SIZE] = bytes.try_into().ok()?;
+
+    Instruction::try_from(bytes).ok()
+}
+
+/// Generates the code of a loader that appends the code of the given chunks to the call frame's
+/// code with `LDC` and jumps to its start. The chunk ids and lengths are placed right after the
+/// instructions.
+fn loader_code(chunks: impl ExactSizeIterator<Item = (ContractId, usize)>) -> Vec<u8> {
+    let entry_ptr = 0x10;
+    let code_start = 0x11;
+    let chunks_left = 0x12;
+    let chunk_len = 0x13;
+
+    let instructions = |num_chunks: u32, data_offset: u16| {
+        [
+            op::move_(entry_ptr, RegId::PC),
+            op::addi(entry_ptr, entry_ptr, data_offset),
+            // the chunks are loaded where the stack starts
+            op::move_(code_start, RegId::SSP),
+            op::movi(chunks_left, num_chunks),
+            // loop over the chunk entries
+            op::lw(chunk_len, entry_ptr, (CHUNK_ID_SIZE / WORD_SIZE) as u16),
+            op::ldc(entry_ptr, RegId::ZERO, chunk_len),
+            op::addi(entry_ptr, entry_ptr, CHUNK_ENTRY_SIZE as u16),
+            op::subi(chunks_left, chunks_left, 1),
+            // jump back to the `lw`
+            op::jnzb(chunks_left, RegId::ZERO, 3),
+            // `jmp` is relative to `$is` and counts instructions
+            op::sub(code_start, code_start, RegId::IS),
+            op::divi(code_start, code_start, Instruction::SIZE as u16),
+            op::jmp(code_start),
+        ]
+    };
+
+    let num_chunks = chunks.len() as u32;
+    let data_offset = (instructions(0, 0).len() * Instruction::SIZE) as u16;
+
+    let mut code: Vec<u8> = instructions(num_chunks, data_offset)
+        .into_iter()
+        .flat_map(|instruction| instruction.to_bytes())
+        .collect();
+
+    for (contract_id, len) in chunks {
+        code.extend_from_slice(contract_id.as_ref());
+        code.extend_from_slice(&(len as u64).to_be_bytes());
+    }
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(binary_len: usize) -> Contract {
+        let binary = (0..binary_len).map(|byte| byte as u8).collect();
+
+        Contract::new(binary, Salt::zeroed(), vec![])
+    }
+
+    fn contract_from_instructions(instructions: Vec<Instruction>) -> Contract {
+        Contract::new(instructions.into_iter().collect(), Salt::zeroed(), vec![])
+    }
+
+    #[test]
+    fn binary_is_split_into_word_aligned_chunks() -> Result<()> {
+        let original = contract(20);
+
+        let loader = LoaderContract::new(original.clone(), 8)?;
+
+        let chunks: Vec<_> = loader.chunks.iter().map(|chunk| &chunk.binary).collect();
+        assert_eq!(
+            chunks,
+            [
+                &original.binary[..8],
+                &original.binary[8..16],
+                &[16, 17, 18, 19, 0, 0, 0, 0][..]
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn loader_lists_chunks_after_its_instructions() -> Result<()> {
+        let loader = LoaderContract::new(contract(20), 8)?;
+
+        let code = &loader.loader.binary;
+        let data_offset = code.len() - 3 * CHUNK_ENTRY_SIZE;
+        assert_eq!(data_offset % WORD_SIZE, 0);
+
+        for (chunk, entry) in loader.chunks.iter().zip(code[data_offset..].chunks(40)) {
+            assert_eq!(&entry[..CHUNK_ID_SIZE], chunk.contract_id().as_ref());
+            assert_eq!(entry[CHUNK_ID_SIZE..], 8u64.to_be_bytes());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn loader_keeps_salt_and_storage_of_the_contract() -> Result<()> {
+        let storage_slots = vec![fuel_tx::StorageSlot::new([1; 32].into(), [2; 32].into())];
+        let original = Contract::new(vec![0; 16], Salt::new([3; 32]), storage_slots.clone());
+
+        let loader = LoaderContract::new(original, 8)?;
+
+        assert_eq!(loader.loader.salt, Salt::new([3; 32]));
+        assert_eq!(loader.loader.storage_slots, storage_slots);
+        assert!(loader
+            .chunks
+            .iter()
+            .all(|chunk| chunk.storage_slots.is_empty()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn chunks_with_the_same_code_share_an_id() -> Result<()> {
+        let mut binary = vec![1; 16];
+        binary.extend([2; 8]);
+
+        let loader = LoaderContract::new(Contract::new(binary, Salt::zeroed(), vec![]), 8)?;
+
+        assert_eq!(loader.chunks.len(), 3);
+        assert_eq!(
+            loader.chunk_ids(),
+            [
+                loader.chunks[0].contract_id().into(),
+                loader.chunks[2].contract_id().into()
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn chunk_size_must_be_word_aligned() {
+        let error = LoaderContract::new(contract(16), 12).expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            "chunk size must be a non-zero multiple of 8, got 12"
+        );
+    }
+
+    #[test]
+    fn contracts_with_absolute_jumps_are_rejected() {
+        for jump in [
+            op::ji(1),
+            op::jnei(0x10, RegId::ZERO, 1),
+            op::jnzi(0x10, 1),
+            op::jmp(0x10),
+            op::jne(0x10, RegId::ZERO, 0x11),
+        ] {
+            let contract = contract_from_instructions(vec![op::noop(), jump, op::ret(RegId::ONE)]);
+
+            let error = LoaderContract::new(contract, 8).expect_err("should have failed");
+
+            assert_eq!(
+                error.to_string(),
+                "cannot deploy the contract in chunks: it uses an absolute jump at byte offset 4, only relative jumps can be used by loaded code"
+            );
+        }
+    }
+
+    /// A program with a preamble storing the offset of `data`, placed after `instructions`.
+    fn program_with_data_section(instructions: Vec<Instruction>, data: &[u8]) -> Vec<u8> {
+        let data_section_offset = PREAMBLE_SIZE + instructions.len() * Instruction::SIZE;
+
+        let mut binary: Vec<u8> = [op::move_(0x10, RegId::PC), op::jmpf(RegId::ZERO, 1)]
+            .into_iter()
+            .collect();
+        binary.extend((data_section_offset as u64).to_be_bytes());
+        binary.extend(instructions.into_iter().collect::<Vec<u8>>());
+        binary.extend(data);
+
+        binary
+    }
+
+    #[test]
+    fn data_section_is_not_checked_for_jumps() -> Result<()> {
+        let jump_as_data: Vec<u8> = [op::ji(1), op::jmp(0x10)].into_iter().collect();
+        let binary = program_with_data_section(vec![op::ret(RegId::ONE)], &jump_as_data);
+
+        LoaderContract::new(Contract::new(binary, Salt::zeroed(), vec![]), 8)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn instructions_before_the_data_section_are_checked_for_jumps() {
+        let binary = program_with_data_section(vec![op::noop(), op::ji(1)], &[0; WORD_SIZE]);
+
+        let error = LoaderContract::new(Contract::new(binary, Salt::zeroed(), vec![]), 8)
+            .expect_err("should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            "cannot deploy the contract in chunks: it uses an absolute jump at byte offset 20, only relative jumps can be used by loaded code"
+        );
+    }
+
+    #[test]
+    fn contracts_with_relative_jumps_are_accepted() -> Result<()> {
+        let contract = contract_from_instructions(vec![
+            op::jmpf(RegId::ZERO, 0),
+            op::jnzb(0x10, RegId::ZERO, 0),
+            op::jnef(0x10, RegId::ZERO, RegId::ZERO, 0),
+            op::ret(RegId::ONE),
+        ]);
+
+        LoaderContract::new(contract, 8)?;
+
+        Ok(())
+    }
+}
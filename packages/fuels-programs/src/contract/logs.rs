@@ -0,0 +1,70 @@
+use std::ops::Range;
+
+use fuels_accounts::{provider::ContractLog, Account};
+use fuels_core::{
+    codec::LogDecoder,
+    traits::{Parameterize, Tokenizable},
+    types::{bech32::Bech32ContractId, errors::Result},
+};
+use futures::stream::BoxStream;
+
+/// Queries the logs of a contract from the chain, returned by the `logs()` method of the
+/// contract's bindings.
+#[derive(Debug, Clone)]
+pub struct ContractLogs<T> {
+    contract_id: Bech32ContractId,
+    account: T,
+    log_decoder: LogDecoder,
+}
+
+impl<T: Account> ContractLogs<T> {
+    pub fn new(contract_id: Bech32ContractId, account: T, log_decoder: LogDecoder) -> Self {
+        Self {
+            contract_id,
+            account,
+            log_decoder,
+        }
+    }
+
+    /// Returns the values of type `L` logged by the contract in the blocks within `heights`,
+    /// oldest first. See `Provider::query_logs`.
+    pub async fn query<L: Tokenizable + Parameterize + 'static>(
+        &self,
+        heights: Range<u32>,
+    ) -> Result<Vec<ContractLog<L>>> {
+        self.account
+            .try_provider()?
+            .query_logs(&self.contract_id, heights, &self.log_decoder)
+            .await
+    }
+}
+
+/// Streams the logs of a contract as they are produced, returned by the `events()` method of the
+/// contract's bindings.
+#[derive(Debug, Clone)]
+pub struct ContractEvents<T> {
+    contract_id: Bech32ContractId,
+    account: T,
+    log_decoder: LogDecoder,
+}
+
+impl<T: Account> ContractEvents<T> {
+    pub fn new(contract_id: Bech32ContractId, account: T, log_decoder: LogDecoder) -> Self {
+        Self {
+            contract_id,
+            account,
+            log_decoder,
+        }
+    }
+
+    /// Streams the values of type `E` logged by the contract in the blocks produced from now on.
+    /// See `Provider::subscribe_logs`.
+    pub async fn subscribe<E: Tokenizable + Parameterize + Send + 'static>(
+        &self,
+    ) -> Result<BoxStream<'_, Result<ContractLog<E>>>> {
+        self.account
+            .try_provider()?
+            .subscribe_logs(&self.contract_id, &self.log_decoder)
+            .await
+    }
+}
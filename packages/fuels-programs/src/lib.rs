@@ -1,4 +1,5 @@
 pub mod call_response;
+pub mod call_trace;
 pub mod call_utils;
 pub mod contract;
 pub mod receipt_parser;
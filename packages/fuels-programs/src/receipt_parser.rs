@@ -1,6 +1,6 @@
 use fuel_tx::{ContractId, Receipt};
 use fuels_core::{
-    codec::{ABIDecoder, DecoderConfig},
+    codec::{ABIDecoder, DecoderConfig, Encoding},
     types::{
         bech32::Bech32ContractId,
         errors::{error, Error, Result},
@@ -43,11 +43,16 @@ impl ReceiptParser {
             // During a script execution, the script's contract id is the **null** contract id
             .unwrap_or_else(ContractId::zeroed);
 
-        output_param.validate_is_decodable(self.decoder.config.max_depth)?;
+        let data = match self.decoder.config.encoding {
+            Encoding::Legacy => {
+                output_param.validate_is_decodable(self.decoder.config.max_depth)?;
 
-        let data = self
-            .extract_raw_data(output_param, &contract_id)
-            .ok_or_else(|| Self::missing_receipts_error(output_param))?;
+                self.extract_raw_data(output_param, &contract_id)
+            }
+            // The whole output, heap data included, is returned in a single `ReturnData` receipt
+            Encoding::Experimental => self.extract_return_data(&contract_id),
+        }
+        .ok_or_else(|| Self::missing_receipts_error(output_param))?;
 
         self.decoder.decode(output_param, &data)
     }
@@ -351,4 +356,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn receipt_parser_decodes_experimental_return_data() -> Result<()> {
+        let expected_receipts = get_relevant_receipts();
+        let contract_id = target_contract();
+
+        let mut receipts = expected_receipts.clone();
+        let data = [
+            [0, 0, 0, 0, 0, 0, 0, 7].as_slice(),
+            &[0, 0, 0, 0, 0, 0, 0, 2, 1, 2],
+        ]
+        .concat();
+        receipts.push(get_return_receipt(contract_id, 0));
+        receipts.push(get_return_data_receipt(contract_id, &data));
+        let decoder_config = DecoderConfig {
+            encoding: Encoding::Experimental,
+            ..Default::default()
+        };
+        let mut parser = ReceiptParser::new(&receipts, decoder_config);
+
+        let token = parser
+            .parse(Some(&contract_id.into()), &<(u64, Vec<u8>)>::param_type())
+            .expect("parsing should succeed");
+
+        assert_eq!(<(u64, Vec<u8>)>::from_token(token)?, (7, vec![1, 2]));
+
+        Ok(())
+    }
 }
@@ -1,13 +1,13 @@
-use std::{collections::HashSet, fmt::Debug, marker::PhantomData};
+use std::{collections::HashSet, fmt::Debug, marker::PhantomData, sync::Arc};
 
 use fuel_tx::{Bytes32, ContractId, Output, Receipt};
 use fuel_types::bytes::padded_len_usize;
 use fuels_accounts::{
     provider::{Provider, TransactionCost},
-    Account,
+    Account, FeePayer,
 };
 use fuels_core::{
-    codec::{DecoderConfig, LogDecoder},
+    codec::{ABIEncoder, DecoderConfig, EncoderConfig, LogDecoder},
     error,
     offsets::base_offset_script,
     traits::{Parameterize, Tokenizable},
@@ -15,12 +15,14 @@ use fuels_core::{
         bech32::Bech32ContractId,
         errors::Result,
         input::Input,
+        param_types::ParamType,
         transaction::{ScriptTransaction, Transaction, TxPolicies},
         transaction_builders::{
             BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
         },
         tx_status::TxStatus,
         unresolved_bytes::UnresolvedBytes,
+        Token,
     },
 };
 use itertools::chain;
@@ -84,26 +86,37 @@ pub struct ScriptCallHandler<T: Account, D> {
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
     decoder_config: DecoderConfig,
+    fee_payer: Option<Arc<dyn FeePayer>>,
     pub account: T,
     pub provider: Provider,
     pub datatype: PhantomData<D>,
     pub log_decoder: LogDecoder,
+    output_param: ParamType,
 }
 
 impl<T: Account, D> ScriptCallHandler<T, D>
 where
     D: Parameterize + Tokenizable + Debug,
 {
+    /// Encodes `args` with `encoder_config`. The return value and the logs are decoded with the
+    /// same encoding.
     pub fn new(
         script_binary: Vec<u8>,
-        encoded_args: Result<UnresolvedBytes>,
+        args: &[Token],
         account: T,
         provider: Provider,
-        log_decoder: LogDecoder,
+        mut log_decoder: LogDecoder,
+        encoder_config: EncoderConfig,
     ) -> Self {
+        let decoder_config = DecoderConfig {
+            encoding: encoder_config.encoding,
+            ..Default::default()
+        };
+        log_decoder.set_decoder_config(decoder_config);
+
         let script_call = ScriptCall {
             script_binary,
-            encoded_args,
+            encoded_args: ABIEncoder::new(encoder_config).encode(args),
             inputs: vec![],
             outputs: vec![],
             external_contracts: vec![],
@@ -117,7 +130,9 @@ where
             provider,
             datatype: PhantomData,
             log_decoder,
-            decoder_config: DecoderConfig::default(),
+            decoder_config,
+            fee_payer: None,
+            output_param: D::param_type(),
         }
     }
 
@@ -133,6 +148,18 @@ where
         self
     }
 
+    /// Sets an account paying the transaction fee instead of the account running the script,
+    /// e.g. for users holding no base asset. Both accounts sign the transaction.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// instance.main(...).with_fee_payer(sponsor_wallet).call()
+    /// ```
+    pub fn with_fee_payer(mut self, fee_payer: impl FeePayer + 'static) -> Self {
+        self.fee_payer = Some(Arc::new(fee_payer));
+        self
+    }
+
     pub fn with_decoder_config(mut self, decoder_config: DecoderConfig) -> Self {
         self.decoder_config = decoder_config;
         self.log_decoder.set_decoder_config(decoder_config);
@@ -219,7 +246,10 @@ where
         let mut tb = self.transaction_builder().await?;
 
         self.account.add_witnesses(&mut tb)?;
-        self.account.adjust_for_fee(&mut tb, 0).await?;
+        match &self.fee_payer {
+            Some(fee_payer) => fee_payer.pay_fee(&mut tb, 0).await?,
+            None => self.account.adjust_for_fee(&mut tb, 0).await?,
+        }
 
         tb.build(self.account.try_provider()?).await
     }
@@ -280,11 +310,17 @@ where
 
         Ok(transaction_cost)
     }
+}
 
+impl<T, D> ScriptCallHandler<T, D>
+where
+    T: Account,
+    D: Tokenizable + Debug,
+{
     /// Create a [`FuelCallResponse`] from call receipts
     pub fn get_response(&self, receipts: Vec<Receipt>) -> Result<FuelCallResponse<D>> {
         let token =
-            ReceiptParser::new(&receipts, self.decoder_config).parse(None, &D::param_type())?;
+            ReceiptParser::new(&receipts, self.decoder_config).parse(None, &self.output_param)?;
 
         Ok(FuelCallResponse::new(
             D::from_token(token)?,
@@ -3,11 +3,7 @@ use std::fmt::Debug;
 use fuel_tx::Receipt;
 use fuel_types::Bytes32;
 use fuels_accounts::{provider::Provider, Account};
-use fuels_core::{
-    codec::LogDecoder,
-    traits::{Parameterize, Tokenizable},
-    types::errors::Result,
-};
+use fuels_core::{codec::LogDecoder, traits::Tokenizable, types::errors::Result};
 
 use crate::{
     call_response::FuelCallResponse,
@@ -40,26 +36,26 @@ pub struct SubmitResponse<T: Account, D> {
 
 #[derive(Debug)]
 pub enum CallHandler<T: Account, D> {
-    Contract(ContractCallHandler<T, D>),
-    Script(ScriptCallHandler<T, D>),
+    Contract(Box<ContractCallHandler<T, D>>),
+    Script(Box<ScriptCallHandler<T, D>>),
 }
 
 impl<T: Account, D> From<ScriptCallHandler<T, D>> for CallHandler<T, D> {
     fn from(value: ScriptCallHandler<T, D>) -> Self {
-        Self::Script(value)
+        Self::Script(Box::new(value))
     }
 }
 
 impl<T: Account, D> From<ContractCallHandler<T, D>> for CallHandler<T, D> {
     fn from(value: ContractCallHandler<T, D>) -> Self {
-        Self::Contract(value)
+        Self::Contract(Box::new(value))
     }
 }
 
 impl<T, D> CallHandler<T, D>
 where
     T: Account,
-    D: Tokenizable + Parameterize + Debug,
+    D: Tokenizable + Debug,
 {
     fn get_response(&self, receipts: Vec<Receipt>) -> Result<FuelCallResponse<D>> {
         match self {
@@ -85,7 +81,7 @@ where
     }
 }
 
-impl<T: Account, D: Tokenizable + Parameterize + Debug> SubmitResponse<T, D> {
+impl<T: Account, D: Tokenizable + Debug> SubmitResponse<T, D> {
     pub fn new(tx_id: Bytes32, call_handler: impl Into<CallHandler<T, D>>) -> Self {
         Self {
             tx_id,
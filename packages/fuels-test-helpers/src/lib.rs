@@ -27,6 +27,10 @@ pub(crate) mod fuel_bin_service;
 
 #[cfg(feature = "fuels-accounts")]
 mod accounts;
+#[cfg(feature = "fuels-accounts")]
+mod signing_service;
+#[cfg(feature = "fuels-accounts")]
+pub use signing_service::*;
 
 pub use service::*;
 mod service;
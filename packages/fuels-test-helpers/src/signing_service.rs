@@ -0,0 +1,173 @@
+use std::{collections::HashMap, sync::Arc};
+
+use fuel_crypto::Message;
+use fuels_accounts::{
+    remote_signer::{SignRequest, SignResponse, SIGN_METHOD},
+    wallet::WalletUnlocked,
+};
+use fuels_core::{traits::Signer, types::errors::Result};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+    task::JoinHandle,
+};
+
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const UNKNOWN_ADDRESS: i64 = -32000;
+
+/// A local signing service answering the requests of a `RemoteSigner` with the keys of the given
+/// wallets. Meant for testing code that signs through a separate signing service.
+#[derive(Debug, Clone)]
+pub struct MockSigningService {
+    wallets: Arc<HashMap<String, WalletUnlocked>>,
+}
+
+/// A running [`MockSigningService`]. The service is stopped when this is dropped.
+#[derive(Debug)]
+pub struct MockSigningServer {
+    endpoint: String,
+    task: JoinHandle<()>,
+}
+
+impl MockSigningServer {
+    /// The URL, or the socket path, the service can be reached at.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+impl Drop for MockSigningServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl MockSigningService {
+    pub fn new(wallets: impl IntoIterator<Item = WalletUnlocked>) -> Self {
+        let wallets = wallets
+            .into_iter()
+            .map(|wallet| (wallet.address().to_string(), wallet))
+            .collect();
+
+        Self {
+            wallets: Arc::new(wallets),
+        }
+    }
+
+    pub async fn handle(&self, request: SignRequest) -> SignResponse {
+        if request.method != SIGN_METHOD {
+            return SignResponse::error(
+                request.id,
+                METHOD_NOT_FOUND,
+                format!("unknown method `{}`", request.method),
+            );
+        }
+
+        let Some(wallet) = self.wallets.get(&request.params.address) else {
+            return SignResponse::error(
+                request.id,
+                UNKNOWN_ADDRESS,
+                format!("no key for address `{}`", request.params.address),
+            );
+        };
+
+        let message = request.params.message.trim_start_matches("0x");
+        let Some(message) = hex::decode(message)
+            .ok()
+            .and_then(|bytes| <[u8; Message::LEN]>::try_from(bytes).ok())
+        else {
+            return SignResponse::error(request.id, INVALID_PARAMS, "invalid message hash");
+        };
+
+        match wallet.sign(Message::from_bytes(message)).await {
+            Ok(signature) => SignResponse::success(request.id, &signature),
+            Err(err) => SignResponse::error(request.id, UNKNOWN_ADDRESS, err.to_string()),
+        }
+    }
+
+    async fn handle_json(&self, request: &[u8]) -> Vec<u8> {
+        let response = match serde_json::from_slice::<SignRequest>(request) {
+            Ok(request) => self.handle(request).await,
+            Err(err) => SignResponse::error(0, INVALID_PARAMS, err.to_string()),
+        };
+
+        serde_json::to_vec(&response).expect("response is serializable")
+    }
+
+    /// Serves JSON-RPC over HTTP on a random local port.
+    pub async fn serve_http(self) -> Result<MockSigningServer> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let endpoint = format!("http://{}", listener.local_addr()?);
+
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let service = self.clone();
+                tokio::spawn(async move {
+                    let _ = service.answer_http(stream).await;
+                });
+            }
+        });
+
+        Ok(MockSigningServer { endpoint, task })
+    }
+
+    async fn answer_http(&self, stream: tokio::net::TcpStream) -> std::io::Result<()> {
+        let mut reader = BufReader::new(stream);
+
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).await?;
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or_default();
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).await?;
+        let response = self.handle_json(&body).await;
+
+        let mut stream = reader.into_inner();
+        let head = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+            response.len()
+        );
+        stream.write_all(head.as_bytes()).await?;
+        stream.write_all(&response).await?;
+        stream.shutdown().await
+    }
+
+    /// Serves newline delimited JSON-RPC on a Unix domain socket at `path`.
+    #[cfg(unix)]
+    pub async fn serve_unix(self, path: impl AsRef<std::path::Path>) -> Result<MockSigningServer> {
+        let path = path.as_ref();
+        let listener = tokio::net::UnixListener::bind(path)?;
+        let endpoint = path.display().to_string();
+
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let service = self.clone();
+                tokio::spawn(async move {
+                    let (reader, mut writer) = stream.into_split();
+                    let mut lines = BufReader::new(reader).lines();
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        let mut response = service.handle_json(line.as_bytes()).await;
+                        response.push(b'\n');
+                        if writer.write_all(&response).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        Ok(MockSigningServer { endpoint, task })
+    }
+}
@@ -75,7 +75,7 @@ pub mod prelude {
             predicate::Predicate,
             provider::*,
             wallet::{generate_mnemonic_phrase, WalletUnlocked},
-            Account, ViewOnlyAccount,
+            Account, FeePayer, ViewOnlyAccount,
         },
         core::{
             codec::{LogDecoder, LogId, LogResult},
@@ -84,8 +84,8 @@ pub mod prelude {
         programs::{
             call_utils::TxDependencyExtension,
             contract::{
-                CallParameters, Contract, LoadConfiguration, MultiContractCallHandler,
-                SettableContract, StorageConfiguration,
+                CallParameters, Contract, ContractError, DynamicContract, LoadConfiguration,
+                LoaderContract, MultiContractCallHandler, SettableContract, StorageConfiguration,
             },
         },
         test_helpers::*,
@@ -1,7 +1,7 @@
 use fuels::{
     core::codec::{calldata, fn_selector, DecoderConfig, EncoderConfig},
     prelude::*,
-    types::{errors::transaction::Reason, Bits256},
+    types::{errors::transaction::Reason, Bits256, Token},
 };
 
 #[tokio::test]
@@ -1838,3 +1838,403 @@ async fn contract_encoder_config_is_applied() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn can_call_contract_with_dynamic_abi() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    let contract_id = contract_instance.contract_id().clone();
+
+    // ANCHOR: dynamic_contract
+    let json_abi =
+        std::fs::read_to_string("tests/contracts/contract_test/out/debug/contract_test-abi.json")?;
+    let contract = DynamicContract::new(&json_abi, contract_id, wallet.clone())?;
+
+    let response = contract
+        .call("get", vec![Token::U64(5), Token::U64(6)])?
+        .call()
+        .await?;
+
+    assert_eq!(response.value, Token::U64(11));
+    // ANCHOR_END: dynamic_contract
+
+    let error = contract
+        .call("get", vec![Token::U64(5)])
+        .expect_err("should fail because `get` takes two arguments");
+    assert!(error.to_string().contains("expects 2 argument(s)"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_call_round_trips_in_the_experimental_encoding() -> Result<()> {
+    use fuel_core_types::{
+        fuel_asm::{op, RegId},
+        fuel_vm::CallFrame,
+    };
+    use fuels::core::codec::Encoding;
+
+    let wallet = launch_provider_and_get_wallet().await?;
+
+    // Stands in for a contract compiled with the new encoding: it reverts unless `param1` points
+    // to the encoded name of `echo`, and returns the arguments `param2` points to
+    let [selector_ptr, selector_len, expected_len, is_expected, args_ptr, args_len] =
+        [0x10, 0x11, 0x12, 0x13, 0x14, 0x15];
+    let code = [
+        op::lw(selector_ptr, RegId::FP, (CallFrame::a_offset() / 8) as u16),
+        op::lw(selector_len, selector_ptr, 0),
+        op::movi(expected_len, "echo".len() as u32),
+        op::eq(is_expected, selector_len, expected_len),
+        op::jnzf(is_expected, RegId::ZERO, 1),
+        op::rvrt(RegId::ONE),
+        op::lw(args_ptr, RegId::FP, (CallFrame::b_offset() / 8) as u16),
+        op::movi(args_len, 9),
+        op::retd(args_ptr, args_len),
+    ]
+    .into_iter()
+    .collect();
+    let contract_id = Contract::new(code, Salt::default(), vec![])
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+    let abi = r#"
+    {
+        "types": [
+            { "typeId": 0, "type": "(_, _)", "components": [
+                { "name": "__tuple_element", "type": 2, "typeArguments": null },
+                { "name": "__tuple_element", "type": 1, "typeArguments": null }
+            ], "typeParameters": null },
+            { "typeId": 1, "type": "bool", "components": null, "typeParameters": null },
+            { "typeId": 2, "type": "u64", "components": null, "typeParameters": null }
+        ],
+        "functions": [
+            {
+                "inputs": [
+                    { "name": "a", "type": 2, "typeArguments": null },
+                    { "name": "b", "type": 1, "typeArguments": null }
+                ],
+                "name": "echo",
+                "output": { "name": "", "type": 0, "typeArguments": null },
+                "attributes": null
+            }
+        ],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }
+    "#;
+    let contract =
+        DynamicContract::new(abi, contract_id, wallet)?.with_encoder_config(EncoderConfig {
+            encoding: Encoding::Experimental,
+            ..Default::default()
+        });
+
+    let response = contract
+        .call("echo", vec![Token::U64(42), Token::Bool(true)])?
+        .call()
+        .await?;
+
+    assert_eq!(
+        response.value,
+        Token::Tuple(vec![Token::U64(42), Token::Bool(true)])
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dynamic_contract_calls_can_be_submitted_and_decode_logs() -> Result<()> {
+    use fuel_core_types::fuel_asm::{op, RegId};
+
+    let wallet = launch_provider_and_get_wallet().await?;
+
+    // logs `42` under the log id `0` and returns it
+    let value = 0x10;
+    let code = [
+        op::movi(value, 42),
+        op::log(value, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(value),
+    ]
+    .into_iter()
+    .collect();
+    let contract_id = Contract::new(code, Salt::default(), vec![])
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+    let abi = r#"
+    {
+        "types": [
+            { "typeId": 0, "type": "u64", "components": null, "typeParameters": null }
+        ],
+        "functions": [
+            {
+                "inputs": [],
+                "name": "log_and_return",
+                "output": { "name": "", "type": 0, "typeArguments": null },
+                "attributes": null
+            }
+        ],
+        "loggedTypes": [
+            { "logId": 0, "loggedType": { "name": "", "type": 0, "typeArguments": null } }
+        ],
+        "messagesTypes": [],
+        "configurables": []
+    }
+    "#;
+    let contract = DynamicContract::new(abi, contract_id, wallet)?;
+
+    let response = contract
+        .call("log_and_return", vec![])?
+        .submit()
+        .await?
+        .response()
+        .await?;
+
+    assert_eq!(response.value, Token::U64(42));
+    assert_eq!(response.decode_logs().filter_succeeded(), vec!["U64(42)"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn call_trace_decodes_the_arguments_of_contract_calls() -> Result<()> {
+    use fuel_core_types::fuel_asm::{op, RegId};
+    use fuels::programs::call_trace::CallTraceBuilder;
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?.clone();
+
+    let code = [op::ret(RegId::ONE)].into_iter().collect();
+    let contract_id = Contract::new(code, Salt::default(), vec![])
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+    let abi = r#"
+    {
+        "types": [
+            { "typeId": 0, "type": "bool", "components": null, "typeParameters": null },
+            { "typeId": 1, "type": "u64", "components": null, "typeParameters": null }
+        ],
+        "functions": [
+            {
+                "inputs": [
+                    { "name": "value", "type": 1, "typeArguments": null },
+                    { "name": "flag", "type": 0, "typeArguments": null }
+                ],
+                "name": "pick",
+                "output": { "name": "", "type": 1, "typeArguments": null },
+                "attributes": null
+            }
+        ],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }
+    "#;
+    let contract = DynamicContract::new(abi, contract_id.clone(), wallet)?;
+
+    let tx = contract
+        .call("pick", vec![Token::U64(42), Token::Bool(true)])?
+        .build_tx()
+        .await?;
+    let receipts = provider
+        .send_transaction_and_await_commit(tx.clone())
+        .await?
+        .take_receipts_checked(None)?;
+
+    let trace = CallTraceBuilder::default()
+        .with_contract_abi(&contract_id, abi)?
+        .with_script_transaction(&tx, provider.consensus_parameters())
+        .build(&receipts);
+
+    let call = trace.frames()[1];
+    assert_eq!(call.function.as_deref(), Some("pick"));
+    assert_eq!(
+        call.arguments,
+        Some(vec![Token::U64(42), Token::Bool(true)])
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_call_fee_can_be_paid_by_another_account() -> Result<()> {
+    setup_program_test!(
+        Wallets("sponsor"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "sponsor"
+        ),
+    );
+    let provider = sponsor.try_provider()?.clone();
+    let user = WalletUnlocked::new_random(Some(provider));
+    let sponsor_balance = sponsor.get_asset_balance(&BASE_ASSET_ID).await?;
+
+    // ANCHOR: contract_call_fee_payer
+    let response = contract_instance
+        .with_account(user.clone())
+        .methods()
+        .initialize_counter(42)
+        .with_tx_policies(TxPolicies::default().with_gas_price(1))
+        .with_fee_payer(sponsor.clone())
+        .call()
+        .await?;
+    // ANCHOR_END: contract_call_fee_payer
+
+    assert_eq!(response.value, 42);
+    assert_eq!(user.get_asset_balance(&BASE_ASSET_ID).await?, 0);
+    assert!(sponsor.get_asset_balance(&BASE_ASSET_ID).await? < sponsor_balance);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_can_be_deployed_in_chunks() -> Result<()> {
+    use fuel_core_types::{
+        fuel_asm::{op, GTFArgs, RegId},
+        fuel_tx::{Output, Receipt, TxPointer, UtxoId},
+    };
+    use fuels::types::{
+        input::Input,
+        transaction_builders::{BuildableTransaction, ScriptTransactionBuilder},
+    };
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+
+    // returns 42 after jumping over enough padding to need several chunks
+    let padding = 40;
+    let code = [op::movi(0x10, 42), op::jmpf(RegId::ZERO, padding)]
+        .into_iter()
+        .chain(std::iter::repeat(op::noop()).take(padding as usize))
+        .chain([op::ret(0x10)])
+        .collect();
+
+    // ANCHOR: deploy_in_chunks
+    let chunk_size = 64;
+    let loader = Contract::new(code, Salt::default(), vec![])
+        .deploy_in_chunks(&wallet, TxPolicies::default(), chunk_size)
+        .await?;
+    // ANCHOR_END: deploy_in_chunks
+
+    assert!(loader.chunk_ids().len() > 1);
+
+    // calls the loader, with the chunks as contract inputs
+    let contract_ids: Vec<_> = [loader.contract_id()]
+        .into_iter()
+        .chain(loader.chunk_ids())
+        .collect();
+    let inputs = contract_ids
+        .iter()
+        .map(|contract_id| {
+            Input::contract(
+                UtxoId::default(),
+                Default::default(),
+                Default::default(),
+                TxPointer::default(),
+                contract_id.into(),
+            )
+        })
+        .collect();
+    let outputs = (0..contract_ids.len())
+        .map(|idx| Output::contract(idx as u8, Default::default(), Default::default()))
+        .collect();
+    let script = [
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = loader
+        .contract_id()
+        .hash()
+        .iter()
+        .copied()
+        .chain([0; 16])
+        .collect();
+
+    let mut tb = ScriptTransactionBuilder::default()
+        .with_script(script)
+        .with_script_data(script_data)
+        .with_inputs(inputs)
+        .with_outputs(outputs);
+    wallet.add_witnesses(&mut tb)?;
+    wallet.adjust_for_fee(&mut tb, 0).await?;
+    let tx = tb.build(provider).await?;
+
+    let receipts = provider
+        .send_transaction_and_await_commit(tx)
+        .await?
+        .take_receipts_checked(None)?;
+
+    assert!(receipts.iter().any(|receipt| matches!(
+        receipt,
+        Receipt::Return { id, val: 42, .. } if *id == loader.contract_id().into()
+    )));
+
+    // an interrupted deployment can be resumed, already deployed contracts are skipped
+    loader.deploy(&wallet, TxPolicies::default()).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sponsored_call_forwarding_base_asset_keeps_balances_apart() -> Result<()> {
+    setup_program_test!(
+        Wallets("sponsor"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "sponsor"
+        ),
+    );
+    let provider = sponsor.try_provider()?.clone();
+    let user = WalletUnlocked::new_random(Some(provider.clone()));
+    sponsor
+        .transfer(user.address(), 1000, BASE_ASSET_ID, TxPolicies::default())
+        .await?;
+    let sponsor_balance = sponsor.get_asset_balance(&BASE_ASSET_ID).await?;
+
+    let response = contract_instance
+        .with_account(user.clone())
+        .methods()
+        .get_msg_amount()
+        .call_params(CallParameters::default().with_amount(100))?
+        .with_tx_policies(TxPolicies::default().with_gas_price(1))
+        .with_fee_payer(sponsor.clone())
+        .call()
+        .await?;
+
+    assert_eq!(response.value, 100);
+    assert_eq!(user.get_asset_balance(&BASE_ASSET_ID).await?, 900);
+    let new_sponsor_balance = sponsor.get_asset_balance(&BASE_ASSET_ID).await?;
+    assert!(0 < new_sponsor_balance && new_sponsor_balance < sponsor_balance);
+    assert_eq!(
+        provider
+            .get_contract_asset_balance(contract_instance.contract_id(), BASE_ASSET_ID)
+            .await?,
+        100
+    );
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use fuels::{
     prelude::*,
     types::{errors::transaction::Reason, Bits256, SizedAsciiString},
 };
+use futures::StreamExt;
 
 #[tokio::test]
 async fn test_parse_logged_variables() -> Result<()> {
@@ -42,6 +43,91 @@ async fn test_parse_logged_variables() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_query_logs() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "packages/fuels/tests/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let contract_methods = contract_instance.methods();
+    let provider = wallet.try_provider()?;
+
+    let first_height = provider.latest_block_height().await? + 1;
+    contract_methods.produce_logs_values().call().await?;
+    contract_methods.produce_logs_variables().call().await?;
+    let last_height = provider.latest_block_height().await?;
+    contract_methods.produce_logs_values().call().await?;
+
+    // ANCHOR: query_logs
+    let logs = contract_instance
+        .logs()
+        .query::<u64>(first_height..last_height + 1)
+        .await?;
+    // ANCHOR_END: query_logs
+
+    let values: Vec<_> = logs.iter().map(|log| log.value).collect();
+    assert_eq!(values, vec![64, 64]);
+    assert!(logs[0].block_height < logs[1].block_height);
+
+    let strings = provider
+        .query_logs::<SizedAsciiString<4>>(
+            contract_instance.contract_id(),
+            0..provider.latest_block_height().await? + 1,
+            &contract_instance.log_decoder(),
+        )
+        .await?;
+    assert_eq!(strings.len(), 1);
+    assert_eq!(strings[0].value, "Fuel");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_events() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "packages/fuels/tests/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: subscribe_events
+    let events = contract_instance.events();
+    let mut stream = events.subscribe::<u64>().await?;
+    // ANCHOR_END: subscribe_events
+
+    let contract_methods = contract_instance.methods();
+    let response = contract_methods.produce_logs_values().call().await?;
+    contract_methods.produce_logs_variables().call().await?;
+
+    // ANCHOR: next_event
+    let event = stream.next().await.expect("stream should not end")?;
+    // ANCHOR_END: next_event
+    let next_event = stream.next().await.expect("stream should not end")?;
+
+    assert_eq!(event.value, 64);
+    assert_eq!(Some(event.tx_id), response.tx_id);
+    assert!(event.block_height < next_event.block_height);
+    assert_eq!(next_event.value, 64);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_parse_logs_values() -> Result<()> {
     setup_program_test!(
@@ -487,6 +573,55 @@ async fn test_require_log() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_require_log_typed_error() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "RequireContract",
+            project = "packages/fuels/tests/contracts/require"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "RequireContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let contract_methods = contract_instance.methods();
+    // ANCHOR: typed_revert_error
+    let response = contract_methods.require_primitive().call_typed().await;
+
+    match response {
+        Err(ContractError::Revert(RequireContractRevertError::U64(code))) => assert_eq!(code, 42),
+        other => panic!("expected the contract to revert with `42`, got {other:?}"),
+    }
+    // ANCHOR_END: typed_revert_error
+    {
+        let response = contract_methods.require_string().call_typed().await;
+
+        assert!(matches!(
+            response,
+            Err(ContractError::Revert(RequireContractRevertError::SizedAsciiString4(message)))
+                if message == "fuel"
+        ));
+    }
+    {
+        // the error is logged last, after the additional logs
+        let response = contract_methods
+            .require_with_additional_logs()
+            .call_typed()
+            .await;
+
+        assert!(matches!(
+            response,
+            Err(ContractError::Revert(RequireContractRevertError::U64(64)))
+        ));
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_multi_call_require_log_single_contract() -> Result<()> {
     setup_program_test!(
@@ -914,3 +914,67 @@ async fn predicate_encoder_config_is_applied() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn multisig_account_collects_signatures_of_owners() -> Result<()> {
+    use fuels::{accounts::multisig::MultisigAccount, types::Bits256};
+
+    // ANCHOR: multisig_account
+    abigen!(Predicate(
+        name = "MultisigPredicate",
+        abi = "packages/fuels/tests/predicates/multisig/out/debug/multisig-abi.json"
+    ));
+
+    let owners: Vec<WalletUnlocked> = (0..3).map(|_| WalletUnlocked::new_random(None)).collect();
+    let owner_addresses: Vec<Bech32Address> =
+        owners.iter().map(|owner| owner.address().clone()).collect();
+    let threshold = 2;
+
+    let owners_config = [0, 1, 2].map(|i| Bits256(owner_addresses[i].hash().into()));
+    let configurables = MultisigPredicateConfigurables::default()
+        .with_OWNERS(owners_config)?
+        .with_THRESHOLD(threshold as u64)?;
+
+    let predicate = Predicate::load_from("tests/predicates/multisig/out/debug/multisig.bin")?
+        .with_configurables(configurables);
+    let mut multisig = MultisigAccount::new(predicate, owner_addresses, threshold)?;
+    // ANCHOR_END: multisig_account
+
+    let num_coins = 4;
+    let num_messages = 8;
+    let amount = 16;
+    let (provider, multisig_balance, receiver, receiver_balance, asset_id) =
+        setup_predicate_test(multisig.address(), num_coins, num_messages, amount).await?;
+
+    multisig.set_provider(provider.clone());
+
+    // ANCHOR: multisig_signing
+    let mut multisig_tx = multisig
+        .transfer_tx(
+            receiver.address(),
+            multisig_balance,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    // each owner signs the transaction ID, possibly on another machine
+    let detached_signature = owners[2].sign(multisig_tx.message()).await?;
+    multisig_tx.add_signature(detached_signature)?;
+    multisig_tx.sign_with(&owners[0]).await?;
+
+    let tx = multisig_tx.finalize()?;
+    provider.send_transaction_and_await_commit(tx).await?;
+    // ANCHOR_END: multisig_signing
+
+    assert_address_balance(multisig.address(), &provider, asset_id, 0).await;
+    assert_address_balance(
+        receiver.address(),
+        &provider,
+        asset_id,
+        receiver_balance + multisig_balance,
+    )
+    .await;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+predicate;
+
+use std::{b512::B512, ecr::ec_recover_address, tx::{tx_id, tx_witness_data, tx_witnesses_count}};
+
+// ANCHOR: multisig_predicate
+configurable {
+    OWNERS: [b256; 3] = [
+        0x0000000000000000000000000000000000000000000000000000000000000000,
+        0x0000000000000000000000000000000000000000000000000000000000000000,
+        0x0000000000000000000000000000000000000000000000000000000000000000,
+    ],
+    THRESHOLD: u64 = 2,
+}
+
+fn owner_index(signature: B512) -> Option<u64> {
+    if let Result::Ok(address) = ec_recover_address(signature, tx_id()) {
+        let mut i = 0;
+        while i < 3 {
+            if address.value == OWNERS[i] {
+                return Option::Some(i);
+            }
+            i += 1;
+        }
+    }
+
+    Option::None
+}
+
+fn main() -> bool {
+    let mut signed = [false, false, false];
+    let mut signatures = 0;
+
+    let mut i = 0;
+    while i < tx_witnesses_count() {
+        let signature: B512 = tx_witness_data(i);
+        if let Option::Some(owner) = owner_index(signature) {
+            if !signed[owner] {
+                signed[owner] = true;
+                signatures += 1;
+            }
+        }
+        i += 1;
+    }
+
+    signatures >= THRESHOLD
+}
+// ANCHOR_END: multisig_predicate
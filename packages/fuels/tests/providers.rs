@@ -923,6 +923,119 @@ async fn test_cache_invalidation_on_await() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "coin-cache")]
+#[tokio::test]
+async fn coin_locks_are_shared_between_providers() -> Result<()> {
+    use fuels::accounts::{coin_cache::FileCoinLockStore, coin_selection::LargestFirst};
+    use fuels_core::types::tx_status::TxStatus;
+
+    // blocks are produced manually, so that both transfers are pending at the same time
+    let provider_config = Config {
+        block_production: Trigger::Never,
+        ..Config::default()
+    };
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(1), Some(2), Some(1000)),
+        Some(provider_config),
+        None,
+    )
+    .await?;
+    let wallet = wallets.pop().unwrap();
+    let url = wallet.try_provider()?.url().to_string();
+    let receiver = WalletUnlocked::new_random(None);
+
+    // ANCHOR: coin_lock_store
+    let lock_dir = tempfile::tempdir()?;
+    let store = FileCoinLockStore::new(lock_dir.path().join("coin_locks.json"));
+
+    let provider = Provider::connect(&url)
+        .await?
+        .with_coin_lock_store(store.clone());
+    // ANCHOR_END: coin_lock_store
+    let other_provider = Provider::connect(&url).await?.with_coin_lock_store(store);
+
+    // with coins of equal amounts, both providers would select the same coin if it weren't
+    // locked by the first transfer
+    let mut tx_ids = vec![];
+    for provider in [provider, other_provider] {
+        let provider = provider.with_coin_selector(LargestFirst);
+        let mut wallet = wallet.clone();
+        wallet.set_provider(provider.clone());
+
+        let tx = create_transfer(&wallet, 500, receiver.address()).await?;
+        tx_ids.push(provider.send_transaction(tx).await?);
+    }
+
+    let provider = wallet.try_provider()?;
+    provider.produce_blocks(1, None).await?;
+
+    for tx_id in tx_ids {
+        let status = provider.tx_status(&tx_id).await?;
+        assert!(matches!(status, TxStatus::Success { .. }));
+    }
+    assert_eq!(
+        provider
+            .get_asset_balance(receiver.address(), BASE_ASSET_ID)
+            .await?,
+        1000
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "coin-cache")]
+#[tokio::test]
+async fn coins_selected_by_one_provider_are_not_selected_by_another() -> Result<()> {
+    use fuels::accounts::{coin_cache::FileCoinLockStore, coin_selection::LargestFirst};
+    use fuels_core::types::tx_status::TxStatus;
+
+    let provider_config = Config {
+        block_production: Trigger::Never,
+        ..Config::default()
+    };
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(1), Some(2), Some(1000)),
+        Some(provider_config),
+        None,
+    )
+    .await?;
+    let wallet = wallets.pop().unwrap();
+    let url = wallet.try_provider()?.url().to_string();
+    let receiver = WalletUnlocked::new_random(None);
+
+    let lock_dir = tempfile::tempdir()?;
+    let store = FileCoinLockStore::new(lock_dir.path().join("coin_locks.json"));
+
+    // both transfers are built, i.e. their coins are selected, before either is submitted
+    let mut txs = vec![];
+    for _ in 0..2 {
+        let provider = Provider::connect(&url)
+            .await?
+            .with_coin_lock_store(store.clone())
+            .with_coin_selector(LargestFirst);
+        let mut wallet = wallet.clone();
+        wallet.set_provider(provider.clone());
+
+        let tx = create_transfer(&wallet, 500, receiver.address()).await?;
+        txs.push((provider, tx));
+    }
+
+    let mut tx_ids = vec![];
+    for (provider, tx) in txs {
+        tx_ids.push(provider.send_transaction(tx).await?);
+    }
+
+    let provider = wallet.try_provider()?;
+    provider.produce_blocks(1, None).await?;
+
+    for tx_id in tx_ids {
+        let status = provider.tx_status(&tx_id).await?;
+        assert!(matches!(status, TxStatus::Success { .. }));
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn can_fetch_mint_transactions() -> Result<()> {
     setup_program_test!(
@@ -987,3 +1100,116 @@ async fn test_build_with_provider() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn can_subscribe_to_tx_status() -> Result<()> {
+    use fuels_core::types::tx_status::TxStatus;
+    use futures::StreamExt;
+
+    let provider_config = Config {
+        block_production: Trigger::Never,
+        ..Config::default()
+    };
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(1), Some(1), Some(100)),
+        Some(provider_config),
+        None,
+    )
+    .await?;
+    let wallet = wallets.pop().unwrap();
+    let provider = wallet.try_provider()?;
+    let receiver = WalletUnlocked::new_random(None);
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(BASE_ASSET_ID, 10)
+        .await?;
+    let outputs = wallet.get_asset_outputs_for_amount(receiver.address(), BASE_ASSET_ID, 10);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(wallet.clone())?;
+    let tx = tb.build(provider).await?;
+
+    // ANCHOR: subscribe_tx_status
+    let tx_id = provider.send_transaction(tx).await?;
+    let mut statuses = provider.subscribe_tx_status(&tx_id).await?;
+
+    assert!(matches!(
+        statuses.next().await,
+        Some(Ok(TxStatus::Submitted))
+    ));
+
+    provider.produce_blocks(1, None).await?;
+
+    assert!(matches!(
+        statuses.next().await,
+        Some(Ok(TxStatus::Success { .. }))
+    ));
+    // The stream ends once the transaction reaches a final status
+    assert!(statuses.next().await.is_none());
+    // ANCHOR_END: subscribe_tx_status
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_stream_new_blocks() -> Result<()> {
+    use futures::StreamExt;
+
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+    provider.produce_blocks(2, None).await?;
+
+    // ANCHOR: subscribe_blocks
+    let mut blocks = provider.subscribe_blocks().await?;
+    provider.produce_blocks(3, None).await?;
+
+    let mut last_cursor = None;
+    for expected_height in [3, 4, 5] {
+        let (block, cursor) = blocks.next().await.expect("stream should not end")?;
+        assert_eq!(block.header.height, expected_height);
+        last_cursor = Some(cursor);
+    }
+    // ANCHOR_END: subscribe_blocks
+    drop(blocks);
+
+    // ANCHOR: resume_blocks
+    provider.produce_blocks(1, None).await?;
+    let mut blocks = provider.subscribe_blocks_from(last_cursor.unwrap());
+
+    let (block, _) = blocks.next().await.expect("stream should not end")?;
+    assert_eq!(block.header.height, 6);
+    // ANCHOR_END: resume_blocks
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_watch_owner_transactions() -> Result<()> {
+    use futures::StreamExt;
+
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(2), Some(2), Some(100)),
+        None,
+        None,
+    )
+    .await?;
+    let sender = wallets.pop().unwrap();
+    let receiver = wallets.pop().unwrap();
+    let provider = sender.try_provider()?;
+
+    sender
+        .transfer(receiver.address(), 1, BASE_ASSET_ID, TxPolicies::default())
+        .await?;
+
+    let mut transactions = provider.watch_owner(receiver.address()).await?;
+
+    let (tx_id, _) = sender
+        .transfer(receiver.address(), 2, BASE_ASSET_ID, TxPolicies::default())
+        .await?;
+
+    let (response, _) = transactions.next().await.expect("stream should not end")?;
+    let TransactionType::Script(tx) = response.transaction else {
+        panic!("expected a script transaction");
+    };
+    assert_eq!(tx.id(provider.chain_id()), tx_id);
+
+    Ok(())
+}
@@ -438,3 +438,98 @@ async fn script_encoder_config_is_applied() {
         ));
     }
 }
+
+#[tokio::test]
+async fn script_output_and_logs_are_decoded_with_the_configured_encoding() -> Result<()> {
+    use fuel_core_types::fuel_asm::{op, RegId};
+    use fuels::core::codec::Encoding;
+
+    abigen!(Script(
+        name = "MyScript",
+        abi = r#"
+        {
+            "types": [
+                { "typeId": 0, "type": "u64", "components": null, "typeParameters": null }
+            ],
+            "functions": [
+                {
+                    "inputs": [],
+                    "name": "main",
+                    "output": { "name": "", "type": 0, "typeArguments": null },
+                    "attributes": null
+                }
+            ],
+            "loggedTypes": [
+                { "logId": 0, "loggedType": { "name": "", "type": 0, "typeArguments": null } }
+            ],
+            "messagesTypes": [],
+            "configurables": []
+        }
+        "#
+    ));
+    let wallet = launch_provider_and_get_wallet().await?;
+
+    // logs and returns `42` encoded with the experimental encoding, i.e. as 8 bytes in memory
+    let (value, ptr, len) = (0x10, 0x11, 0x12);
+    let binary: Vec<u8> = [
+        op::movi(value, 42),
+        op::move_(ptr, RegId::SP),
+        op::cfei(8),
+        op::sw(ptr, value, 0),
+        op::movi(len, 8),
+        op::logd(RegId::ZERO, RegId::ZERO, ptr, len),
+        op::retd(ptr, len),
+    ]
+    .into_iter()
+    .collect();
+    let dir = tempfile::tempdir()?;
+    let bin_path = dir.path().join("script.bin");
+    std::fs::write(&bin_path, binary)?;
+
+    let encoder_config = EncoderConfig {
+        encoding: Encoding::Experimental,
+        ..Default::default()
+    };
+    let response = MyScript::new(wallet, bin_path.to_str().unwrap())
+        .with_encoder_config(encoder_config)
+        .main()
+        .call()
+        .await?;
+
+    assert_eq!(response.value, 42);
+    assert_eq!(response.decode_logs().filter_succeeded(), vec!["42"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn script_call_fee_can_be_paid_by_another_account() -> Result<()> {
+    setup_program_test!(
+        Wallets("sponsor"),
+        Abigen(Script(
+            name = "MyScript",
+            project = "packages/fuels/tests/scripts/basic_script"
+        )),
+        LoadScript(
+            name = "script_instance",
+            script = "MyScript",
+            wallet = "sponsor"
+        )
+    );
+    let provider = sponsor.try_provider()?.clone();
+    let user = WalletUnlocked::new_random(Some(provider));
+    let sponsor_balance = sponsor.get_asset_balance(&BASE_ASSET_ID).await?;
+
+    script_instance
+        .with_account(user.clone())
+        .main(4, 2)
+        .with_tx_policies(TxPolicies::default().with_gas_price(1))
+        .with_fee_payer(sponsor.clone())
+        .call()
+        .await?;
+
+    assert_eq!(user.get_asset_balance(&BASE_ASSET_ID).await?, 0);
+    assert!(sponsor.get_asset_balance(&BASE_ASSET_ID).await? < sponsor_balance);
+
+    Ok(())
+}
@@ -460,3 +460,635 @@ async fn test_transfer_with_multiple_signatures() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn remote_signer_can_transfer_funds() -> Result<()> {
+    use fuels::accounts::remote_signer::{HttpTransport, RemoteSigner, UnixSocketTransport};
+
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::new(Some(2), None, None), None, None)
+            .await?;
+    let (custody_wallet, receiver) = (&wallets[0], &wallets[1]);
+    let provider = custody_wallet.try_provider()?.clone();
+
+    // ANCHOR: remote_signer
+    let server = MockSigningService::new([custody_wallet.clone()])
+        .serve_http()
+        .await?;
+
+    let signer = RemoteSigner::new(
+        custody_wallet.address().clone(),
+        HttpTransport::new(server.endpoint()),
+        Some(provider.clone()),
+    );
+
+    signer
+        .transfer(
+            receiver.address(),
+            100,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        )
+        .await?;
+    // ANCHOR_END: remote_signer
+
+    let socket_dir = tempfile::tempdir()?;
+    let server = MockSigningService::new([custody_wallet.clone()])
+        .serve_unix(socket_dir.path().join("signer.sock"))
+        .await?;
+    let signer = RemoteSigner::new(
+        custody_wallet.address().clone(),
+        UnixSocketTransport::new(server.endpoint()),
+        Some(provider),
+    );
+
+    signer
+        .transfer(
+            receiver.address(),
+            200,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let balance = receiver.get_asset_balance(&BASE_ASSET_ID).await?;
+    assert_eq!(balance, DEFAULT_COIN_AMOUNT * DEFAULT_NUM_COINS + 300);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transactions_can_be_built_and_signed_offline() -> Result<()> {
+    use fuels::types::{
+        coin_type::CoinType,
+        input::Input,
+        transaction_builders::{BuildableTransaction, OfflineDryRunner, ScriptTransactionBuilder},
+    };
+
+    let cold_wallet = WalletUnlocked::new_random(None);
+    let mut receiver = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(cold_wallet.address(), BASE_ASSET_ID, 1, 1000);
+    let provider = setup_test_provider(coins.clone(), vec![], None, None).await?;
+    receiver.set_provider(provider.clone());
+
+    // The consensus parameters and the gas price are fetched once on a connected machine
+    let consensus_parameters = provider.consensus_parameters().clone();
+    let gas_price = provider.node_info().await?.min_gas_price;
+
+    // ANCHOR: offline_build
+    let offline = OfflineDryRunner::new(consensus_parameters, gas_price);
+
+    let inputs = coins
+        .into_iter()
+        .map(|coin| Input::resource_signed(CoinType::Coin(coin)))
+        .collect();
+    let outputs = cold_wallet.get_asset_outputs_for_amount(receiver.address(), BASE_ASSET_ID, 400);
+
+    let tx = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default())
+        .build_without_signatures(&offline)
+        .await?;
+    let unsigned_tx = tx.to_hex();
+    // ANCHOR_END: offline_build
+
+    // ANCHOR: offline_sign
+    let mut tx = ScriptTransaction::from_hex(&unsigned_tx)?;
+    tx.sign_with(&cold_wallet, offline.consensus_parameters().chain_id)
+        .await?;
+    let signed_tx = tx.to_hex();
+    // ANCHOR_END: offline_sign
+
+    // ANCHOR: offline_send
+    let tx = ScriptTransaction::from_hex(&signed_tx)?;
+    provider.send_transaction_and_await_commit(tx).await?;
+    // ANCHOR_END: offline_send
+
+    assert_eq!(receiver.get_asset_balance(&BASE_ASSET_ID).await?, 400);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn offline_script_gas_estimate_matches_the_node() -> Result<()> {
+    use fuel_core_types::fuel_asm::{op, RegId};
+    use fuels::types::transaction_builders::{
+        BuildableTransaction, OfflineDryRunner, ScriptTransactionBuilder,
+    };
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let offline = OfflineDryRunner::new(
+        provider.consensus_parameters().clone(),
+        provider.node_info().await?.min_gas_price,
+    );
+
+    let script: Vec<u8> = [op::movi(0x10, 3), op::add(0x10, 0x10, 0x10), op::ret(0x10)]
+        .into_iter()
+        .collect();
+    let inputs = wallet.get_asset_inputs_for_amount(BASE_ASSET_ID, 1).await?;
+    let tb = || {
+        ScriptTransactionBuilder::default()
+            .with_script(script.clone())
+            .with_inputs(inputs.clone())
+            .with_gas_estimation_tolerance(0.0)
+    };
+
+    let offline_tx = tb().build_without_signatures(&offline).await?;
+    let online_tx = tb().build_without_signatures(provider).await?;
+
+    assert_eq!(offline_tx.gas_limit(), online_tx.gas_limit());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sponsor_pays_the_fee_of_a_transfer() -> Result<()> {
+    use fuels::types::transaction_builders::{BuildableTransaction, ScriptTransactionBuilder};
+
+    let mut user = WalletUnlocked::new_random(None);
+    let mut sponsor = WalletUnlocked::new_random(None);
+    let receiver = WalletUnlocked::new_random(None);
+
+    let asset_id = AssetId::new([1; 32]);
+    let mut coins = setup_single_asset_coins(user.address(), asset_id, 1, 100);
+    coins.extend(setup_single_asset_coins(
+        sponsor.address(),
+        BASE_ASSET_ID,
+        1,
+        1_000_000,
+    ));
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    user.set_provider(provider.clone());
+    sponsor.set_provider(provider.clone());
+
+    // ANCHOR: fee_payer
+    let inputs = user.get_asset_inputs_for_amount(asset_id, 40).await?;
+    let outputs = user.get_asset_outputs_for_amount(receiver.address(), asset_id, 40);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        inputs,
+        outputs,
+        TxPolicies::default().with_gas_price(1),
+    );
+    user.add_witnesses(&mut tb)?;
+
+    sponsor.pay_fee(&mut tb, 0).await?;
+
+    let tx = tb.build(&provider).await?;
+    provider.send_transaction_and_await_commit(tx).await?;
+    // ANCHOR_END: fee_payer
+
+    assert_eq!(
+        provider
+            .get_asset_balance(receiver.address(), asset_id)
+            .await?,
+        40
+    );
+    assert_eq!(user.get_asset_balance(&asset_id).await?, 60);
+    assert_eq!(user.get_asset_balance(&BASE_ASSET_ID).await?, 0);
+    let sponsor_balance = sponsor.get_asset_balance(&BASE_ASSET_ID).await?;
+    assert!(0 < sponsor_balance && sponsor_balance < 1_000_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sponsor_keeps_the_base_change_when_the_user_spends_base_asset() -> Result<()> {
+    use fuels::types::transaction_builders::{BuildableTransaction, ScriptTransactionBuilder};
+
+    let mut user = WalletUnlocked::new_random(None);
+    let mut sponsor = WalletUnlocked::new_random(None);
+    let receiver = WalletUnlocked::new_random(None);
+
+    let mut coins = setup_single_asset_coins(user.address(), BASE_ASSET_ID, 2, 500);
+    coins.extend(setup_single_asset_coins(
+        sponsor.address(),
+        BASE_ASSET_ID,
+        1,
+        1_000_000,
+    ));
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    user.set_provider(provider.clone());
+    sponsor.set_provider(provider.clone());
+
+    let inputs = user.get_asset_inputs_for_amount(BASE_ASSET_ID, 700).await?;
+    let outputs = user.get_asset_outputs_for_amount(receiver.address(), BASE_ASSET_ID, 300);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        inputs,
+        outputs,
+        TxPolicies::default().with_gas_price(1),
+    );
+    user.add_witnesses(&mut tb)?;
+
+    sponsor.pay_fee(&mut tb, 300).await?;
+
+    let tx = tb.build(&provider).await?;
+    let max_fee = tx
+        .fee_checked_from_tx(provider.consensus_parameters())
+        .expect("fee should be computable")
+        .max_fee();
+    provider.send_transaction_and_await_commit(tx).await?;
+
+    assert_eq!(
+        provider
+            .get_asset_balance(receiver.address(), BASE_ASSET_ID)
+            .await?,
+        300
+    );
+    assert_eq!(user.get_asset_balance(&BASE_ASSET_ID).await?, 700);
+    let sponsor_balance = sponsor.get_asset_balance(&BASE_ASSET_ID).await?;
+    assert!(1_000_000 - max_fee <= sponsor_balance && sponsor_balance < 1_000_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn message_proof_is_verified_locally() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let base_layer_address = Bech32Address::from(Address::new([1; 32]));
+
+    provider.produce_blocks(3, None).await?;
+    let (tx_id, nonce, _receipts) = wallet
+        .withdraw_to_base_layer(&base_layer_address, 1000, TxPolicies::default())
+        .await?;
+    let commit_height = provider.produce_blocks(2, None).await?;
+
+    let mut proof = provider
+        .get_message_proof(&tx_id, &nonce, None, Some(commit_height))
+        .await?
+        .expect("failed to retrieve message proof");
+
+    proof.verify()?;
+
+    proof.amount = 1;
+    let error = proof.verify().expect_err("should have failed");
+    assert!(error.to_string().contains("is not included in block"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transfer_batch_pays_many_recipients_in_one_transaction() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let receivers: Vec<_> = (0..3).map(|_| WalletUnlocked::new_random(None)).collect();
+
+    let asset_id = AssetId::new([1; 32]);
+    let mut coins = setup_single_asset_coins(wallet.address(), asset_id, 2, 100);
+    coins.extend(setup_single_asset_coins(
+        wallet.address(),
+        BASE_ASSET_ID,
+        2,
+        1000,
+    ));
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider.clone());
+
+    // ANCHOR: transfer_batch
+    let transfers = vec![
+        (receivers[0].address().clone(), BASE_ASSET_ID, 300),
+        (receivers[1].address().clone(), BASE_ASSET_ID, 400),
+        (receivers[1].address().clone(), asset_id, 120),
+        (receivers[2].address().clone(), asset_id, 30),
+    ];
+
+    let (_tx_id, _receipts) = wallet
+        .transfer_batch(transfers, TxPolicies::default())
+        .await?;
+    // ANCHOR_END: transfer_batch
+
+    let balance_of = |address: &Bech32Address, asset_id: AssetId| {
+        let provider = provider.clone();
+        let address = address.clone();
+        async move { provider.get_asset_balance(&address, asset_id).await }
+    };
+    assert_eq!(
+        balance_of(receivers[0].address(), BASE_ASSET_ID).await?,
+        300
+    );
+    assert_eq!(
+        balance_of(receivers[1].address(), BASE_ASSET_ID).await?,
+        400
+    );
+    assert_eq!(balance_of(receivers[1].address(), asset_id).await?, 120);
+    assert_eq!(balance_of(receivers[2].address(), asset_id).await?, 30);
+    assert_eq!(balance_of(wallet.address(), asset_id).await?, 50);
+    // no fee is charged with the default gas price
+    assert_eq!(balance_of(wallet.address(), BASE_ASSET_ID).await?, 1300);
+
+    let error = wallet
+        .transfer_batch(vec![], TxPolicies::default())
+        .await
+        .expect_err("should have failed");
+    assert_eq!(error.to_string(), "no transfers given");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn coins_can_be_consolidated_and_split() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+
+    let asset_id = AssetId::new([1; 32]);
+    let mut coins = setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, 10, 10);
+    coins.extend(setup_single_asset_coins(
+        wallet.address(),
+        asset_id,
+        1,
+        1000,
+    ));
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider);
+
+    // ANCHOR: consolidate_coins
+    let tx_ids = wallet.consolidate_coins(BASE_ASSET_ID, 4).await?;
+    // ANCHOR_END: consolidate_coins
+
+    assert_eq!(tx_ids.len(), 3);
+    let base_coins = wallet.get_coins(BASE_ASSET_ID).await?;
+    assert_eq!(base_coins.len(), 3);
+    assert_eq!(wallet.get_asset_balance(&BASE_ASSET_ID).await?, 100);
+
+    // ANCHOR: split_coins
+    let tx_ids = wallet.split_coins(asset_id, 5, 100).await?;
+    // ANCHOR_END: split_coins
+
+    assert_eq!(tx_ids.len(), 1);
+    let mut amounts: Vec<_> = wallet
+        .get_coins(asset_id)
+        .await?
+        .into_iter()
+        .map(|coin| coin.amount)
+        .collect();
+    amounts.sort();
+    assert_eq!(amounts, [100, 100, 100, 100, 100, 500]);
+
+    let error = wallet
+        .consolidate_coins(asset_id, 1)
+        .await
+        .expect_err("should have failed");
+    assert_eq!(
+        error.to_string(),
+        "at least 2 inputs per transaction are needed to consolidate coins, got `1`"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn splitting_coins_respects_the_output_limit() -> Result<()> {
+    use fuels::{
+        test_helpers::ChainConfig,
+        tx::{ConsensusParameters, TxParameters},
+    };
+
+    let consensus_parameters = ConsensusParameters {
+        tx_params: TxParameters::default().with_max_outputs(4),
+        ..Default::default()
+    };
+    let chain_config = ChainConfig {
+        consensus_parameters,
+        ..ChainConfig::default()
+    };
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, 1, 1000);
+    let provider = setup_test_provider(coins, vec![], None, Some(chain_config)).await?;
+    wallet.set_provider(provider);
+
+    // three coins and the change fit in a transaction
+    let tx_ids = wallet.split_coins(BASE_ASSET_ID, 5, 100).await?;
+
+    assert_eq!(tx_ids.len(), 2);
+    let coins = wallet.get_coins(BASE_ASSET_ID).await?;
+    assert_eq!(coins.iter().filter(|coin| coin.amount == 100).count(), 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transfers_can_use_a_client_side_coin_selector() -> Result<()> {
+    use fuels::accounts::coin_selection::SmallestFirst;
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let mut coins = setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, 3, 10);
+    coins.extend(setup_single_asset_coins(
+        wallet.address(),
+        BASE_ASSET_ID,
+        1,
+        1000,
+    ));
+
+    // ANCHOR: coin_selector
+    let provider = setup_test_provider(coins, vec![], None, None)
+        .await?
+        .with_coin_selector(SmallestFirst);
+    // ANCHOR_END: coin_selector
+    wallet.set_provider(provider);
+
+    let receiver = WalletUnlocked::new_random(None);
+    wallet
+        .transfer(receiver.address(), 25, BASE_ASSET_ID, TxPolicies::default())
+        .await?;
+
+    let mut amounts: Vec<_> = wallet
+        .get_coins(BASE_ASSET_ID)
+        .await?
+        .into_iter()
+        .map(|coin| coin.amount)
+        .collect();
+    amounts.sort();
+    assert_eq!(amounts, [5, 1000]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pipeline_submits_transfers_concurrently() -> Result<()> {
+    use fuels::{accounts::pipeline::TxPipeline, types::tx_status::TxStatus};
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, 1, 10_000);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider);
+
+    let receivers: Vec<_> = repeat(())
+        .take(8)
+        .map(|_| WalletUnlocked::new_random(None))
+        .collect();
+
+    // ANCHOR: tx_pipeline
+    let mut pipeline = TxPipeline::new(wallet.clone())
+        .with_max_in_flight(4)
+        .with_coin_split(BASE_ASSET_ID, 500);
+
+    for receiver in &receivers {
+        pipeline.push_transfer(
+            receiver.address(),
+            100,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        );
+    }
+
+    let responses = pipeline.run().await?;
+    // ANCHOR_END: tx_pipeline
+
+    let provider = wallet.try_provider()?;
+    let mut tx_ids = vec![];
+    for response in responses {
+        let response = response?;
+        assert!(matches!(response.tx_status, TxStatus::Success { .. }));
+        tx_ids.push(response.tx_id);
+    }
+    tx_ids.sort();
+    tx_ids.dedup();
+    assert_eq!(tx_ids.len(), receivers.len());
+
+    for receiver in &receivers {
+        assert_eq!(
+            provider
+                .get_asset_balance(receiver.address(), BASE_ASSET_ID)
+                .await?,
+            100
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pipeline_reports_requests_that_cannot_be_funded() -> Result<()> {
+    use fuels::accounts::pipeline::TxPipeline;
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, 2, 100);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider);
+    let receiver = WalletUnlocked::new_random(None);
+
+    let mut pipeline = TxPipeline::new(wallet.clone());
+    pipeline
+        .push_transfer(
+            receiver.address(),
+            100,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        )
+        .push_transfer(
+            receiver.address(),
+            1000,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        );
+
+    let responses = pipeline.run().await?;
+
+    assert!(responses[0].is_ok());
+    assert!(responses[1].is_err());
+    assert_eq!(wallet.get_asset_balance(&BASE_ASSET_ID).await?, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hd_wallet_discovers_used_wallets() -> Result<()> {
+    use fuels::accounts::hd_wallet::{HdWallet, MnemonicLanguage, WatchOnlyHdWallet};
+
+    let phrase = "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+    let offline_hd_wallet = HdWallet::new_from_mnemonic_phrase(phrase, None)?;
+
+    let coins = [(0, 0), (0, 3), (0, 9), (1, 1)]
+        .into_iter()
+        .map(|(account, index)| {
+            let wallet = offline_hd_wallet.wallet(account, index)?;
+            Ok(setup_single_asset_coins(
+                wallet.address(),
+                BASE_ASSET_ID,
+                1,
+                100,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .concat();
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    // ANCHOR: hd_wallet
+    let hd_wallet = HdWallet::new_from_mnemonic_phrase_with_options(
+        phrase,
+        None,
+        MnemonicLanguage::English,
+        Some(provider.clone()),
+    )?;
+
+    // the wallet of account 0 and index 3
+    let wallet = hd_wallet.wallet(0, 3)?;
+    // ANCHOR_END: hd_wallet
+    assert_eq!(wallet.get_asset_balance(&BASE_ASSET_ID).await?, 100);
+
+    // ANCHOR: hd_wallet_discover
+    let discovered = hd_wallet.discover(3).await?;
+    // ANCHOR_END: hd_wallet_discover
+    let found: Vec<_> = discovered
+        .iter()
+        .map(|discovered| (discovered.account, discovered.index))
+        .collect();
+    // index 9 of account 0 is beyond the gap limit
+    assert_eq!(found, [(0, 0), (0, 3), (1, 1)]);
+
+    // ANCHOR: hd_wallet_watch_only
+    let xpub = hd_wallet.watch_only(1)?.xpub()?;
+
+    let watch_only = WatchOnlyHdWallet::from_xpub(&xpub, Some(provider))?;
+    let wallet = watch_only.wallet(1)?;
+    // ANCHOR_END: hd_wallet_watch_only
+    assert_eq!(wallet.get_asset_balance(&BASE_ASSET_ID).await?, 100);
+
+    let discovered = watch_only.discover(20).await?;
+    assert_eq!(discovered.len(), 1);
+    assert_eq!(discovered[0].wallet.address(), wallet.address());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn typed_data_can_be_signed_and_recovered() -> Result<()> {
+    // ANCHOR: sign_typed_data
+    use fuels::{
+        macros::{Parameterize, Tokenizable},
+        types::{
+            typed_data::{recover_personal_message, recover_typed, TypedDataDomain},
+            ChainId,
+        },
+    };
+
+    #[derive(Clone, Parameterize, Tokenizable)]
+    struct Order {
+        maker: Address,
+        asset_id: AssetId,
+        amount: u64,
+        nonce: u64,
+    }
+
+    let wallet = WalletUnlocked::new_random(None);
+    let order = Order {
+        maker: wallet.address().into(),
+        asset_id: BASE_ASSET_ID,
+        amount: 100,
+        nonce: 1,
+    };
+    let domain = TypedDataDomain::new(ChainId::new(0), ContractId::from([1; 32]), 1);
+
+    let signature = wallet.sign_typed(&domain, order.clone()).await?;
+
+    let signer = recover_typed(&signature, &domain, order)?;
+    assert_eq!(&signer, wallet.address());
+    // ANCHOR_END: sign_typed_data
+
+    // ANCHOR: sign_personal_message
+    let signature = wallet.sign_personal_message("Sign in to app.fuel").await?;
+
+    let signer = recover_personal_message(&signature, "Sign in to app.fuel")?;
+    assert_eq!(&signer, wallet.address());
+    // ANCHOR_END: sign_personal_message
+
+    Ok(())
+}